@@ -0,0 +1,54 @@
+//! Helpers shared by the headless examples.
+//!
+//! Every example builds its scenario on [`headless_app`], narrates what happens through
+//! [`Transcript`], and drives frames with [`run_frames`]. The examples double as tests, checking
+//! their transcript against what the scenario is expected to produce.
+#![allow(dead_code, reason = "Not every example uses every helper")]
+
+use bevy::prelude::*;
+use std::collections::BTreeSet;
+
+/// An [`App`] without windowing or rendering, that is updated by hand rather than by a runner.
+pub fn headless_app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins(TaskPoolPlugin::default())
+        .init_resource::<Transcript>();
+    app
+}
+
+/// Runs `frames` updates of `app`.
+pub fn run_frames(app: &mut App, frames: usize) {
+    (0..frames).for_each(|_| app.update());
+}
+
+#[derive(Resource, Default)]
+/// Every line narrated by an example, in the order it was recorded.
+pub struct Transcript(Vec<String>);
+impl Transcript {
+    /// Prints `line` and keeps it for later checks.
+    pub fn record(&mut self, line: impl Into<String>) {
+        let line = line.into();
+
+        println!("{line}");
+        self.0.push(line);
+    }
+    pub fn lines(&self) -> &[String] {
+        &self.0
+    }
+    /// Drains the lines recorded so far, such as those of the previous frame.
+    pub fn take(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+/// Takes the lines recorded in `app` since the last call.
+pub fn take_transcript(app: &mut App) -> Vec<String> {
+    app.world_mut().resource_mut::<Transcript>().take()
+}
+
+/// Takes the lines recorded in `app` since the last call, for lines recorded by systems or
+/// observers that run in no particular order.
+pub fn take_unordered(app: &mut App) -> BTreeSet<String> {
+    take_transcript(app).into_iter().collect()
+}
@@ -114,7 +114,7 @@ fn setup(mut commands: Commands) {
                      -> Result<(), BevyError> {
                         let mut node = health_bar.get_mut(mutation.entity)?;
 
-                        let health = health.get(mutation.mutated)?;
+                        let health = mutation.component(&health)?;
 
                         node.width = percent(health.0 as f32);
                         Ok(())
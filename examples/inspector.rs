@@ -0,0 +1,157 @@
+//! An editor-style inspector that watches whichever component the user searches for, and edits it
+//! through reflection without gameplay mistaking the edit for a change made by the game.
+//!
+//! Shows [`WatchDynamic`] watching a component chosen at runtime, and [`ApplyAndNotify`] marking
+//! edits with [`Mutation::via_reflection`].
+use bevy::{ecs::reflect::AppTypeRegistry, prelude::*};
+use bevy_monitors::prelude::*;
+
+mod common;
+
+use common::{Transcript, headless_app, run_frames};
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Health(u8);
+
+#[derive(Component)]
+pub struct Player;
+
+fn main() {
+    let mut app = inspector_app();
+
+    search(&mut app, "Health");
+    run_frames(&mut app, 1);
+    hurt_player(&mut app);
+    run_frames(&mut app, 1);
+    edit_player(&mut app);
+    run_frames(&mut app, 1);
+}
+
+fn inspector_app() -> App {
+    let mut app = headless_app();
+
+    app.register_type::<Health>()
+        .add_systems(Startup, setup)
+        .add_observer(
+            |notification: On<DynamicNotification>, world: &World, mut commands: Commands| {
+                // Names are taken from reflection, as the component isn't known at compile time.
+                let name = world
+                    .components()
+                    .get_info(notification.component)
+                    .and_then(|info| info.type_id())
+                    .and_then(|type_id| {
+                        world.resource::<AppTypeRegistry>().read().get(type_id).map(
+                            |registration| registration.type_info().type_path_table().short_path(),
+                        )
+                    })
+                    .unwrap_or_default();
+                let line = format!("inspector: {name} {:?}", notification.kind);
+
+                commands.queue(move |world: &mut World| {
+                    world.resource_mut::<Transcript>().record(line);
+                });
+            },
+        );
+    app
+}
+
+fn setup(mut commands: Commands) {
+    let player = commands.spawn((Player, Health(100))).id();
+
+    commands
+        .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+        .observe(
+            |mutation: On<Mutation<Health>>, mut transcript: ResMut<Transcript>| {
+                if mutation.via_reflection() {
+                    transcript.record("gameplay: health edited, not replaying damage");
+                } else {
+                    transcript.record("gameplay: health changed");
+                }
+            },
+        );
+}
+
+/// Watches the component whose name the user typed into the inspector's search box.
+fn search(app: &mut App, name: &str) {
+    let world = app.world_mut();
+    let type_path = world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .get_with_short_type_path(name)
+        .map(|registration| registration.type_info().type_path())
+        .unwrap();
+
+    world.watch_by_path(type_path).unwrap();
+}
+
+fn player(app: &mut App) -> Entity {
+    let world = app.world_mut();
+
+    world
+        .query_filtered::<Entity, With<Player>>()
+        .single(world)
+        .unwrap()
+}
+
+fn hurt_player(app: &mut App) {
+    let player = player(app);
+
+    app.world_mut().get_mut::<Health>(player).unwrap().0 -= 30;
+}
+
+fn edit_player(app: &mut App) {
+    let player = player(app);
+
+    app.world_mut()
+        .apply_and_notify(player, &Health(100))
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::take_unordered;
+
+    #[test]
+    fn inspect_and_edit() {
+        let mut app = inspector_app();
+
+        search(&mut app, "Health");
+        run_frames(&mut app, 1);
+
+        assert_eq!(
+            take_unordered(&mut app),
+            [
+                "inspector: Health Added",
+                "inspector: Health Changed",
+                "gameplay: health changed",
+            ]
+            .map(String::from)
+            .into()
+        );
+
+        hurt_player(&mut app);
+        run_frames(&mut app, 1);
+
+        assert_eq!(
+            take_unordered(&mut app),
+            ["inspector: Health Changed", "gameplay: health changed"]
+                .map(String::from)
+                .into()
+        );
+
+        edit_player(&mut app);
+        run_frames(&mut app, 1);
+
+        assert_eq!(
+            take_unordered(&mut app),
+            [
+                "inspector: Health Changed",
+                "gameplay: health edited, not replaying damage",
+            ]
+            .map(String::from)
+            .into()
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! Keeps an inventory list in sync with the items in a backpack, only touching the rows that
+//! changed, along with the total weight of the backpack.
+//!
+//! Shows [`SortedView`] reporting positional changes and [`Aggregate`] keeping a summary on a
+//! parent entity.
+use bevy::prelude::*;
+use bevy_monitors::prelude::*;
+
+mod common;
+
+use common::{Transcript, headless_app, run_frames};
+
+#[derive(Component)]
+pub struct Item {
+    name: &'static str,
+    weight: u32,
+}
+
+#[derive(Component)]
+pub struct Backpack;
+
+#[derive(Component, Default)]
+pub struct TotalWeight(u32);
+
+impl Summary<Item> for TotalWeight {
+    type Contribution = u32;
+
+    fn contribution(item: &Item) -> u32 {
+        item.weight
+    }
+    fn include(&mut self, weight: &u32) {
+        self.0 += weight;
+    }
+    fn exclude(&mut self, weight: &u32) -> bool {
+        self.0 -= weight;
+        true
+    }
+}
+
+fn main() {
+    let mut app = inventory_app();
+
+    run_frames(&mut app, 1);
+    pick_up(&mut app, "Bow", 3);
+    run_frames(&mut app, 1);
+    use_up(&mut app, "Potion");
+    run_frames(&mut app, 1);
+}
+
+fn inventory_app() -> App {
+    let mut app = headless_app();
+
+    app.add_systems(Startup, setup)
+        .add_systems(PostUpdate, report_weight);
+    app
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn((
+        Backpack,
+        Aggregate::<Item, TotalWeight>::default(),
+        children![
+            Item {
+                name: "Sword",
+                weight: 8,
+            },
+            Item {
+                name: "Potion",
+                weight: 1,
+            },
+        ],
+    ));
+
+    // The rows of the list are only ever inserted, removed, or moved, never rebuilt.
+    commands
+        .spawn(SortedView::<Item, _>::by(|item| item.name))
+        .observe(
+            |entered: On<ItemEntered<Item>>,
+             items: Query<&Item>,
+             mut transcript: ResMut<Transcript>| {
+                if let Ok(item) = items.get(entered.item) {
+                    transcript.record(format!("insert {} at row {}", item.name, entered.index));
+                }
+            },
+        )
+        .observe(
+            |left: On<ItemLeft<Item>>, mut transcript: ResMut<Transcript>| {
+                transcript.record(format!("remove row {}", left.index));
+            },
+        )
+        .observe(
+            |moved: On<ItemMoved<Item>>,
+             items: Query<&Item>,
+             mut transcript: ResMut<Transcript>| {
+                if let Ok(item) = items.get(moved.item) {
+                    transcript.record(format!(
+                        "move {} from row {} to row {}",
+                        item.name, moved.from, moved.to
+                    ));
+                }
+            },
+        );
+}
+
+fn report_weight(
+    backpack: Single<&TotalWeight, (With<Backpack>, Changed<TotalWeight>)>,
+    mut transcript: ResMut<Transcript>,
+) {
+    transcript.record(format!("backpack weighs {}", backpack.0));
+}
+
+fn pick_up(app: &mut App, name: &'static str, weight: u32) {
+    let world = app.world_mut();
+    let backpack = world
+        .query_filtered::<Entity, With<Backpack>>()
+        .single(world)
+        .unwrap();
+
+    world.spawn((Item { name, weight }, ChildOf(backpack)));
+}
+
+fn use_up(app: &mut App, name: &'static str) {
+    let world = app.world_mut();
+    let item = world
+        .query::<(Entity, &Item)>()
+        .iter(world)
+        .find_map(|(entity, item)| (item.name == name).then_some(entity))
+        .unwrap();
+
+    world.entity_mut(item).despawn();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::take_transcript;
+
+    #[test]
+    fn inventory_rows() {
+        let mut app = inventory_app();
+
+        run_frames(&mut app, 1);
+
+        assert_eq!(
+            take_transcript(&mut app),
+            [
+                "insert Potion at row 0",
+                "insert Sword at row 1",
+                "backpack weighs 9",
+            ]
+        );
+
+        pick_up(&mut app, "Bow", 3);
+        run_frames(&mut app, 1);
+
+        assert_eq!(
+            take_transcript(&mut app),
+            [
+                "insert Bow at row 0",
+                "move Potion from row 0 to row 1",
+                "move Sword from row 1 to row 2",
+                "backpack weighs 12",
+            ]
+        );
+
+        use_up(&mut app, "Potion");
+        run_frames(&mut app, 1);
+
+        assert_eq!(
+            take_transcript(&mut app),
+            [
+                "remove row 1",
+                "move Sword from row 2 to row 1",
+                "backpack weighs 11",
+            ]
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Sends only what changed to clients, rather than the whole world every tick.
+//!
+//! Shows a single global monitor collecting dirty entities from [`Mutation<C>`] and
+//! [`Removal<C>`], which a plain system turns into one packet per frame.
+use bevy::prelude::*;
+use bevy_monitors::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+mod common;
+
+use common::{Transcript, headless_app, run_frames};
+
+#[derive(Component)]
+pub struct Replicated;
+
+#[derive(Component)]
+pub struct Player;
+
+#[derive(Component)]
+pub struct Barrel;
+
+#[derive(Component)]
+pub struct Position(i32, i32);
+
+#[derive(Component)]
+pub struct Health(u8);
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Field {
+    Position,
+    Health,
+}
+
+#[derive(Resource, Default)]
+/// The fields each replicated entity has had changed since the last packet, and the replicated
+/// entities that have been despawned.
+pub struct Dirty {
+    changed: BTreeMap<Entity, BTreeSet<Field>>,
+    despawned: BTreeSet<Entity>,
+}
+impl Dirty {
+    fn mark(&mut self, entity: Entity, field: Field) {
+        self.changed.entry(entity).or_default().insert(field);
+    }
+}
+
+fn main() {
+    let mut app = network_app();
+
+    run_frames(&mut app, 2);
+
+    let player = find::<Player>(&mut app);
+    let barrel = find::<Barrel>(&mut app);
+
+    app.world_mut().get_mut::<Position>(player).unwrap().0 += 1;
+    app.world_mut().get_mut::<Health>(barrel).unwrap().0 -= 5;
+    run_frames(&mut app, 1);
+
+    app.world_mut().entity_mut(barrel).despawn();
+    run_frames(&mut app, 1);
+}
+
+fn network_app() -> App {
+    let mut app = headless_app();
+
+    app.init_resource::<Dirty>()
+        .add_systems(Startup, setup)
+        .add_systems(PostUpdate, send_packet);
+    app
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn((Player, Replicated, Position(0, 0), Health(100)));
+    commands.spawn((Barrel, Replicated, Position(4, 2), Health(10)));
+    // Only exists locally, so is never sent.
+    commands.spawn(Position(0, 8));
+
+    // Without `Monitor` this watches every entity, replicated or not.
+    commands
+        .spawn((
+            NotifyChanged::<Position>::default(),
+            NotifyChanged::<Health>::default(),
+            NotifyRemoved::<Replicated>::default(),
+        ))
+        .observe(
+            |mutation: On<Mutation<Position>>,
+             replicated: Query<(), With<Replicated>>,
+             mut dirty: ResMut<Dirty>| {
+                if replicated.contains(mutation.mutated) {
+                    dirty.mark(mutation.mutated, Field::Position);
+                }
+            },
+        )
+        .observe(
+            |mutation: On<Mutation<Health>>,
+             replicated: Query<(), With<Replicated>>,
+             mut dirty: ResMut<Dirty>| {
+                if replicated.contains(mutation.mutated) {
+                    dirty.mark(mutation.mutated, Field::Health);
+                }
+            },
+        )
+        .observe(
+            |removal: On<Removal<Replicated>>, mut dirty: ResMut<Dirty>| {
+                dirty.changed.remove(&removal.removed);
+                dirty.despawned.insert(removal.removed);
+            },
+        );
+}
+
+fn send_packet(
+    mut dirty: ResMut<Dirty>,
+    replicated: Query<(&Position, &Health), With<Replicated>>,
+    mut sequence: Local<u32>,
+    mut transcript: ResMut<Transcript>,
+) {
+    if dirty.changed.is_empty() && dirty.despawned.is_empty() {
+        return;
+    }
+
+    let Dirty { changed, despawned } = std::mem::take(&mut *dirty);
+    *sequence += 1;
+
+    transcript.record(format!("packet {}", *sequence));
+    changed.into_iter().for_each(|(entity, fields)| {
+        let Ok((position, health)) = replicated.get(entity) else {
+            return;
+        };
+
+        fields.into_iter().for_each(|field| match field {
+            Field::Position => {
+                transcript.record(format!("  {entity} position {} {}", position.0, position.1))
+            }
+            Field::Health => transcript.record(format!("  {entity} health {}", health.0)),
+        });
+    });
+    despawned.into_iter().for_each(|entity| {
+        transcript.record(format!("  {entity} despawned"));
+    });
+}
+
+fn find<M: Component>(app: &mut App) -> Entity {
+    let world = app.world_mut();
+
+    world
+        .query_filtered::<Entity, With<M>>()
+        .single(world)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{take_transcript, take_unordered};
+
+    #[test]
+    fn send_only_changes() {
+        let mut app = network_app();
+
+        run_frames(&mut app, 1);
+
+        let player = find::<Player>(&mut app);
+        let barrel = find::<Barrel>(&mut app);
+
+        // Everything is sent once, as it was just spawned. Entities are written in the order of their
+        // ids, which isn't necessarily the order they were spawned in.
+        assert_eq!(
+            take_unordered(&mut app),
+            BTreeSet::from([
+                "packet 1".to_string(),
+                format!("  {player} position 0 0"),
+                format!("  {player} health 100"),
+                format!("  {barrel} position 4 2"),
+                format!("  {barrel} health 10"),
+            ])
+        );
+
+        run_frames(&mut app, 1);
+
+        assert!(take_transcript(&mut app).is_empty());
+
+        app.world_mut().get_mut::<Position>(player).unwrap().0 += 1;
+        app.world_mut().get_mut::<Health>(barrel).unwrap().0 -= 5;
+        run_frames(&mut app, 1);
+
+        assert_eq!(
+            take_unordered(&mut app),
+            BTreeSet::from([
+                "packet 2".to_string(),
+                format!("  {player} position 1 0"),
+                format!("  {barrel} health 5"),
+            ])
+        );
+
+        app.world_mut().entity_mut(barrel).despawn();
+        run_frames(&mut app, 1);
+
+        assert_eq!(
+            take_unordered(&mut app),
+            BTreeSet::from(["packet 3".to_string(), format!("  {barrel} despawned")])
+        );
+    }
+}
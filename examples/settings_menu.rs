@@ -0,0 +1,212 @@
+//! A settings menu whose rows redraw themselves, which saves once per burst of changes rather than
+//! once per changed setting.
+//!
+//! Shows monitors targeting a single entity, [`NotifySettled`] for once-per-frame work, and
+//! [`on_mutation`] driving a plain system.
+use bevy::prelude::*;
+use bevy_monitors::prelude::*;
+
+mod common;
+
+use common::{Transcript, headless_app, run_frames};
+
+#[derive(Component)]
+pub struct Settings;
+
+#[derive(Component)]
+pub struct Volume(u8);
+
+#[derive(Component)]
+pub struct Fullscreen(bool);
+
+#[derive(Component, Clone, Copy, Debug)]
+pub enum Difficulty {
+    Easy,
+    Hard,
+}
+
+#[derive(Component)]
+/// The text of a row in the menu.
+pub struct Label(String);
+impl Default for Label {
+    fn default() -> Self {
+        Self("...".to_string())
+    }
+}
+
+fn main() {
+    let mut app = settings_app();
+
+    run_frames(&mut app, 1);
+    edit(&mut app, |mut settings| {
+        settings.get_mut::<Volume>().unwrap().0 = 60;
+        settings.get_mut::<Volume>().unwrap().0 = 80;
+        settings.get_mut::<Fullscreen>().unwrap().0 = true;
+    });
+    run_frames(&mut app, 1);
+    edit(&mut app, |mut settings| {
+        *settings.get_mut::<Difficulty>().unwrap() = Difficulty::Hard;
+    });
+    run_frames(&mut app, 1);
+}
+
+fn settings_app() -> App {
+    let mut app = headless_app();
+
+    app.add_systems(Startup, setup)
+        .add_systems(PostUpdate, apply_volume.run_if(on_mutation::<Volume>()));
+    app
+}
+
+fn setup(mut commands: Commands) {
+    let settings = commands
+        .spawn((Settings, Volume(50), Fullscreen(false), Difficulty::Easy))
+        .id();
+
+    // Each row only redraws when its own setting changes.
+    commands
+        .spawn((
+            Label::default(),
+            Monitor(settings),
+            NotifyChanged::<Volume>::default(),
+        ))
+        .observe(
+            |mutation: On<Mutation<Volume>>,
+             volume: Query<&Volume>,
+             mut labels: Query<&mut Label>| {
+                if let (Ok(volume), Ok(mut label)) = (
+                    volume.get(mutation.mutated),
+                    labels.get_mut(mutation.entity),
+                ) {
+                    label.0 = format!("Volume: {}", volume.0);
+                }
+            },
+        );
+    commands
+        .spawn((
+            Label::default(),
+            Monitor(settings),
+            NotifyChanged::<Fullscreen>::default(),
+        ))
+        .observe(
+            |mutation: On<Mutation<Fullscreen>>,
+             fullscreen: Query<&Fullscreen>,
+             mut labels: Query<&mut Label>| {
+                if let (Ok(fullscreen), Ok(mut label)) = (
+                    fullscreen.get(mutation.mutated),
+                    labels.get_mut(mutation.entity),
+                ) {
+                    label.0 = match fullscreen.0 {
+                        true => "Fullscreen: on".to_string(),
+                        false => "Fullscreen: off".to_string(),
+                    };
+                }
+            },
+        );
+    commands
+        .spawn((
+            Label::default(),
+            Monitor(settings),
+            NotifyChanged::<Difficulty>::default(),
+        ))
+        .observe(
+            |mutation: On<Mutation<Difficulty>>,
+             difficulty: Query<&Difficulty>,
+             mut labels: Query<&mut Label>| {
+                if let (Ok(difficulty), Ok(mut label)) = (
+                    difficulty.get(mutation.mutated),
+                    labels.get_mut(mutation.entity),
+                ) {
+                    label.0 = format!("Difficulty: {difficulty:?}");
+                }
+            },
+        );
+
+    // However many settings changed this frame, they are written to disk once.
+    commands
+        .spawn((
+            Monitor(settings),
+            NotifyChanged::<Volume>::default(),
+            NotifyChanged::<Fullscreen>::default(),
+            NotifyChanged::<Difficulty>::default(),
+            NotifySettled::default(),
+        ))
+        .observe(|_: On<Settled>, mut transcript: ResMut<Transcript>| {
+            transcript.record("saved settings");
+        });
+}
+
+fn apply_volume(volume: Single<&Volume, With<Settings>>, mut transcript: ResMut<Transcript>) {
+    transcript.record(format!("audio volume set to {}", volume.0));
+}
+
+fn edit(app: &mut App, edit: impl FnOnce(EntityWorldMut)) {
+    let world = app.world_mut();
+    let settings = world
+        .query_filtered::<Entity, With<Settings>>()
+        .single(world)
+        .unwrap();
+
+    edit(world.entity_mut(settings));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::take_transcript;
+
+    fn labels(app: &mut App) -> Vec<String> {
+        let world = app.world_mut();
+        let mut labels = world
+            .query::<&Label>()
+            .iter(world)
+            .map(|label| label.0.clone())
+            .collect::<Vec<_>>();
+
+        labels.sort();
+        labels
+    }
+
+    #[test]
+    fn save_once_per_burst() {
+        let mut app = settings_app();
+
+        run_frames(&mut app, 1);
+
+        assert_eq!(
+            labels(&mut app),
+            ["Difficulty: Easy", "Fullscreen: off", "Volume: 50"]
+        );
+        assert_eq!(
+            take_transcript(&mut app),
+            ["audio volume set to 50", "saved settings"]
+        );
+
+        edit(&mut app, |mut settings| {
+            settings.get_mut::<Volume>().unwrap().0 = 60;
+            settings.get_mut::<Volume>().unwrap().0 = 80;
+            settings.get_mut::<Fullscreen>().unwrap().0 = true;
+        });
+        run_frames(&mut app, 1);
+
+        assert_eq!(
+            labels(&mut app),
+            ["Difficulty: Easy", "Fullscreen: on", "Volume: 80"]
+        );
+        assert_eq!(
+            take_transcript(&mut app),
+            ["audio volume set to 80", "saved settings"]
+        );
+
+        edit(&mut app, |mut settings| {
+            *settings.get_mut::<Difficulty>().unwrap() = Difficulty::Hard;
+        });
+        run_frames(&mut app, 1);
+
+        assert_eq!(
+            labels(&mut app),
+            ["Difficulty: Hard", "Fullscreen: on", "Volume: 80"]
+        );
+        assert_eq!(take_transcript(&mut app), ["saved settings"]);
+    }
+}
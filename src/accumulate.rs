@@ -0,0 +1,360 @@
+use crate::{
+    dispatch::Notifier,
+    monitors::resolve_targets,
+    mutation::configure_notify_sets,
+    prelude::*,
+    registry::{add_systems, register_once, register_teardown, unregister},
+};
+use bevy_app::Update;
+use bevy_ecs::{
+    component::Mutable, entity::EntityHashMap, lifecycle::HookContext, prelude::*,
+    schedule::ScheduleCleanupPolicy, world::DeferredWorld,
+};
+use bevy_time::Time;
+use std::{marker::PhantomData, mem, time::Duration};
+
+#[derive(Resource)]
+/// Contains the observer accumulating changes to the component [`C`].
+struct DetectingAccumulation<C: Diffable> {
+    observer: Entity,
+    _phantom: PhantomData<C>,
+}
+
+#[derive(EntityEvent)]
+/// Contains the sum of all changes to [`C`] seen by a monitor over an interval.
+///
+/// See [`AccumulateChanges<C>`] for more information on how this is triggered.
+pub struct Accumulated<C: Diffable> {
+    pub entity: Entity,
+    /// The sum of the deltas of every change over the interval.
+    pub total_delta: C::Delta,
+    /// The number of changes that were summed.
+    pub count: u32,
+    _phantom: PhantomData<C>,
+}
+
+#[derive(Component)]
+#[component(
+    on_add = AccumulateChanges::<C>::register_accumulation,
+    on_remove = AccumulateChanges::<C>::remove_accumulation
+)]
+#[require(NotifyChanged<C> = NotifyChanged::required())]
+/// Adding this component to a monitor will cause it to sum up the deltas of changes to [`C`] and
+/// deliver them as a single [`Accumulated<C>`] every `interval`.
+///
+/// This is useful for things like floating damage numbers or rate meters that need to know how
+/// much changed over a window rather than each individual change. [`Mutation<C>`] will still be
+/// triggered for every change as usual.
+///
+/// No [`Accumulated<C>`] is triggered for intervals without changes.
+///
+/// Intervals are measured with the [`Time`] resource, without it changes keep accumulating but are
+/// never delivered.
+///
+/// # Technical info
+///
+/// The delta of a change is computed against the previous value of [`C`] seen by this monitor. The
+/// watched entity's value is recorded when this component is added, entities that are not known at
+/// that point (such as those watched by a global monitor) have their first change recorded as the
+/// starting value instead.
+///
+/// Adding this component to an entity will spawn an [`Observer`] for [`Mutation<C>`] and add a
+/// system to the [`Update`] schedule, this is only done once. These are removed when all instances
+/// of this component in the world are removed.
+///
+/// [`Time`]: https://docs.rs/bevy/latest/bevy/time/struct.Time.html
+pub struct AccumulateChanges<C: Diffable> {
+    interval: Duration,
+    elapsed: Duration,
+    total_delta: C::Delta,
+    count: u32,
+    previous: EntityHashMap<C>,
+}
+impl<C: Diffable> AccumulateChanges<C> {
+    /// Delivers the accumulated changes once every `interval`.
+    pub fn every(interval: Duration) -> Self
+    where
+        C: Component<Mutability = Mutable>,
+    {
+        Self {
+            interval,
+            elapsed: Duration::ZERO,
+            total_delta: C::Delta::default(),
+            count: 0,
+            previous: EntityHashMap::default(),
+        }
+    }
+    /// The interval at which [`Accumulated<C>`] is triggered.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+    fn register_accumulation(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        world.commands().queue(move |world: &mut World| {
+            let targets = resolve_targets(world, entity).unwrap_or_default();
+
+            let previous = targets
+                .into_iter()
+                .filter_map(|target| Some((target, world.get::<C>(target)?.clone())))
+                .collect::<Vec<_>>();
+
+            if let Some(mut accumulate) = world.get_mut::<AccumulateChanges<C>>(entity) {
+                accumulate.previous.extend(previous);
+            }
+        });
+
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                let observer = world.add_observer(accumulate_mutation::<C>).id();
+                configure_notify_sets(world, Update);
+                add_systems(
+                    world,
+                    Update,
+                    deliver_accumulated::<C>.in_set(NotifySet::Dispatch),
+                );
+                world.insert_resource(DetectingAccumulation::<C> {
+                    observer,
+                    _phantom: PhantomData,
+                });
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_accumulation(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_accumulating = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_accumulating == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `AccumulateChanges::<C>` adds the `DetectingAccumulation`
+        // resource and the system.
+        let DetectingAccumulation { observer, .. } =
+            world.remove_resource::<DetectingAccumulation<C>>().unwrap();
+        world.entity_mut(observer).despawn();
+        world.schedule_scope(Update, |world, schedule| {
+            schedule
+                .remove_systems_in_set(
+                    deliver_accumulated::<C>,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+    }
+}
+
+fn accumulate_mutation<C: Diffable>(
+    mutation: On<Mutation<C>>,
+    mut accumulators: Query<&mut AccumulateChanges<C>>,
+    values: Query<&C>,
+) {
+    let Ok(mut accumulate) = accumulators.get_mut(mutation.entity) else {
+        return;
+    };
+    let Ok(value) = values.get(mutation.mutated) else {
+        return;
+    };
+
+    if let Some(previous) = accumulate.previous.insert(mutation.mutated, value.clone()) {
+        accumulate.total_delta += value.delta(&previous);
+        accumulate.count += 1;
+    }
+}
+
+fn deliver_accumulated<C: Diffable>(
+    mut notifier: Notifier,
+    time: Option<Res<Time>>,
+    mut accumulators: Query<(Entity, &mut AccumulateChanges<C>)>,
+) {
+    let Some(time) = time else {
+        return;
+    };
+
+    accumulators
+        .iter_mut()
+        .for_each(|(entity, mut accumulate)| {
+            accumulate.elapsed += time.delta();
+
+            if accumulate.elapsed < accumulate.interval {
+                return;
+            }
+            // Overshooting the interval counts towards the next one, so frame times don't make
+            // the intervals drift.
+            let interval = accumulate.interval;
+            accumulate.elapsed -= interval;
+
+            if accumulate.count == 0 {
+                return;
+            }
+
+            notifier.trigger(Accumulated::<C> {
+                entity,
+                total_delta: mem::take(&mut accumulate.total_delta),
+                count: mem::take(&mut accumulate.count),
+                _phantom: PhantomData,
+            });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+    use std::time::Duration;
+
+    #[derive(Component, Clone)]
+    pub struct Health(i32);
+    impl Diffable for Health {
+        type Delta = i32;
+
+        fn delta(&self, previous: &Self) -> Self::Delta {
+            self.0 - previous.0
+        }
+    }
+
+    #[derive(Resource, Default)]
+    pub struct DamageNumbers(Vec<(i32, u32)>);
+
+    #[test]
+    fn accumulate_damage() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+
+        world.init_resource::<DamageNumbers>();
+        world.init_resource::<Time>();
+
+        let player = world.spawn(Health(100)).id();
+
+        world
+            .spawn((
+                Monitor(player),
+                AccumulateChanges::<Health>::every(Duration::from_secs(1)),
+            ))
+            .observe(
+                |accumulated: On<Accumulated<Health>>, mut damage: ResMut<DamageNumbers>| {
+                    damage.0.push((accumulated.total_delta, accumulated.count));
+                },
+            );
+
+        world.flush();
+
+        for damage in [10, 5, 20] {
+            world.get_mut::<Health>(player).unwrap().0 -= damage;
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(300));
+            world.run_schedule(Update);
+        }
+
+        assert!(world.resource::<DamageNumbers>().0.is_empty());
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(300));
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<DamageNumbers>().0, vec![(-35, 3)]);
+
+        // Nothing changed over the next interval.
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<DamageNumbers>().0, vec![(-35, 3)]);
+    }
+
+    #[test]
+    fn accumulate_for_many_targets() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+
+        world.init_resource::<DamageNumbers>();
+        world.init_resource::<Time>();
+
+        let party = [world.spawn(Health(100)).id(), world.spawn(Health(80)).id()];
+
+        world
+            .spawn((
+                MonitorMany(party.to_vec()),
+                AccumulateChanges::<Health>::every(Duration::from_secs(1)),
+            ))
+            .observe(
+                |accumulated: On<Accumulated<Health>>, mut damage: ResMut<DamageNumbers>| {
+                    damage.0.push((accumulated.total_delta, accumulated.count));
+                },
+            );
+
+        world.flush();
+
+        world.get_mut::<Health>(party[0]).unwrap().0 -= 10;
+        world.get_mut::<Health>(party[1]).unwrap().0 -= 20;
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_schedule(Update);
+
+        // Both were known when the monitor was added, so neither first change is lost.
+        assert_eq!(world.resource::<DamageNumbers>().0, vec![(-30, 2)]);
+    }
+
+    #[test]
+    fn carry_over_overshoot() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+
+        world.init_resource::<DamageNumbers>();
+
+        let player = world.spawn(Health(100)).id();
+
+        world
+            .spawn((
+                Monitor(player),
+                AccumulateChanges::<Health>::every(Duration::from_secs(1)),
+            ))
+            .observe(
+                |accumulated: On<Accumulated<Health>>, mut damage: ResMut<DamageNumbers>| {
+                    damage.0.push((accumulated.total_delta, accumulated.count));
+                },
+            );
+
+        world.flush();
+
+        // Without `Time` nothing is delivered, but nothing is lost either.
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert!(world.resource::<DamageNumbers>().0.is_empty());
+
+        world.init_resource::<Time>();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(1200));
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 5;
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(800));
+        world.run_schedule(Update);
+
+        // The 200ms past the first interval count towards the second.
+        assert_eq!(world.resource::<DamageNumbers>().0, vec![(-10, 1), (-5, 1)]);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::monitors::resolve_targets;
+use bevy_ecs::{
+    lifecycle::HookContext, prelude::*, system::IntoObserverSystem, world::DeferredWorld,
+};
+
+#[derive(Component)]
+#[component(
+    on_add = AdaptedObserver::spawn_observer,
+    on_replace = AdaptedObserver::despawn_observer
+)]
+/// Wraps an existing plain Bevy observer, such as one observing [`On<Add, C>`], so it only reacts
+/// to the entities a monitor is watching.
+///
+/// This allows migrating to monitors one observer at a time, keeping the original signature,
+/// rather than rewriting every observer to [`Addition<C>`] and [`Removal<C>`] at once.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// // An observer written before monitors were introduced.
+/// fn show_health_bar(add: On<Add, Health>) {
+///     println!("{} now has health", add.entity);
+/// }
+///
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands.spawn((Monitor(player), AdaptedObserver::on_add(show_health_bar)));
+/// # }
+/// ```
+///
+/// Like any monitor, the observer reacts to the entity itself with [`MonitorSelf`], to several
+/// entities with [`MonitorMany`] or [`MonitorChildren`], and to every entity without any of these
+/// or [`Monitor`]. Children are those the entity has once commands are applied.
+///
+/// # Technical info
+///
+/// The observer is spawned as its own entity once commands are applied, watching the target of
+/// the monitor at that point, and is despawned along with this component. Retargeting the monitor
+/// or filters such as [`MonitorPaused`] don't apply to it, those require moving to
+/// [`NotifyAdded<C>`] and [`NotifyRemoved<C>`].
+///
+/// [`Addition<C>`]: crate::prelude::Addition
+/// [`Removal<C>`]: crate::prelude::Removal
+/// [`Monitor`]: crate::prelude::Monitor
+/// [`MonitorSelf`]: crate::prelude::MonitorSelf
+/// [`MonitorMany`]: crate::prelude::MonitorMany
+/// [`MonitorChildren`]: crate::prelude::MonitorChildren
+/// [`MonitorPaused`]: crate::prelude::MonitorPaused
+/// [`NotifyAdded<C>`]: crate::prelude::NotifyAdded
+/// [`NotifyRemoved<C>`]: crate::prelude::NotifyRemoved
+pub struct AdaptedObserver {
+    observer: Option<Observer>,
+    spawned: Option<Entity>,
+}
+impl AdaptedObserver {
+    /// Adapts an observer of [`C`] being added.
+    pub fn on_add<C: Component, M>(system: impl IntoObserverSystem<Add, C, M>) -> Self {
+        Self::new(Observer::new(system))
+    }
+    /// Adapts an observer of [`C`] being removed.
+    pub fn on_remove<C: Component, M>(system: impl IntoObserverSystem<Remove, C, M>) -> Self {
+        Self::new(Observer::new(system))
+    }
+    fn new(observer: Observer) -> Self {
+        Self {
+            observer: Some(observer),
+            spawned: None,
+        }
+    }
+    fn spawn_observer(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        // # Safety
+        // This hook only runs for entities that have this component.
+        let Some(mut observer) = world.get_mut::<Self>(entity).unwrap().observer.take() else {
+            return;
+        };
+
+        world.commands().queue(move |world: &mut World| {
+            match resolve_targets(world, entity) {
+                // An observer watching no entities would react to all of them.
+                Some(targets) if targets.is_empty() => return,
+                Some(targets) => observer.watch_entities(targets),
+                None => {}
+            }
+
+            let spawned = world.spawn(observer).id();
+
+            match world.get_mut::<Self>(entity) {
+                Some(mut adapted) => adapted.spawned = Some(spawned),
+                // Removed again before commands were applied.
+                None => world.entity_mut(spawned).despawn(),
+            }
+        });
+    }
+    fn despawn_observer(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        // # Safety
+        // This hook only runs for entities that have this component.
+        if let Some(spawned) = world.get::<Self>(entity).unwrap().spawned {
+            world.commands().entity(spawned).try_despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health;
+
+    #[derive(Resource, Default)]
+    pub struct Added(Vec<Entity>);
+
+    fn record_added(add: On<Add, Health>, mut added: ResMut<Added>) {
+        added.0.push(add.entity);
+    }
+
+    #[test]
+    fn adapt_plain_observer() {
+        let mut world = World::new();
+
+        world.init_resource::<Added>();
+
+        let player = world.spawn_empty().id();
+        let enemy = world.spawn_empty().id();
+        let monitor = world
+            .spawn((Monitor(player), AdaptedObserver::on_add(record_added)))
+            .id();
+        world.flush();
+
+        world.entity_mut(player).insert(Health);
+        world.entity_mut(enemy).insert(Health);
+
+        assert_eq!(world.resource::<Added>().0, vec![player]);
+
+        world.entity_mut(monitor).despawn();
+        world.flush();
+        world.entity_mut(player).remove::<Health>().insert(Health);
+
+        assert_eq!(world.resource::<Added>().0, vec![player]);
+    }
+
+    #[test]
+    fn adapt_for_many_targets() {
+        let mut world = World::new();
+
+        world.init_resource::<Added>();
+
+        let party = [world.spawn_empty().id(), world.spawn_empty().id()];
+        let enemy = world.spawn_empty().id();
+        world.spawn((
+            MonitorMany(party.to_vec()),
+            AdaptedObserver::on_add(record_added),
+        ));
+        // Watching no entities doesn't mean watching all of them.
+        world.spawn((
+            MonitorMany(Vec::new()),
+            AdaptedObserver::on_add(record_added),
+        ));
+        world.flush();
+
+        world.entity_mut(party[0]).insert(Health);
+        world.entity_mut(enemy).insert(Health);
+        world.entity_mut(party[1]).insert(Health);
+
+        assert_eq!(world.resource::<Added>().0, party);
+    }
+}
@@ -1,28 +1,107 @@
-use crate::prelude::*;
-use bevy_ecs::{lifecycle::HookContext, prelude::*, world::DeferredWorld};
-use std::marker::PhantomData;
+use crate::{
+    catch_up::register_catch_up,
+    change_id::ChangeIds,
+    dispatch::{Notifier, NotifyGate, component_ticks},
+    monitors::{Hierarchy, MonitorTargets},
+    payload::NotificationPayload,
+    prelude::*,
+    registry::{register_implicitly, register_teardown, unregister},
+    removal::Capture,
+    topology::register_watched,
+};
+use bevy_ecs::{
+    change_detection::{ComponentTicks, Tick},
+    component::Mutable,
+    lifecycle::HookContext,
+    prelude::*,
+    query::QueryFilter,
+    world::DeferredWorld,
+};
+use bevy_reflect::Reflect;
+use std::{marker::PhantomData, num::NonZeroU64};
 
 #[derive(Resource)]
-/// Used to indicate that the component [`C`] already has an observer detecting when it is added.
+/// Contains the observer detecting when the component [`C`] is added.
 struct DetectingAdded<C: Component> {
     observer: Entity,
     _phantom: PhantomData<C>,
 }
-#[derive(EntityEvent)]
+#[derive(EntityEvent, Message, Reflect, Clone, Debug)]
 /// Indicates that the component [`C`] has been added to an entity watched by a monitor.
 ///
 /// See [`NotifyAdded<C>`] for more information on how this is triggered.
+///
+/// [`Addition::value`] and the [`MonitorData`] aren't visible through reflection, see
+/// [`AppNotifyExt::register_notification_types`] for registering this type.
 pub struct Addition<C: Component> {
     pub entity: Entity,
     /// The [`Entity`] that [`C`] was added to.
     pub added: Entity,
+    /// The change tick at which this was triggered.
+    pub tick: Tick,
+    /// The change this was caused by, shared by every monitor notified about it.
+    pub change: ChangeId,
+    /// The ticks at which [`C`] was added to, and last changed on, the watched entity.
+    ///
+    /// Comparing these with [`Addition::tick`] allows notifications buffered across frames to be
+    /// deduplicated, ordered, or dropped once stale.
+    pub ticks: ComponentTicks,
+    /// Everything the monitor opted into, which is boxed to keep this event small.
+    #[reflect(ignore)]
+    payload: Option<Box<NotificationPayload<C>>>,
+    /// See [`Addition::sequence`].
+    sequence: Option<NonZeroU64>,
+    #[reflect(ignore)]
     _phantom: PhantomData<C>,
 }
+impl<C: Component> Addition<C> {
+    /// The value [`C`] was added with, this is `None` unless the monitor was created with
+    /// [`NotifyAdded::with_value`].
+    pub fn value(&self) -> Option<&C> {
+        self.payload.as_ref()?.value.as_ref()
+    }
+    /// The position of this notification among those about [`C`], this is `None` unless the
+    /// [`SequencedNotifications<C>`] resource exists.
+    pub fn sequence(&self) -> Option<u64> {
+        self.sequence.map(NonZeroU64::get)
+    }
+    /// When this was triggered, this is `None` unless the [`TimestampedNotifications`] resource
+    /// exists.
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.payload.as_ref()?.timestamp
+    }
+    /// The [`MonitorData`] of the monitor, if it has any.
+    pub fn data(&self) -> Option<&MonitorData> {
+        self.payload.as_ref()?.data.as_ref()
+    }
+    /// Reads [`C`] from the entity it was added to through `query`.
+    ///
+    /// This fails if [`C`] has been removed since, or the entity despawned.
+    pub fn component<'a, F: QueryFilter>(
+        &self,
+        query: &'a Query<'_, '_, &C, F>,
+    ) -> Result<&'a C, BevyError> {
+        Ok(query.get(self.added)?)
+    }
+    /// Mutably accesses [`C`] on the entity it was added to through `query`.
+    ///
+    /// This fails if [`C`] has been removed since, or the entity despawned.
+    pub fn component_mut<'a, F: QueryFilter>(
+        &self,
+        query: &'a mut Query<'_, '_, &mut C, F>,
+    ) -> Result<Mut<'a, C>, BevyError>
+    where
+        C: Component<Mutability = Mutable>,
+    {
+        Ok(query.get_mut(self.added)?)
+    }
+}
 
 #[derive(Component)]
 #[component(
     on_add = NotifyAdded::<C>::register_component_add_observer,
-    on_remove = NotifyAdded::<C>::remove_component_add_observer
+    on_remove = NotifyAdded::<C>::remove_component_add_observer,
+    clone_behavior = clone::<Self>()
 )]
 /// Adding this component to a entity will cause it to react to component [`C`] being added to
 /// an entity with [`Addition<C>`].
@@ -30,30 +109,64 @@ pub struct Addition<C: Component> {
 /// By default this will react to changes on **all** entities. See [`Monitor`], and [`MonitorSelf`]
 /// for restricting this.
 ///
+/// If the added value is needed, such as to show a tooltip for a picked up item, create this with
+/// [`NotifyAdded::with_value`] rather than querying for it.
+///
 /// # Technical info
 ///
 /// Adding this component to an entity will spawn an [`Observer`] for event [`On<Add, C>`], this is
 /// only done once.
 ///
 /// When all instances of this component in the world are removed the observer will be despawned.
-pub struct NotifyAdded<C: Component>(PhantomData<C>);
+pub struct NotifyAdded<C: Component> {
+    capture: Capture<C>,
+    _phantom: PhantomData<C>,
+}
 impl<C: Component> Default for NotifyAdded<C> {
     fn default() -> Self {
-        Self(PhantomData)
+        Self {
+            capture: Capture(None),
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Component> Clone for NotifyAdded<C> {
+    fn clone(&self) -> Self {
+        Self {
+            capture: self.capture,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Component + Clone> NotifyAdded<C> {
+    /// Includes the value [`C`] was added with in [`Addition::value`].
+    pub fn with_value() -> Self {
+        Self {
+            capture: Capture(Some(C::clone)),
+            _phantom: PhantomData,
+        }
     }
 }
 impl<C: Component> NotifyAdded<C> {
     fn register_component_add_observer(mut world: DeferredWorld, _: HookContext) {
-        if world.contains_resource::<DetectingAdded<C>>() {
-            return;
-        }
-
-        let mut commands = world.commands();
-        let observer = commands.add_observer(notify_on_add::<C>).id();
-        commands.insert_resource(DetectingAdded::<C> {
+        world
+            .commands()
+            .queue(|world: &mut World| register_implicitly::<Self>(world, Self::register));
+    }
+    /// Registers the observer detecting additions of [`C`], this is done by the first
+    /// monitor or by [`NotifyPlugin`].
+    ///
+    /// [`NotifyPlugin`]: crate::NotifyPlugin
+    pub(crate) fn register(world: &mut World) {
+        let observer = world.add_observer(notify_on_add::<C>).id();
+        world.insert_resource(DetectingAdded::<C> {
             observer,
             _phantom: PhantomData,
         });
+        world.init_resource::<ChangeIds>();
+        register_catch_up::<Self>(world, catch_up_added::<C>);
+        register_teardown::<Self>(world, Self::teardown);
+        register_watched::<Self, C>(world, NotificationKind::Added);
     }
     fn remove_component_add_observer(mut world: DeferredWorld, _: HookContext) {
         // # Safety
@@ -67,48 +180,131 @@ impl<C: Component> NotifyAdded<C> {
 
         if total_reactive == 0 {
             world.commands().queue(|world: &mut World| {
-                // # Safety
-                // In order for this component to be removed `NotifyAdded::register_component_add_observer` must have run which adds the `DetectingAdded` resource.
-                let DetectingAdded { observer, .. } =
-                    world.remove_resource::<DetectingAdded<C>>().unwrap();
-                world.entity_mut(observer).despawn();
+                unregister::<Self>(world, Self::teardown);
             });
         }
     }
+    /// Removes everything added by registering [`Self`].
+    pub(crate) fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `NotifyAdded::<C>` adds the `DetectingAdded` resource.
+        let DetectingAdded { observer, .. } = world.remove_resource::<DetectingAdded<C>>().unwrap();
+        world.entity_mut(observer).despawn();
+    }
 }
 
 pub(crate) fn notify_on_add<C: Component>(
     add: On<Add, C>,
-    mut commands: Commands,
-    local_monitors: Query<Entity, (With<NotifyAdded<C>>, With<MonitorSelf>)>,
-    monitors: Query<(Entity, &Monitor), With<NotifyAdded<C>>>,
-    global_monitors: Query<Entity, (With<NotifyAdded<C>>, Without<Monitor>, Without<MonitorSelf>)>,
+    mut notifier: Notifier,
+    mut gate: NotifyGate<C>,
+    suppressed: Option<Res<SuppressedNotifications<C>>>,
+    (deterministic, mut sequences): (
+        Option<Res<DeterministicNotifications>>,
+        Option<ResMut<SequencedNotifications<C>>>,
+    ),
+    captures: Query<&NotifyAdded<C>>,
+    (values, monitors, hierarchy): (
+        Query<Ref<C>>,
+        Query<MonitorTargets, With<NotifyAdded<C>>>,
+        Hierarchy,
+    ),
 ) {
-    if local_monitors.contains(add.entity) {
-        commands.trigger(Addition::<C> {
-            entity: add.entity,
-            added: add.entity,
-            _phantom: PhantomData,
-        });
+    let Ok(value) = values.get(add.entity) else {
+        return;
+    };
+    if suppressed.is_some() {
+        return;
     }
 
-    monitors
+    let mut monitors = monitors
         .iter()
-        .filter(|(_, Monitor(entity))| *entity == add.entity)
-        .for_each(|(entity, &Monitor(added))| {
-            commands.trigger(Addition::<C> {
+        .filter(|targets| targets.watches(add.entity, &hierarchy))
+        .map(|targets| targets.entity)
+        .collect::<Vec<_>>();
+
+    if deterministic.is_some() {
+        monitors.sort_unstable();
+    }
+
+    let change = notifier.next_change();
+    let ticks = component_ticks(&value);
+
+    monitors.into_iter().for_each(|entity| {
+        if !gate.allows(entity, add.entity) {
+            notifier.drop(entity);
+            return;
+        }
+
+        notifier.trigger_in_order(
+            Addition::<C> {
                 entity,
-                added,
+                added: add.entity,
+                tick: notifier.tick(),
+                change,
+                ticks,
+                payload: NotificationPayload {
+                    value: captures
+                        .get(entity)
+                        .ok()
+                        .and_then(|notify| notify.capture.0)
+                        .map(|capture| capture(&value)),
+                    timestamp: notifier.timestamp(),
+                    data: notifier.data(entity),
+                    ..Default::default()
+                }
+                .boxed(),
+                sequence: sequences.as_deref_mut().map(SequencedNotifications::next),
                 _phantom: PhantomData,
-            });
-        });
+            },
+            add.entity,
+            NotificationKind::Added,
+        );
+    });
+}
 
-    global_monitors.iter().for_each(|entity| {
-        commands.trigger(Addition::<C> {
-            entity,
-            added: add.entity,
-            _phantom: PhantomData,
-        });
+/// Triggers [`Addition<C>`] on `monitor` if `target` has [`C`].
+fn catch_up_added<C: Component>(world: &mut World, monitor: Entity, target: Entity) {
+    // The monitor may have been despawned in the meantime, in which case there is nothing to do.
+    let _ = world.run_system_cached_with(trigger_catch_up_addition::<C>, (monitor, target));
+}
+
+fn trigger_catch_up_addition<C: Component>(
+    In((monitor, target)): In<(Entity, Entity)>,
+    mut notifier: Notifier,
+    mut gate: NotifyGate<C>,
+    captures: Query<&NotifyAdded<C>>,
+    values: Query<Ref<C>>,
+    sequences: Option<ResMut<SequencedNotifications<C>>>,
+) {
+    let Ok(value) = values.get(target) else {
+        return;
+    };
+    if !gate.allows(monitor, target) {
+        notifier.drop(monitor);
+        return;
+    }
+
+    let change = notifier.next_change();
+
+    notifier.trigger(Addition::<C> {
+        entity: monitor,
+        added: target,
+        tick: notifier.tick(),
+        change,
+        ticks: component_ticks(&value),
+        payload: NotificationPayload {
+            value: captures
+                .get(monitor)
+                .ok()
+                .and_then(|notify| notify.capture.0)
+                .map(|capture| capture(&value)),
+            timestamp: notifier.timestamp(),
+            data: notifier.data(monitor),
+            ..Default::default()
+        }
+        .boxed(),
+        sequence: sequences.map(|mut sequences| sequences.next()),
+        _phantom: PhantomData,
     });
 }
 
@@ -164,4 +360,34 @@ mod tests {
 
         assert_eq!(world.resource::<TimesPoisoned>().0, 2);
     }
+
+    #[test]
+    fn check_added_value() {
+        #[derive(Component, Clone)]
+        pub struct Item {
+            weight: u32,
+        }
+
+        #[derive(Resource, Debug)]
+        pub struct Carried(u32);
+
+        let mut world = World::new();
+
+        world.insert_resource(Carried(0));
+
+        let player = world
+            .spawn((MonitorSelf, NotifyAdded::<Item>::with_value()))
+            .observe(
+                |addition: On<Addition<Item>>, mut carried: ResMut<Carried>| {
+                    if let Some(item) = addition.value() {
+                        carried.0 += item.weight;
+                    }
+                },
+            )
+            .id();
+
+        world.entity_mut(player).insert(Item { weight: 12 });
+
+        assert_eq!(world.resource::<Carried>().0, 12);
+    }
 }
@@ -0,0 +1,412 @@
+use crate::{
+    prelude::*,
+    registry::{register_once, register_teardown, unregister},
+};
+use bevy_ecs::{
+    component::Mutable, entity::EntityHashMap, lifecycle::HookContext, prelude::*,
+    world::DeferredWorld,
+};
+use std::marker::PhantomData;
+
+/// A component summarising [`C`] across the children of an entity, kept up to date by
+/// [`Aggregate<C, Self>`].
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// pub struct Weight(pub u32);
+///
+/// #[derive(Component, Default)]
+/// pub struct TotalWeight(pub u32);
+///
+/// impl Summary<Weight> for TotalWeight {
+///     type Contribution = u32;
+///
+///     fn contribution(weight: &Weight) -> u32 {
+///         weight.0
+///     }
+///     fn include(&mut self, weight: &u32) {
+///         self.0 += weight;
+///     }
+///     fn exclude(&mut self, weight: &u32) -> bool {
+///         self.0 -= weight;
+///         true
+///     }
+/// }
+/// ```
+///
+/// Summaries that can't undo a single contribution, such as the highest level among the children,
+/// return `false` from [`Summary::exclude`] to be rebuilt from the remaining contributions.
+pub trait Summary<C: Component>: Component<Mutability = Mutable> + Default {
+    /// What a single child contributes to the summary.
+    type Contribution: Send + Sync + 'static;
+
+    /// The contribution of a child with `value`.
+    fn contribution(value: &C) -> Self::Contribution;
+    /// Adds the contribution of a child to the summary.
+    fn include(&mut self, contribution: &Self::Contribution);
+    /// Removes the contribution of a child from the summary, returning `false` if the summary has
+    /// to be rebuilt instead.
+    fn exclude(&mut self, contribution: &Self::Contribution) -> bool;
+}
+
+#[derive(Resource)]
+/// Contains the monitor maintaining [`Aggregate<C, A>`], along with the parent each child
+/// contributes to.
+struct Aggregating<C: Component, A: Summary<C>> {
+    monitor: Entity,
+    reparenting: [Entity; 2],
+    parents: EntityHashMap<Entity>,
+    _phantom: PhantomData<(C, A)>,
+}
+
+#[derive(Component)]
+#[component(
+    on_add = Aggregate::<C, A>::register_aggregation,
+    on_remove = Aggregate::<C, A>::remove_aggregation
+)]
+#[require(A)]
+/// Adding this component to an entity keeps the summary [`A`] on it up to date with [`C`] on its
+/// children, replacing systems that reduce over every child each frame.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Weight(pub u32);
+/// # #[derive(Component, Default)]
+/// # pub struct TotalWeight(pub u32);
+/// # impl Summary<Weight> for TotalWeight {
+/// #     type Contribution = u32;
+/// #     fn contribution(weight: &Weight) -> u32 { weight.0 }
+/// #     fn include(&mut self, weight: &u32) { self.0 += weight; }
+/// #     fn exclude(&mut self, weight: &u32) -> bool { self.0 -= weight; true }
+/// # }
+/// # fn showcase(mut commands: Commands) {
+/// commands.spawn((
+///     Aggregate::<Weight, TotalWeight>::default(),
+///     children![Weight(10), Weight(25)],
+/// ));
+/// # }
+/// ```
+///
+/// [`A`] is updated incrementally as [`C`] is added to, changed on, or removed from children, and
+/// as children are added or removed, see [`Summary`].
+///
+/// # Technical info
+///
+/// Adding this component to an entity will spawn a global monitor for [`C`] along with
+/// [`Observer`]s for [`ChildOf`] being inserted and removed, this is only done once. Changes to
+/// [`C`] are therefore only reflected once [`MutationSet`] has run.
+///
+/// When all instances of this component in the world are removed the monitor and observers will
+/// be despawned.
+pub struct Aggregate<C: Component, A: Summary<C>> {
+    contributions: EntityHashMap<A::Contribution>,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component<Mutability = Mutable>, A: Summary<C>> Default for Aggregate<C, A> {
+    fn default() -> Self {
+        Self {
+            contributions: EntityHashMap::default(),
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Component, A: Summary<C>> Aggregate<C, A> {
+    /// Sets the contribution of `child`, replacing any it already had.
+    fn set(&mut self, summary: &mut A, child: Entity, contribution: A::Contribution) {
+        self.unset(summary, child);
+        summary.include(&contribution);
+        self.contributions.insert(child, contribution);
+    }
+    /// Removes the contribution of `child`, if it had one.
+    fn unset(&mut self, summary: &mut A, child: Entity) {
+        let Some(contribution) = self.contributions.remove(&child) else {
+            return;
+        };
+
+        if !summary.exclude(&contribution) {
+            *summary = A::default();
+            self.contributions
+                .values()
+                .for_each(|contribution| summary.include(contribution));
+        }
+    }
+    fn register_aggregation(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        world.commands().queue(move |world: &mut World| {
+            register_once::<Self>(world, |world| {
+                let monitor = world
+                    .spawn((
+                        NotifyAdded::<C>::default(),
+                        NotifyChanged::<C>::required(),
+                        NotifyRemoved::<C>::default(),
+                    ))
+                    .observe(aggregate_added::<C, A>)
+                    .observe(aggregate_changed::<C, A>)
+                    .observe(aggregate_removed::<C, A>)
+                    .id();
+                let reparenting = [
+                    world.add_observer(aggregate_adopted::<C, A>).id(),
+                    world.add_observer(aggregate_orphaned::<C, A>).id(),
+                ];
+
+                world.insert_resource(Aggregating::<C, A> {
+                    monitor,
+                    reparenting,
+                    parents: EntityHashMap::default(),
+                    _phantom: PhantomData,
+                });
+                register_teardown::<Self>(world, Self::teardown);
+            });
+
+            // Children that already have `C` are never added again.
+            let children = world
+                .get::<Children>(entity)
+                .map(|children| children.to_vec())
+                .unwrap_or_default();
+
+            children
+                .into_iter()
+                .for_each(|child| adopt::<C, A>(world, child, entity));
+        });
+    }
+    fn remove_aggregation(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_aggregates = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        world.commands().queue(move |world: &mut World| {
+            if let Some(mut aggregating) = world.get_resource_mut::<Aggregating<C, A>>() {
+                aggregating
+                    .parents
+                    .retain(|_, &mut parent| parent != entity);
+            }
+            if total_aggregates == 0 {
+                unregister::<Self>(world, Self::teardown);
+            }
+        });
+    }
+    fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `Aggregate::<C, A>` adds the `Aggregating` resource.
+        let Aggregating {
+            monitor,
+            reparenting,
+            ..
+        } = world.remove_resource::<Aggregating<C, A>>().unwrap();
+
+        world.entity_mut(monitor).despawn();
+        reparenting.into_iter().for_each(|observer| {
+            world.entity_mut(observer).despawn();
+        });
+    }
+}
+
+/// Makes `child` contribute to `parent`, if it has [`C`] and `parent` has [`Aggregate<C, A>`].
+fn adopt<C: Component, A: Summary<C>>(world: &mut World, child: Entity, parent: Entity) {
+    let Some(contribution) = world.get::<C>(child).map(A::contribution) else {
+        return;
+    };
+    let Ok(mut parent_mut) = world.get_entity_mut(parent) else {
+        return;
+    };
+    let Some((mut aggregate, mut summary)) = parent_mut
+        .get_components_mut::<(&mut Aggregate<C, A>, &mut A)>()
+        .ok()
+    else {
+        return;
+    };
+
+    aggregate.set(&mut summary, child, contribution);
+    world
+        .resource_mut::<Aggregating<C, A>>()
+        .parents
+        .insert(child, parent);
+}
+
+/// Stops `child` from contributing to the parent it was contributing to.
+fn orphan<C: Component, A: Summary<C>>(world: &mut World, child: Entity) {
+    let Some(parent) = world
+        .resource_mut::<Aggregating<C, A>>()
+        .parents
+        .remove(&child)
+    else {
+        return;
+    };
+    let Ok(mut parent) = world.get_entity_mut(parent) else {
+        return;
+    };
+
+    if let Ok((mut aggregate, mut summary)) =
+        parent.get_components_mut::<(&mut Aggregate<C, A>, &mut A)>()
+    {
+        aggregate.unset(&mut summary, child);
+    }
+}
+
+fn aggregate_added<C: Component, A: Summary<C>>(addition: On<Addition<C>>, mut commands: Commands) {
+    let child = addition.added;
+
+    commands.queue(move |world: &mut World| {
+        if let Some(&ChildOf(parent)) = world.get::<ChildOf>(child) {
+            adopt::<C, A>(world, child, parent);
+        }
+    });
+}
+
+fn aggregate_changed<C: Component, A: Summary<C>>(
+    mutation: On<Mutation<C>>,
+    mut commands: Commands,
+) {
+    let child = mutation.mutated;
+
+    commands.queue(move |world: &mut World| {
+        if let Some(&ChildOf(parent)) = world.get::<ChildOf>(child) {
+            adopt::<C, A>(world, child, parent);
+        }
+    });
+}
+
+fn aggregate_removed<C: Component, A: Summary<C>>(removal: On<Removal<C>>, mut commands: Commands) {
+    let child = removal.removed;
+
+    commands.queue(move |world: &mut World| {
+        // The removal may be delivered after `C` was inserted again, such as when it's replaced or
+        // while notifications are ordered, in which case the child still contributes.
+        if world.get::<C>(child).is_some()
+            && let Some(&ChildOf(parent)) = world.get::<ChildOf>(child)
+        {
+            adopt::<C, A>(world, child, parent);
+        } else {
+            orphan::<C, A>(world, child);
+        }
+    });
+}
+
+fn aggregate_adopted<C: Component, A: Summary<C>>(
+    insert: On<Insert, ChildOf>,
+    mut commands: Commands,
+) {
+    let child = insert.entity;
+
+    commands.queue(move |world: &mut World| {
+        orphan::<C, A>(world, child);
+
+        if let Some(&ChildOf(parent)) = world.get::<ChildOf>(child) {
+            adopt::<C, A>(world, child, parent);
+        }
+    });
+}
+
+fn aggregate_orphaned<C: Component, A: Summary<C>>(
+    remove: On<Remove, ChildOf>,
+    mut commands: Commands,
+) {
+    let child = remove.entity;
+
+    commands.queue(move |world: &mut World| orphan::<C, A>(world, child));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Level(u32);
+
+    #[derive(Component, Default)]
+    pub struct HighestLevel(u32);
+
+    impl Summary<Level> for HighestLevel {
+        type Contribution = u32;
+
+        fn contribution(level: &Level) -> u32 {
+            level.0
+        }
+        fn include(&mut self, level: &u32) {
+            self.0 = self.0.max(*level);
+        }
+        fn exclude(&mut self, level: &u32) -> bool {
+            *level < self.0
+        }
+    }
+
+    #[test]
+    fn maintain_summary() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+
+        let party = world
+            .spawn((
+                Aggregate::<Level, HighestLevel>::default(),
+                children![Level(3), Level(7)],
+            ))
+            .id();
+        world.flush();
+
+        let highest = |world: &World| world.get::<HighestLevel>(party).unwrap().0;
+
+        assert_eq!(highest(&world), 7);
+
+        let recruit = world.spawn((Level(9), ChildOf(party))).id();
+        world.flush();
+
+        assert_eq!(highest(&world), 9);
+
+        world.get_mut::<Level>(recruit).unwrap().0 = 1;
+        world.run_schedule(Update);
+
+        assert_eq!(highest(&world), 7);
+
+        let veteran = world.spawn(Level(12)).id();
+        world.entity_mut(veteran).insert(ChildOf(party));
+        world.flush();
+
+        assert_eq!(highest(&world), 12);
+
+        world.entity_mut(veteran).despawn();
+        world.flush();
+
+        assert_eq!(highest(&world), 7);
+    }
+
+    #[test]
+    fn keep_reinserted_children() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<OrderedNotifications>();
+
+        let party = world
+            .spawn((
+                Aggregate::<Level, HighestLevel>::default(),
+                children![Level(3)],
+            ))
+            .id();
+        let recruit = world.spawn((Level(9), ChildOf(party))).id();
+        world.run_schedule(Update);
+
+        let highest = |world: &World| world.get::<HighestLevel>(party).unwrap().0;
+
+        assert_eq!(highest(&world), 9);
+
+        world.entity_mut(recruit).remove::<Level>().insert(Level(9));
+        world.run_schedule(Update);
+
+        assert_eq!(highest(&world), 9);
+
+        world.entity_mut(recruit).insert(Level(11));
+        world.run_schedule(Update);
+
+        assert_eq!(highest(&world), 11);
+    }
+}
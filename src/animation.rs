@@ -0,0 +1,150 @@
+use bevy_animation::{AnimationPlayer, graph::AnimationNodeIndex};
+use bevy_ecs::{lifecycle::HookContext, prelude::*, world::DeferredWorld};
+use std::marker::PhantomData;
+
+#[derive(Component)]
+#[component(
+    on_add = AnimateOnNotify::<E>::attach_observer,
+    on_remove = AnimateOnNotify::<E>::detach_observer
+)]
+/// Adding this component to a monitor will start an animation whenever it receives the
+/// notification [`E`], such as flashing a health bar red when [`Mutation<Health>`] is received.
+///
+/// The animation is started from the beginning on the [`AnimationPlayer`] of the `player` entity,
+/// even if it is already playing.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::{animation::graph::AnimationNodeIndex, prelude::*};
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity, health_bar: Entity, flash: AnimationNodeIndex) {
+/// commands.spawn((
+///     Monitor(player),
+///     NotifyChanged::<Health>::default(),
+///     AnimateOnNotify::<Mutation<Health>>::new(health_bar, flash),
+/// ));
+/// # }
+/// ```
+///
+/// # Technical info
+///
+/// Adding this component to an entity will spawn an [`Observer`] watching that entity for [`E`],
+/// it is despawned when this component is removed.
+///
+/// [`Mutation<Health>`]: crate::prelude::Mutation
+pub struct AnimateOnNotify<E: EntityEvent> {
+    /// The entity containing the [`AnimationPlayer`].
+    pub player: Entity,
+    /// The animation to start.
+    pub animation: AnimationNodeIndex,
+    observer: Option<Entity>,
+    _phantom: PhantomData<E>,
+}
+impl<E: EntityEvent> AnimateOnNotify<E> {
+    /// Start `animation` on the [`AnimationPlayer`] of `player`.
+    pub fn new(player: Entity, animation: AnimationNodeIndex) -> Self {
+        Self {
+            player,
+            animation,
+            observer: None,
+            _phantom: PhantomData,
+        }
+    }
+    fn attach_observer(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let observer = world
+            .commands()
+            .spawn(Observer::new(animate_on_notify::<E>).with_entity(entity))
+            .id();
+
+        if let Some(mut animate) = world.get_mut::<Self>(entity) {
+            animate.observer = Some(observer);
+        }
+    }
+    fn detach_observer(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let Some(observer) = world
+            .get::<Self>(entity)
+            .and_then(|animate| animate.observer)
+        else {
+            return;
+        };
+
+        world.commands().entity(observer).try_despawn();
+    }
+}
+
+fn animate_on_notify<E: EntityEvent>(
+    notification: On<E>,
+    animate: Query<&AnimateOnNotify<E>>,
+    mut players: Query<&mut AnimationPlayer>,
+) -> Result<(), BevyError> {
+    let animate = animate.get(notification.event_target())?;
+
+    players.get_mut(animate.player)?.start(animate.animation);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::{animation::graph::AnimationNodeIndex, prelude::*};
+
+    #[derive(Component)]
+    pub struct Poisoned;
+
+    #[test]
+    fn flash_when_poisoned() {
+        let mut world = World::new();
+
+        let flash = AnimationNodeIndex::new(1);
+
+        let player = world.spawn_empty().id();
+        let health_bar = world.spawn(AnimationPlayer::default()).id();
+
+        let monitor = world
+            .spawn((
+                Monitor(player),
+                NotifyAdded::<Poisoned>::default(),
+                AnimateOnNotify::<Addition<Poisoned>>::new(health_bar, flash),
+            ))
+            .id();
+
+        assert!(
+            !world
+                .get::<AnimationPlayer>(health_bar)
+                .unwrap()
+                .is_playing_animation(flash)
+        );
+
+        world.entity_mut(player).insert(Poisoned);
+
+        assert!(
+            world
+                .get::<AnimationPlayer>(health_bar)
+                .unwrap()
+                .is_playing_animation(flash)
+        );
+
+        // Stop reacting.
+
+        world
+            .get_mut::<AnimationPlayer>(health_bar)
+            .unwrap()
+            .stop_all();
+        world
+            .entity_mut(monitor)
+            .remove::<AnimateOnNotify<Addition<Poisoned>>>();
+        world
+            .entity_mut(player)
+            .remove::<Poisoned>()
+            .insert(Poisoned);
+
+        assert!(
+            !world
+                .get::<AnimationPlayer>(health_bar)
+                .unwrap()
+                .is_playing_animation(flash)
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::Arc,
+};
+
+/// Delivers notifications of type [`E`], such as [`Mutation<C>`], replacing the default of
+/// triggering them as entity events.
+///
+/// This makes it possible to use the crate's change detection where Bevy observers aren't the
+/// right sink, such as a custom queue, a lock-free ring buffer, or another process. See
+/// [`CustomDispatch`] for how to register one.
+///
+/// This is implemented for any `Fn(&mut World, E)`.
+///
+/// [`Mutation<C>`]: crate::prelude::Mutation
+pub trait NotificationDispatcher<E: EntityEvent>: Send + Sync + 'static {
+    /// Delivers `notification`, which has already passed all of the monitor's filters.
+    fn dispatch(&self, world: &mut World, notification: E);
+}
+
+impl<E: EntityEvent, F: Fn(&mut World, E) + Send + Sync + 'static> NotificationDispatcher<E> for F {
+    fn dispatch(&self, world: &mut World, notification: E) {
+        self(world, notification);
+    }
+}
+
+#[derive(Resource, Default)]
+/// Contains the [`NotificationDispatcher`] registered for each notification type, keyed by the
+/// [`TypeId`] of the notification.
+struct Dispatchers(HashMap<TypeId, Box<dyn Any + Send + Sync>>);
+
+/// Allows replacing how a notification type is delivered.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// #[derive(Resource, Default)]
+/// struct HealthChanges(Vec<Entity>);
+///
+/// # let mut app = App::new();
+/// app.init_resource::<HealthChanges>()
+///     .dispatch_with(|world: &mut World, mutation: Mutation<Health>| {
+///         world.resource_mut::<HealthChanges>().0.push(mutation.mutated);
+///     });
+/// ```
+pub trait CustomDispatch {
+    /// Delivers every [`E`] through `dispatcher` rather than triggering it, replacing any
+    /// dispatcher previously registered for [`E`].
+    fn dispatch_with<E: EntityEvent>(
+        &mut self,
+        dispatcher: impl NotificationDispatcher<E>,
+    ) -> &mut Self;
+}
+
+impl CustomDispatch for World {
+    fn dispatch_with<E: EntityEvent>(
+        &mut self,
+        dispatcher: impl NotificationDispatcher<E>,
+    ) -> &mut Self {
+        let dispatcher: Arc<dyn NotificationDispatcher<E>> = Arc::new(dispatcher);
+
+        self.get_resource_or_init::<Dispatchers>()
+            .0
+            .insert(TypeId::of::<E>(), Box::new(dispatcher));
+        self
+    }
+}
+
+impl CustomDispatch for App {
+    fn dispatch_with<E: EntityEvent>(
+        &mut self,
+        dispatcher: impl NotificationDispatcher<E>,
+    ) -> &mut Self {
+        self.world_mut().dispatch_with(dispatcher);
+        self
+    }
+}
+
+/// Delivers `notification` through the [`NotificationDispatcher`] registered for [`E`], or
+/// triggers it if there is none.
+pub(crate) fn dispatch<'t, E: EntityEvent<Trigger<'t>: Default>>(
+    world: &mut World,
+    notification: E,
+) {
+    // Cloned out so the dispatcher is free to register more dispatchers.
+    let dispatcher = world
+        .get_resource::<Dispatchers>()
+        .and_then(|dispatchers| dispatchers.0.get(&TypeId::of::<E>()))
+        .and_then(|dispatcher| dispatcher.downcast_ref::<Arc<dyn NotificationDispatcher<E>>>())
+        .cloned();
+
+    match dispatcher {
+        Some(dispatcher) => dispatcher.dispatch(world, notification),
+        None => {
+            world.trigger(notification);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Queue(Vec<Entity>);
+
+    #[derive(Resource, Default)]
+    pub struct Observed(usize);
+
+    #[test]
+    fn deliver_into_queue() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Queue>();
+        world.init_resource::<Observed>();
+        world.dispatch_with(|world: &mut World, mutation: Mutation<Health>| {
+            world.resource_mut::<Queue>().0.push(mutation.mutated);
+        });
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                NotifyRemoved::<Health>::default(),
+            ))
+            .observe(|_: On<Mutation<Health>>, mut observed: ResMut<Observed>| {
+                observed.0 += 1;
+            })
+            .observe(|_: On<Removal<Health>>, mut observed: ResMut<Observed>| {
+                observed.0 += 1;
+            });
+
+        world.run_schedule(Update);
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+        world.entity_mut(player).remove::<Health>();
+
+        assert_eq!(world.resource::<Queue>().0, vec![player, player]);
+        // Only the removal used the default dispatcher.
+        assert_eq!(world.resource::<Observed>().0, 1);
+    }
+}
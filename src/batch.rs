@@ -0,0 +1,198 @@
+use crate::{
+    dispatch::Notifier,
+    mutation::{NotifySet, configure_notify_sets},
+    prelude::*,
+    registry::{add_systems, register_once, register_teardown, unregister},
+};
+use bevy_app::Update;
+use bevy_ecs::{
+    change_detection::Tick, component::Mutable, lifecycle::HookContext, prelude::*,
+    schedule::ScheduleCleanupPolicy, system::SystemChangeTick, world::DeferredWorld,
+};
+use std::marker::PhantomData;
+
+#[derive(Component, Debug)]
+#[component(
+    on_add = BatchMutations::<C>::register_batch_system,
+    on_remove = BatchMutations::<C>::remove_batch_system
+)]
+#[require(NotifyChanged<C> = NotifyChanged::required())]
+/// Adding this component to a monitor will merge the [`Mutation<C>`] it would receive for each
+/// changed entity into a single [`MutationBatch<C>`] per frame, listing every entity that changed.
+///
+/// This is meant for monitors watching many entities, such as a global monitor driving a UI list
+/// that is rebuilt once rather than once per changed row.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands) {
+/// commands
+///     .spawn(BatchMutations::<Health>::default())
+///     .observe(|batch: On<MutationBatch<Health>>| {
+///         println!("{} entities changed their health", batch.mutated.len());
+///     });
+/// # }
+/// ```
+///
+/// # Technical info
+///
+/// Adding this component to an entity will add a system to the [`Update`] schedule that runs in
+/// [`NotifySet::Dispatch`], this is only done once per component type.
+///
+/// When all instances of this component in the world are removed the system will be removed.
+pub struct BatchMutations<C: Component> {
+    pending: Vec<Entity>,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component<Mutability = Mutable>> Default for BatchMutations<C> {
+    fn default() -> Self {
+        Self {
+            pending: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Component> Clone for BatchMutations<C> {
+    fn clone(&self) -> Self {
+        Self {
+            pending: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Component> BatchMutations<C> {
+    /// Records a change to [`C`] on `mutated`, which is only listed once per batch.
+    pub(crate) fn push(&mut self, mutated: Entity) {
+        if !self.pending.contains(&mutated) {
+            self.pending.push(mutated);
+        }
+    }
+    fn register_batch_system(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                configure_notify_sets(world, Update);
+                add_systems(
+                    world,
+                    Update,
+                    deliver_batches::<C>.in_set(NotifySet::Dispatch),
+                );
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_batch_system(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_batching = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_batching == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        world.schedule_scope(Update, |world, schedule| {
+            // # Safety
+            // `BatchMutations::<C>` being registered ensures this system exists in the `Update`
+            // schedule.
+            schedule
+                .remove_systems_in_set(
+                    deliver_batches::<C>,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+    }
+}
+
+#[derive(EntityEvent, Clone, Debug)]
+/// Contains every entity a monitor with [`BatchMutations<C>`] saw [`C`] change on this frame.
+pub struct MutationBatch<C: Component> {
+    pub entity: Entity,
+    /// The entities that [`C`] changed on, in the order the changes were detected.
+    pub mutated: Vec<Entity>,
+    /// The change tick at which this was triggered.
+    pub tick: Tick,
+    _phantom: PhantomData<C>,
+}
+
+fn deliver_batches<C: Component>(
+    mut notifier: Notifier,
+    ticks: SystemChangeTick,
+    mut monitors: Query<(Entity, &mut BatchMutations<C>)>,
+) {
+    monitors.iter_mut().for_each(|(entity, mut batch)| {
+        if batch.pending.is_empty() {
+            return;
+        }
+
+        notifier.trigger(MutationBatch::<C> {
+            entity,
+            mutated: std::mem::take(&mut batch.pending),
+            tick: ticks.this_run(),
+            _phantom: PhantomData,
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Rebuilds(Vec<Vec<Entity>>);
+
+    #[test]
+    fn one_batch_per_frame() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Rebuilds>();
+
+        let players = [(); 3].map(|_| world.spawn(Health(100)).id());
+        let list = world
+            .spawn((BatchMutations::<Health>::default(), MonitorStats::default()))
+            .observe(
+                |batch: On<MutationBatch<Health>>, mut rebuilds: ResMut<Rebuilds>| {
+                    rebuilds.0.push(batch.mutated.clone());
+                },
+            )
+            .id();
+
+        world.run_schedule(Update);
+        world.resource_mut::<Rebuilds>().0.clear();
+
+        world.get_mut::<Health>(players[0]).unwrap().0 -= 10;
+        world.get_mut::<Health>(players[2]).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        let mut rebuilds = world.resource::<Rebuilds>().0.clone();
+        rebuilds.iter_mut().for_each(|batch| batch.sort_unstable());
+
+        let mut changed = vec![players[0], players[2]];
+        changed.sort_unstable();
+
+        assert_eq!(rebuilds, vec![changed]);
+
+        // Frames without changes don't trigger a batch.
+
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Rebuilds>().0.len(), 1);
+        assert_eq!(world.get::<MonitorStats>(list).unwrap().coalesced, 5);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::prelude::*;
+use bevy_app::App;
+use bevy_ecs::{component::Mutable, prelude::*};
+use std::marker::PhantomData;
+
+#[derive(Resource)]
+/// The number of [`Mutation<C>`] delivered in a [`StressHarness<C>`].
+struct StressDelivered<C: Component> {
+    delivered: usize,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component> Default for StressDelivered<C> {
+    fn default() -> Self {
+        Self {
+            delivered: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Builds an [`App`] with many monitored entities and monitors, and mutates them at a fixed rate,
+/// allowing the cost of a monitor configuration to be measured before shipping it.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component, Clone)]
+/// # pub struct Health(pub u8);
+/// let mut harness = StressHarness::<Health>::default()
+///     .with_targets(1_000, Health(100))
+///     .with_monitors(2, MonitorStats::default)
+///     .mutating(100);
+///
+/// // Targets count as changed the first time they are seen.
+/// harness.step();
+///
+/// // Inside of a benchmark.
+/// harness.step();
+///
+/// assert_eq!(harness.delivered(), 2_000 + 200);
+/// ```
+///
+/// Every monitor watches a single target with [`NotifyChanged<C>`], along with whatever bundle is
+/// given to [`StressHarness::with_monitors`]. Targets are mutated in turn, so every target is
+/// mutated once every `targets / per_frame` steps.
+///
+/// The first step delivers a mutation to every monitor, so benchmarks should step once before
+/// measuring.
+pub struct StressHarness<C: Component> {
+    app: App,
+    targets: Vec<Entity>,
+    monitors: Vec<Entity>,
+    per_frame: usize,
+    next: usize,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component> Default for StressHarness<C> {
+    fn default() -> Self {
+        let mut app = App::new();
+
+        app.init_resource::<StressDelivered<C>>().add_observer(
+            |_: On<Mutation<C>>, mut delivered: ResMut<StressDelivered<C>>| {
+                delivered.delivered += 1;
+            },
+        );
+
+        Self {
+            app,
+            targets: Vec::new(),
+            monitors: Vec::new(),
+            per_frame: 0,
+            next: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Component> StressHarness<C> {
+    /// Spawns `count` entities with `value`.
+    pub fn with_targets(mut self, count: usize, value: C) -> Self
+    where
+        C: Clone,
+    {
+        let world = self.app.world_mut();
+
+        self.targets
+            .extend((0..count).map(|_| world.spawn(value.clone()).id()));
+        self
+    }
+    /// Spawns `per_target` monitors for each target, along with the bundle returned by `bundle`.
+    pub fn with_monitors<B: Bundle>(mut self, per_target: usize, bundle: impl Fn() -> B) -> Self
+    where
+        C: Component<Mutability = Mutable>,
+    {
+        let world = self.app.world_mut();
+
+        self.monitors
+            .extend(self.targets.iter().flat_map(|&target| {
+                (0..per_target)
+                    .map(|_| {
+                        world
+                            .spawn((Monitor(target), NotifyChanged::<C>::default(), bundle()))
+                            .id()
+                    })
+                    .collect::<Vec<_>>()
+            }));
+        self
+    }
+    /// Mutates `per_frame` targets every [`StressHarness::step`].
+    pub fn mutating(mut self, per_frame: usize) -> Self {
+        self.per_frame = per_frame;
+        self
+    }
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+    pub fn targets(&self) -> &[Entity] {
+        &self.targets
+    }
+    pub fn monitors(&self) -> &[Entity] {
+        &self.monitors
+    }
+    /// The number of [`Mutation<C>`] delivered so far.
+    pub fn delivered(&self) -> usize {
+        self.app.world().resource::<StressDelivered<C>>().delivered
+    }
+    /// Marks the next targets as changed, without updating the app.
+    pub fn mutate(&mut self)
+    where
+        C: Component<Mutability = Mutable>,
+    {
+        if self.targets.is_empty() {
+            return;
+        }
+
+        let world = self.app.world_mut();
+
+        (0..self.per_frame).for_each(|_| {
+            let target = self.targets[self.next % self.targets.len()];
+
+            if let Some(mut value) = world.get_mut::<C>(target) {
+                value.set_changed();
+            }
+            self.next += 1;
+        });
+    }
+    /// Mutates the next targets and updates the app once.
+    pub fn step(&mut self)
+    where
+        C: Component<Mutability = Mutable>,
+    {
+        self.mutate();
+        self.app.update();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Clone)]
+    pub struct Health;
+
+    #[test]
+    fn mutate_at_rate() {
+        let mut harness = StressHarness::<Health>::default()
+            .with_targets(10, Health)
+            .with_monitors(3, MonitorStats::default)
+            .mutating(4);
+
+        assert_eq!(harness.monitors().len(), 30);
+
+        // Every target counts as changed the first time the app updates.
+        harness.step();
+        harness.step();
+
+        assert_eq!(harness.delivered(), 30 + 12);
+        assert_eq!(
+            harness
+                .app_mut()
+                .world_mut()
+                .query::<&MonitorStats>()
+                .iter(harness.app().world())
+                .map(|stats| stats.received)
+                .sum::<u64>(),
+            42
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    dispatch::Notifier,
+    payload::NotificationPayload,
+    prelude::*,
+    registry::{register_once, register_teardown, unregister},
+};
+use bevy_ecs::{
+    component::Mutable,
+    entity::{EntityHashMap, EntityHashSet},
+    lifecycle::HookContext,
+    prelude::*,
+    world::DeferredWorld,
+};
+use std::marker::PhantomData;
+
+#[derive(Resource)]
+/// Contains the hidden monitor watching [`C`] and the subscribers of each source entity.
+struct Broadcaster<C: Component> {
+    dispatcher: Entity,
+    subscribers: EntityHashMap<EntityHashSet>,
+    _phantom: PhantomData<C>,
+}
+
+#[derive(Component)]
+/// Marks the hidden monitor used by [`SubscribeTo`].
+struct BroadcastDispatcher;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[component(
+    on_add = SubscribeTo::<C>::register_dispatcher,
+    on_insert = SubscribeTo::<C>::subscribe,
+    on_replace = SubscribeTo::<C>::unsubscribe,
+    on_remove = SubscribeTo::<C>::remove_dispatcher
+)]
+/// Adding this component to an entity will cause it to receive [`Mutation<C>`] whenever [`C`]
+/// changes on `source`, without the entity having to be a monitor itself.
+///
+/// This is much cheaper than a monitor per entity when many entities, such as hundreds of small UI
+/// elements, are interested in the same source.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Gold(pub u32);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// for _ in 0..100 {
+///     commands
+///         .spawn(SubscribeTo::<Gold>::new(player))
+///         .observe(|mutation: On<Mutation<Gold>>| {
+///             // Update the price label.
+///         });
+/// }
+/// # }
+/// ```
+///
+/// Subscribers are not affected by per-monitor components such as [`NotifyPipeline<C>`] or
+/// [`MonitorStats`], only by [`SuppressedNotifications<C>`].
+///
+/// # Technical info
+///
+/// Adding this component to an entity will spawn a hidden global monitor for [`C`] with an
+/// [`Observer`] fanning its notifications out to subscribers, this is only done once.
+///
+/// When all instances of this component in the world are removed the monitor will be despawned.
+pub struct SubscribeTo<C: Component> {
+    /// The entity whose changes are received.
+    pub source: Entity,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component> SubscribeTo<C> {
+    /// Receives changes to [`C`] on `source`.
+    pub fn new(source: Entity) -> Self
+    where
+        C: Component<Mutability = Mutable>,
+    {
+        Self {
+            source,
+            _phantom: PhantomData,
+        }
+    }
+    fn register_dispatcher(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                let dispatcher = world
+                    .spawn((BroadcastDispatcher, NotifyChanged::<C>::required()))
+                    .observe(fan_out::<C>)
+                    .id();
+                world.insert_resource(Broadcaster::<C> {
+                    dispatcher,
+                    subscribers: EntityHashMap::default(),
+                    _phantom: PhantomData,
+                });
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn subscribe(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let Some(&SubscribeTo { source, .. }) = world.get::<Self>(entity) else {
+            return;
+        };
+
+        world.commands().queue(move |world: &mut World| {
+            if let Some(mut broadcaster) = world.get_resource_mut::<Broadcaster<C>>() {
+                broadcaster
+                    .subscribers
+                    .entry(source)
+                    .or_default()
+                    .insert(entity);
+            }
+        });
+    }
+    fn unsubscribe(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let Some(&SubscribeTo { source, .. }) = world.get::<Self>(entity) else {
+            return;
+        };
+
+        world.commands().queue(move |world: &mut World| {
+            let Some(mut broadcaster) = world.get_resource_mut::<Broadcaster<C>>() else {
+                return;
+            };
+            let Some(subscribers) = broadcaster.subscribers.get_mut(&source) else {
+                return;
+            };
+
+            subscribers.remove(&entity);
+            if subscribers.is_empty() {
+                broadcaster.subscribers.remove(&source);
+            }
+        });
+    }
+    fn remove_dispatcher(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_subscribers = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_subscribers == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `SubscribeTo::<C>` adds the `Broadcaster` resource.
+        let Broadcaster { dispatcher, .. } = world.remove_resource::<Broadcaster<C>>().unwrap();
+        world.entity_mut(dispatcher).despawn();
+    }
+}
+
+fn fan_out<C: Component>(
+    mutation: On<Mutation<C>>,
+    mut notifier: Notifier,
+    broadcaster: Res<Broadcaster<C>>,
+    snapshots: Query<&NotifyWithValue<C>>,
+    mut previous: Query<&mut PreviousValue<C>>,
+    values: Query<&C>,
+    mut sequences: Option<ResMut<SequencedNotifications<C>>>,
+) {
+    let Some(subscribers) = broadcaster.subscribers.get(&mutation.mutated) else {
+        return;
+    };
+
+    subscribers.iter().for_each(|&entity| {
+        notifier.trigger(Mutation::<C> {
+            entity,
+            mutated: mutation.mutated,
+            tick: mutation.tick,
+            change: mutation.change,
+            ticks: mutation.ticks,
+            is_added: mutation.is_added,
+            payload: NotificationPayload {
+                diff: None,
+                value: snapshots
+                    .get(entity)
+                    .ok()
+                    .zip(values.get(mutation.mutated).ok())
+                    .map(|(snapshot, value)| snapshot.snapshot(value)),
+                previous: previous
+                    .get_mut(entity)
+                    .ok()
+                    .zip(values.get(mutation.mutated).ok())
+                    .and_then(|(mut previous, value)| previous.replace(mutation.mutated, value)),
+                version: mutation.version(),
+                sequence: sequences
+                    .as_deref_mut()
+                    .map(|sequences| sequences.next().get()),
+                timestamp: notifier.timestamp(),
+                times_changed: mutation
+                    .payload
+                    .as_ref()
+                    .and_then(|payload| payload.times_changed),
+                data: notifier.data(entity),
+                changed_by: mutation.changed_by(),
+                provenance: mutation
+                    .payload
+                    .as_ref()
+                    .and_then(|payload| payload.provenance),
+                via_reflection: mutation.via_reflection(),
+            }
+            .boxed(),
+            _phantom: PhantomData,
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Gold(u32);
+
+    #[derive(Resource, Default)]
+    pub struct Labels(Vec<Entity>);
+
+    #[test]
+    fn fan_out_to_subscribers() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Labels>();
+
+        let player = world.spawn(Gold(10)).id();
+        let merchant = world.spawn(Gold(500)).id();
+
+        let mut labels = [(); 3].map(|_| {
+            world
+                .spawn(SubscribeTo::<Gold>::new(player))
+                .observe(|mutation: On<Mutation<Gold>>, mut labels: ResMut<Labels>| {
+                    labels.0.push(mutation.entity);
+                })
+                .id()
+        });
+
+        world.run_schedule(Update);
+        world.resource_mut::<Labels>().0.clear();
+
+        world.get_mut::<Gold>(player).unwrap().0 += 5;
+        world.get_mut::<Gold>(merchant).unwrap().0 -= 5;
+        world.run_schedule(Update);
+
+        let mut notified = world.resource::<Labels>().0.clone();
+        notified.sort_unstable();
+        labels.sort_unstable();
+
+        assert_eq!(notified, labels.to_vec());
+
+        // Subscribers can switch source.
+
+        world.resource_mut::<Labels>().0.clear();
+        world
+            .entity_mut(labels[0])
+            .insert(SubscribeTo::<Gold>::new(merchant));
+
+        world.get_mut::<Gold>(merchant).unwrap().0 -= 5;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Labels>().0, vec![labels[0]]);
+    }
+}
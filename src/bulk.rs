@@ -0,0 +1,208 @@
+use crate::{
+    mutation::MutationSet,
+    prelude::*,
+    registry::{add_systems, register_once, register_teardown, unregister},
+};
+use bevy_app::Update;
+use bevy_ecs::{
+    component::Mutable, entity::EntityHashSet, lifecycle::HookContext, prelude::*,
+    query::QueryFilter, schedule::ScheduleCleanupPolicy, world::DeferredWorld,
+};
+use std::marker::PhantomData;
+
+#[derive(Component)]
+#[component(
+    on_add = MonitorSet::<C, F>::register_sync_system,
+    on_remove = MonitorSet::<C, F>::tear_down
+)]
+/// The handle to a set of self-monitors created with [`MonitorAll::monitor_all`].
+///
+/// Every entity with [`C`] matching the filter [`F`] is given [`MonitorSelf`] and
+/// [`NotifyChanged<C>`], which are kept in sync as entities start and stop matching. Despawning the
+/// entity this component is on, or removing this component, tears the whole set down.
+///
+/// Only [`NotifyChanged<C>`] is removed from entities leaving the set, [`MonitorSelf`] is left as it
+/// may be used by other monitoring.
+///
+/// # Technical info
+///
+/// Adding this component to an entity will add a system to the [`Update`] schedule that runs before
+/// [`MutationSet`], this is only done once.
+///
+/// When all instances of this component in the world are removed the system will be removed.
+pub struct MonitorSet<C: Component, F: QueryFilter + 'static> {
+    members: EntityHashSet,
+    _phantom: PhantomData<fn() -> (C, F)>,
+}
+impl<C: Component, F: QueryFilter + 'static> MonitorSet<C, F> {
+    /// The entities currently being monitored.
+    pub fn members(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.members.iter().copied()
+    }
+    fn register_sync_system(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                add_systems(world, Update, sync_monitor_sets::<C, F>.before(MutationSet));
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn tear_down(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let members = world
+            .get::<Self>(entity)
+            .map(|set| set.members.iter().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        world.commands().queue(move |world: &mut World| {
+            members.into_iter().for_each(|member| {
+                if let Ok(mut member) = world.get_entity_mut(member) {
+                    member.remove::<NotifyChanged<C>>();
+                }
+            });
+        });
+
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_sets = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_sets == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        world.schedule_scope(Update, |world, schedule| {
+            // # Safety
+            // `MonitorSet::<C, F>` being registered ensures this system exists in the
+            // `Update` schedule.
+            schedule
+                .remove_systems_in_set(
+                    sync_monitor_sets::<C, F>,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+    }
+}
+
+/// Allows setting up self-monitoring for every entity matching a filter, without tracking spawns
+/// yourself.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # #[derive(Component)]
+/// # pub struct Enemy;
+/// fn watch_enemies(mut commands: Commands) {
+///     let enemies = commands.monitor_all::<Health, With<Enemy>>();
+///
+///     // Later, to stop watching every enemy.
+///     commands.entity(enemies).despawn();
+/// }
+/// ```
+pub trait MonitorAll {
+    /// Gives every entity with [`C`] matching `F` [`MonitorSelf`] and [`NotifyChanged<C>`], both
+    /// now and as entities start matching, returning the entity holding the [`MonitorSet<C, F>`].
+    fn monitor_all<C: Component<Mutability = Mutable>, F: QueryFilter + 'static>(
+        &mut self,
+    ) -> Entity;
+}
+
+impl MonitorAll for Commands<'_, '_> {
+    fn monitor_all<C: Component<Mutability = Mutable>, F: QueryFilter + 'static>(
+        &mut self,
+    ) -> Entity {
+        self.spawn(MonitorSet::<C, F> {
+            members: EntityHashSet::default(),
+            _phantom: PhantomData,
+        })
+        .id()
+    }
+}
+
+impl MonitorAll for World {
+    fn monitor_all<C: Component<Mutability = Mutable>, F: QueryFilter + 'static>(
+        &mut self,
+    ) -> Entity {
+        let set = self.commands().monitor_all::<C, F>();
+        self.flush();
+        set
+    }
+}
+
+fn sync_monitor_sets<C: Component, F: QueryFilter + 'static>(
+    mut commands: Commands,
+    matching: Query<Entity, (With<C>, F)>,
+    mut sets: Query<&mut MonitorSet<C, F>>,
+) {
+    let current = matching.iter().collect::<EntityHashSet>();
+
+    sets.iter_mut().for_each(|mut set| {
+        set.members
+            .iter()
+            .filter(|member| !current.contains(*member))
+            .for_each(|&member| {
+                if let Ok(mut member) = commands.get_entity(member) {
+                    member.try_remove::<NotifyChanged<C>>();
+                }
+            });
+        current
+            .iter()
+            .filter(|member| !set.members.contains(*member))
+            .for_each(|&member| {
+                commands
+                    .entity(member)
+                    .insert((MonitorSelf, NotifyChanged::<C>::required()));
+            });
+
+        set.members = current.clone();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health;
+
+    #[derive(Component)]
+    pub struct Enemy;
+
+    #[test]
+    fn keep_set_in_sync() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+
+        let goblin = world.spawn((Health, Enemy)).id();
+        let player = world.spawn(Health).id();
+        let enemies = world.monitor_all::<Health, With<Enemy>>();
+
+        world.run_schedule(Update);
+
+        assert!(world.entity(goblin).contains::<NotifyChanged<Health>>());
+        assert!(!world.entity(player).contains::<NotifyChanged<Health>>());
+
+        let orc = world.spawn((Health, Enemy)).id();
+        world.entity_mut(goblin).remove::<Enemy>();
+        world.run_schedule(Update);
+
+        assert!(world.entity(orc).contains::<NotifyChanged<Health>>());
+        assert!(!world.entity(goblin).contains::<NotifyChanged<Health>>());
+
+        world.entity_mut(enemies).despawn();
+
+        assert!(!world.entity(orc).contains::<NotifyChanged<Health>>());
+    }
+}
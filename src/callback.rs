@@ -0,0 +1,276 @@
+use crate::{
+    prelude::*,
+    registry::{register_once, register_teardown, unregister},
+};
+use bevy_ecs::{
+    change_detection::Tick, lifecycle::HookContext, prelude::*, system::SystemId,
+    world::DeferredWorld,
+};
+use std::marker::PhantomData;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// The input passed to the callbacks of a [`NotifyCallback<C>`].
+pub struct CallbackNotification {
+    pub kind: NotificationKind,
+    /// The monitor that received the notification.
+    pub monitor: Entity,
+    /// The entity the component belongs to.
+    pub watched: Entity,
+    /// The change tick at which the notification was triggered.
+    pub tick: Tick,
+    /// The change that caused the notification.
+    pub change: ChangeId,
+}
+
+/// A one-shot system run by a [`NotifyCallback<C>`].
+pub type NotifyCallbackSystem = SystemId<In<CallbackNotification>>;
+
+#[derive(Resource)]
+/// Contains the observers running the callbacks of [`NotifyCallback<C>`].
+struct DetectingCallbacks<C: Component> {
+    observers: [Entity; 3],
+    _phantom: PhantomData<C>,
+}
+
+#[derive(Component)]
+#[component(
+    on_add = NotifyCallback::<C>::register_callback_observers,
+    on_remove = NotifyCallback::<C>::remove_callback_observers
+)]
+/// Adding this component to a monitor will run stored one-shot systems when it receives
+/// [`Addition<C>`], [`Mutation<C>`], or [`Removal<C>`], rather than observing them.
+///
+/// This allows handlers written for callback-oriented crates, which run a stored system when
+/// something happens, to be reused as they are.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// fn refresh_health_bar(In(notification): In<CallbackNotification>) {
+///     // Redraw the health bar of `notification.watched`.
+/// }
+///
+/// # fn showcase(world: &mut World, player: Entity) {
+/// let refresh = world.register_system(refresh_health_bar);
+///
+/// world.spawn((
+///     Monitor(player),
+///     NotifyChanged::<Health>::default(),
+///     NotifyCallback::<Health>::default().on_changed(refresh),
+/// ));
+/// # }
+/// ```
+///
+/// Monitors still need [`NotifyAdded<C>`], [`NotifyChanged<C>`], or [`NotifyRemoved<C>`] to
+/// receive the notifications in the first place.
+///
+/// # Technical info
+///
+/// Adding this component to an entity will spawn an [`Observer`] for each of [`Addition<C>`],
+/// [`Mutation<C>`], and [`Removal<C>`], this is only done once.
+///
+/// When all instances of this component in the world are removed the observers will be despawned.
+pub struct NotifyCallback<C: Component> {
+    added: Option<NotifyCallbackSystem>,
+    changed: Option<NotifyCallbackSystem>,
+    removed: Option<NotifyCallbackSystem>,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component> Default for NotifyCallback<C> {
+    fn default() -> Self {
+        Self {
+            added: None,
+            changed: None,
+            removed: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Component> NotifyCallback<C> {
+    /// Runs `callback` when the monitor receives [`Addition<C>`].
+    pub fn on_added(mut self, callback: NotifyCallbackSystem) -> Self {
+        self.added = Some(callback);
+        self
+    }
+    /// Runs `callback` when the monitor receives [`Mutation<C>`].
+    pub fn on_changed(mut self, callback: NotifyCallbackSystem) -> Self {
+        self.changed = Some(callback);
+        self
+    }
+    /// Runs `callback` when the monitor receives [`Removal<C>`].
+    pub fn on_removed(mut self, callback: NotifyCallbackSystem) -> Self {
+        self.removed = Some(callback);
+        self
+    }
+    fn callback(&self, kind: NotificationKind) -> Option<NotifyCallbackSystem> {
+        match kind {
+            NotificationKind::Added => self.added,
+            NotificationKind::Changed => self.changed,
+            NotificationKind::Removed => self.removed,
+        }
+    }
+    fn register_callback_observers(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                let observers = [
+                    world
+                        .add_observer(
+                            |addition: On<Addition<C>>,
+                             commands: Commands,
+                             callbacks: Query<&NotifyCallback<C>>| {
+                                run_callback(
+                                    commands,
+                                    callbacks,
+                                    CallbackNotification {
+                                        kind: NotificationKind::Added,
+                                        monitor: addition.entity,
+                                        watched: addition.added,
+                                        tick: addition.tick,
+                                        change: addition.change,
+                                    },
+                                );
+                            },
+                        )
+                        .id(),
+                    world
+                        .add_observer(
+                            |mutation: On<Mutation<C>>,
+                             commands: Commands,
+                             callbacks: Query<&NotifyCallback<C>>| {
+                                run_callback(
+                                    commands,
+                                    callbacks,
+                                    CallbackNotification {
+                                        kind: NotificationKind::Changed,
+                                        monitor: mutation.entity,
+                                        watched: mutation.mutated,
+                                        tick: mutation.tick,
+                                        change: mutation.change,
+                                    },
+                                );
+                            },
+                        )
+                        .id(),
+                    world
+                        .add_observer(
+                            |removal: On<Removal<C>>,
+                             commands: Commands,
+                             callbacks: Query<&NotifyCallback<C>>| {
+                                run_callback(
+                                    commands,
+                                    callbacks,
+                                    CallbackNotification {
+                                        kind: NotificationKind::Removed,
+                                        monitor: removal.entity,
+                                        watched: removal.removed,
+                                        tick: removal.tick,
+                                        change: removal.change,
+                                    },
+                                );
+                            },
+                        )
+                        .id(),
+                ];
+
+                world.insert_resource(DetectingCallbacks::<C> {
+                    observers,
+                    _phantom: PhantomData,
+                });
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_callback_observers(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_callbacks = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_callbacks == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `NotifyCallback::<C>` adds the `DetectingCallbacks` resource.
+        let DetectingCallbacks { observers, .. } =
+            world.remove_resource::<DetectingCallbacks<C>>().unwrap();
+        observers.into_iter().for_each(|observer| {
+            world.entity_mut(observer).despawn();
+        });
+    }
+}
+
+fn run_callback<C: Component>(
+    mut commands: Commands,
+    callbacks: Query<&NotifyCallback<C>>,
+    notification: CallbackNotification,
+) {
+    let Some(callback) = callbacks
+        .get(notification.monitor)
+        .ok()
+        .and_then(|callbacks| callbacks.callback(notification.kind))
+    else {
+        return;
+    };
+
+    commands.run_system_with(callback, notification);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Redrawn(Vec<(NotificationKind, Entity)>);
+
+    fn redraw(In(notification): In<CallbackNotification>, mut redrawn: ResMut<Redrawn>) {
+        redrawn.0.push((notification.kind, notification.watched));
+    }
+
+    #[test]
+    fn run_stored_callbacks() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Redrawn>();
+
+        let redraw = world.register_system(redraw);
+        let player = world.spawn(Health(100)).id();
+        world.spawn((
+            Monitor(player),
+            NotifyChanged::<Health>::default(),
+            NotifyRemoved::<Health>::default(),
+            NotifyCallback::<Health>::default()
+                .on_changed(redraw)
+                .on_removed(redraw),
+        ));
+
+        world.run_schedule(Update);
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+        world.entity_mut(player).remove::<Health>();
+        world.flush();
+
+        assert_eq!(
+            world.resource::<Redrawn>().0,
+            vec![
+                (NotificationKind::Changed, player),
+                (NotificationKind::Changed, player),
+                (NotificationKind::Removed, player),
+            ]
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use crate::registry::{register_once, register_teardown, unregister};
+use bevy_ecs::{
+    change_detection::Tick, lifecycle::HookContext, prelude::*, reflect::AppTypeRegistry,
+    world::DeferredWorld,
+};
+use bevy_scene::DynamicScene;
+use std::{marker::PhantomData, sync::Arc};
+
+/// Captures the world, given the monitor that received the notification.
+type Capture = Arc<dyn Fn(&mut World, Entity) + Send + Sync>;
+
+#[derive(Resource)]
+/// Contains the observer running [`CaptureOnNotify<E>`].
+struct DetectingCaptures<E: EntityEvent> {
+    observer: Entity,
+    _phantom: PhantomData<E>,
+}
+
+/// A snapshot of the world taken by [`CaptureOnNotify::snapshot`].
+pub struct NotifyCapture {
+    /// The monitor that received the notification.
+    pub monitor: Entity,
+    /// The change tick at which the snapshot was taken.
+    pub tick: Tick,
+    /// Every entity and resource registered with the [`AppTypeRegistry`].
+    ///
+    /// [`DynamicScene::serialize`] turns this into a scene file that can be loaded to reproduce
+    /// the state.
+    pub scene: DynamicScene,
+}
+
+#[derive(Resource, Default)]
+/// Every snapshot taken by [`CaptureOnNotify::snapshot`], in the order they were taken.
+pub struct NotifyCaptures {
+    pub captures: Vec<NotifyCapture>,
+}
+
+#[derive(Component)]
+#[component(
+    on_add = CaptureOnNotify::<E>::register_capture_observer,
+    on_remove = CaptureOnNotify::<E>::remove_capture_observer
+)]
+/// Adding this component to a monitor captures the world whenever it receives [`E`], helping to
+/// reproduce rare state dependent bugs the moment the change causing them happens.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands.spawn((
+///     Monitor(player),
+///     NotifyChanged::<Health>::default(),
+///     CaptureOnNotify::<Mutation<Health>>::snapshot(),
+/// ));
+/// # }
+/// ```
+///
+/// Screenshots, or any other kind of capture, can be taken with [`CaptureOnNotify::with`].
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::render::view::screenshot::{Screenshot, save_to_disk};
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands.spawn((
+///     Monitor(player),
+///     NotifyChanged::<Health>::default(),
+///     CaptureOnNotify::<Mutation<Health>>::with(|world, _| {
+///         world
+///             .spawn(Screenshot::primary_window())
+///             .observe(save_to_disk("health.png"));
+///     })
+///     .when(|mutation| mutation.tick.get() % 2 == 0),
+/// ));
+/// # }
+/// ```
+///
+/// # Technical info
+///
+/// Adding this component to an entity will spawn an [`Observer`] for event [`E`], this is only
+/// done once.
+///
+/// When all instances of this component in the world are removed the observer will be despawned.
+pub struct CaptureOnNotify<E: EntityEvent> {
+    capture: Capture,
+    predicate: Option<Box<dyn Fn(&E) -> bool + Send + Sync>>,
+}
+impl<E: EntityEvent> CaptureOnNotify<E> {
+    /// Stores a snapshot of the world in [`NotifyCaptures`].
+    pub fn snapshot() -> Self {
+        Self::with(|world, monitor| {
+            world.init_resource::<AppTypeRegistry>();
+
+            let capture = NotifyCapture {
+                monitor,
+                tick: world.change_tick(),
+                scene: DynamicScene::from_world(world),
+            };
+
+            world
+                .get_resource_or_init::<NotifyCaptures>()
+                .captures
+                .push(capture);
+        })
+    }
+    /// Runs `capture` with exclusive access to the world.
+    pub fn with(capture: impl Fn(&mut World, Entity) + Send + Sync + 'static) -> Self {
+        Self {
+            capture: Arc::new(capture),
+            predicate: None,
+        }
+    }
+    /// Only captures the world when `predicate` returns true for the notification.
+    pub fn when(mut self, predicate: impl Fn(&E) -> bool + Send + Sync + 'static) -> Self {
+        self.predicate = Some(Box::new(predicate));
+        self
+    }
+    fn register_capture_observer(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                let observer = world.add_observer(capture::<E>).id();
+                world.insert_resource(DetectingCaptures::<E> {
+                    observer,
+                    _phantom: PhantomData,
+                });
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_capture_observer(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_captures = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_captures == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `CaptureOnNotify::<E>` adds the `DetectingCaptures` resource.
+        let DetectingCaptures { observer, .. } =
+            world.remove_resource::<DetectingCaptures<E>>().unwrap();
+        world.entity_mut(observer).despawn();
+    }
+}
+
+fn capture<E: EntityEvent>(
+    event: On<E>,
+    mut commands: Commands,
+    captures: Query<&CaptureOnNotify<E>>,
+) {
+    let monitor = event.event_target();
+    let Ok(CaptureOnNotify { capture, predicate }) = captures.get(monitor) else {
+        return;
+    };
+
+    if predicate
+        .as_ref()
+        .is_some_and(|predicate| !predicate(event.event()))
+    {
+        return;
+    }
+
+    let capture = capture.clone();
+    commands.queue(move |world: &mut World| capture(world, monitor));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    pub struct Health(u8);
+
+    #[test]
+    fn capture_when_predicate_holds() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+
+        let player = world.spawn(Health(100)).id();
+        let monitor = world
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                CaptureOnNotify::<Mutation<Health>>::snapshot()
+                    .when(|mutation| mutation.change.0 > 1),
+            ))
+            .id();
+
+        // Counting as changed the first time isn't captured.
+        world.run_schedule(Update);
+
+        assert!(!world.contains_resource::<NotifyCaptures>());
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        let captures = &world.resource::<NotifyCaptures>().captures;
+
+        assert_eq!(captures.len(), 1);
+        assert_eq!(captures[0].monitor, monitor);
+        assert!(
+            captures[0]
+                .scene
+                .entities
+                .iter()
+                .any(|entity| entity.entity == player)
+        );
+    }
+}
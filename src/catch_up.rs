@@ -0,0 +1,43 @@
+use bevy_ecs::{component::ComponentId, prelude::*};
+use std::collections::HashMap;
+
+/// Brings a monitor up to date with the current state of its target, `(world, monitor, target)`.
+pub(crate) type CatchUp = fn(&mut World, Entity, Entity);
+
+#[derive(Resource, Default)]
+/// Contains the [`CatchUp`] for each `Notify*` component that has one, keyed by the
+/// [`ComponentId`] of the `Notify*` component.
+pub(crate) struct CatchUpRegistry(HashMap<ComponentId, CatchUp>);
+
+/// Registers `catch_up` to run for monitors with the component [`N`].
+pub(crate) fn register_catch_up<N: Component>(world: &mut World, catch_up: CatchUp) {
+    let id = world.register_component::<N>();
+    world
+        .get_resource_or_init::<CatchUpRegistry>()
+        .0
+        .insert(id, catch_up);
+}
+
+/// Triggers the notifications `monitor` would have received had it been watching `target` from
+/// the start, such as [`Mutation<C>`] for every [`C`] on `target` the monitor watches for changes.
+///
+/// [`Mutation<C>`]: crate::prelude::Mutation
+pub(crate) fn catch_up(world: &mut World, monitor: Entity, target: Entity) {
+    let Some(registry) = world.get_resource::<CatchUpRegistry>() else {
+        return;
+    };
+    let Ok(entity) = world.get_entity(monitor) else {
+        return;
+    };
+
+    let catch_ups = entity
+        .archetype()
+        .components()
+        .iter()
+        .filter_map(|id| registry.0.get(id).copied())
+        .collect::<Vec<_>>();
+
+    catch_ups
+        .into_iter()
+        .for_each(|catch_up| catch_up(world, monitor, target));
+}
@@ -0,0 +1,241 @@
+use crate::registry::{add_systems, register_once, register_teardown};
+use bevy_app::App;
+use bevy_ecs::{
+    change_detection::Tick,
+    entity::EntityHashMap,
+    prelude::*,
+    schedule::{InternedScheduleLabel, ScheduleCleanupPolicy, ScheduleLabel},
+    system::SystemChangeTick,
+};
+use std::marker::PhantomData;
+
+#[derive(Resource)]
+/// Contains the number of times [`C`] was seen changing on each entity since changes were last
+/// detected, along with the schedules it's counted in.
+pub(crate) struct ChangeCounts<C: Component> {
+    /// The counts of every system detecting changes to [`C`], each resetting only its own.
+    windows: Vec<CountWindow>,
+    schedules: Vec<InternedScheduleLabel>,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component> ChangeCounts<C> {
+    /// The counts of the system detecting changes that was assigned `window`, assigning it one if
+    /// it doesn't have one yet.
+    pub(crate) fn window(&mut self, window: &mut Option<usize>) -> &mut CountWindow {
+        let index = *window.get_or_insert(self.windows.len());
+
+        // The counts may have been torn down and registered again since the window was assigned.
+        if index >= self.windows.len() {
+            self.windows.resize_with(index + 1, CountWindow::default);
+        }
+        &mut self.windows[index]
+    }
+}
+
+#[derive(Default)]
+/// The number of times [`C`] was seen changing on each entity since one system last detected
+/// changes.
+pub(crate) struct CountWindow {
+    counts: EntityHashMap<u32>,
+    /// The last time changes were counted or detected.
+    sampled: Tick,
+}
+impl CountWindow {
+    /// The number of times [`C`] changed on `entity`, including a change not yet counted.
+    pub(crate) fn times_changed(&self, entity: Entity, last_changed: Tick, this_run: Tick) -> u32 {
+        let counted = self.counts.get(&entity).copied().unwrap_or_default();
+        let uncounted = u32::from(last_changed.is_newer_than(self.sampled, this_run));
+
+        (counted + uncounted).max(1)
+    }
+    /// Forgets every count once changes have been detected at `this_run`.
+    pub(crate) fn reset(&mut self, this_run: Tick) {
+        self.counts.clear();
+        self.sampled = this_run;
+    }
+}
+
+/// Allows reporting how many times a component changed between two detections, rather than only
+/// that it did, see [`Mutation::times_changed`].
+///
+/// Bevy only records the last time a component changed, so writes are counted by checking for
+/// changes in additional schedules. Every schedule that changes [`C`] between two detections is
+/// counted once, however many times it wrote to it.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # let mut app = App::new();
+/// // Damage is applied in `PreUpdate`, and healing in `Update`.
+/// app.count_changes_in::<Health>(PreUpdate)
+///     .add_observer(|mutation: On<Mutation<Health>>| {
+///         println!("Health changed {} times", mutation.times_changed());
+///     });
+/// ```
+///
+/// # Technical info
+///
+/// This adds a system to `schedule`, which should run before changes to [`C`] are detected. The
+/// system is removed along with the other registrations of [`C`] by [`NotifyPlugin::cleanup`].
+///
+/// [`Mutation::times_changed`]: crate::prelude::Mutation::times_changed
+/// [`NotifyPlugin::cleanup`]: crate::NotifyPlugin::cleanup
+pub trait CountChanges {
+    /// Counts changes to [`C`] made before `schedule` runs.
+    fn count_changes_in<C: Component>(&mut self, schedule: impl ScheduleLabel) -> &mut Self;
+}
+
+impl CountChanges for World {
+    fn count_changes_in<C: Component>(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
+        register_once::<ChangeCounts<C>>(self, |world| {
+            world.insert_resource(ChangeCounts::<C> {
+                windows: Vec::new(),
+                schedules: Vec::new(),
+                _phantom: PhantomData,
+            });
+            register_teardown::<ChangeCounts<C>>(world, teardown::<C>);
+        });
+
+        let schedule = schedule.intern();
+        let mut counting = self.resource_mut::<ChangeCounts<C>>();
+
+        if !counting.schedules.contains(&schedule) {
+            counting.schedules.push(schedule);
+            add_systems(self, schedule, count_changes::<C>);
+        }
+        self
+    }
+}
+
+impl CountChanges for App {
+    fn count_changes_in<C: Component>(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
+        self.world_mut().count_changes_in::<C>(schedule);
+        self
+    }
+}
+
+fn teardown<C: Component>(world: &mut World) {
+    // # Safety
+    // Registering `ChangeCounts::<C>` adds the resource, and the system to every schedule in it.
+    let ChangeCounts { schedules, .. } = world.remove_resource::<ChangeCounts<C>>().unwrap();
+
+    schedules.into_iter().for_each(|schedule| {
+        world.schedule_scope(schedule, |world, schedule| {
+            schedule
+                .remove_systems_in_set(
+                    count_changes::<C>,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+    });
+}
+
+fn count_changes<C: Component>(
+    mut counting: ResMut<ChangeCounts<C>>,
+    ticks: SystemChangeTick,
+    changed: Query<(Entity, Ref<C>), Changed<C>>,
+) {
+    counting.windows.iter_mut().for_each(|window| {
+        changed
+            .iter()
+            .filter(|(_, value)| {
+                value
+                    .last_changed()
+                    .is_newer_than(window.sampled, ticks.this_run())
+            })
+            .for_each(|(entity, _)| {
+                *window.counts.entry(entity).or_default() += 1;
+            });
+        window.sampled = ticks.this_run();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Counts(Vec<u32>);
+
+    #[test]
+    fn count_changes_between_detections() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(PreUpdate));
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Counts>();
+        world.count_changes_in::<Health>(PreUpdate);
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(
+                |mutation: On<Mutation<Health>>, mut counts: ResMut<Counts>| {
+                    counts.0.push(mutation.times_changed());
+                },
+            );
+        world.run_schedule(PreUpdate);
+        world.run_schedule(Update);
+        world.resource_mut::<Counts>().0.clear();
+
+        // Changed before `PreUpdate` and again before `Update`.
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(PreUpdate);
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        // Only changed before `Update`.
+        world.run_schedule(PreUpdate);
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Counts>().0, vec![2, 1]);
+    }
+
+    #[test]
+    fn count_for_every_detecting_schedule() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(PreUpdate));
+        world.add_schedule(Schedule::new(Update));
+        world.add_schedule(Schedule::new(PostUpdate));
+        world.init_resource::<Counts>();
+        world
+            .add_notify_changed_in::<Health>(Update)
+            .add_notify_changed_in::<Health>(PostUpdate)
+            .count_changes_in::<Health>(PreUpdate);
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(
+                |mutation: On<Mutation<Health>>, mut counts: ResMut<Counts>| {
+                    counts.0.push(mutation.times_changed());
+                },
+            );
+        let run = |world: &mut World| {
+            world.run_schedule(PreUpdate);
+            world.run_schedule(Update);
+            world.run_schedule(PostUpdate);
+        };
+        run(&mut world);
+        world.resource_mut::<Counts>().0.clear();
+
+        // Both `Update` and `PostUpdate` detect the change, and each counts both writes.
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(PreUpdate);
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+        world.run_schedule(PostUpdate);
+
+        assert_eq!(world.resource::<Counts>().0, vec![2, 2]);
+    }
+}
@@ -0,0 +1,88 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+/// Identifies a single detected change, shared by every notification caused by it.
+///
+/// Ids increase with every detected change and are never reused within a world, so logging and
+/// replay can correlate an original change with every reaction it caused, even when they are
+/// delivered frames later. Such as mutations held back by a [`TargetBudget`], delayed by
+/// [`DeliverIn`], or fanned out by [`SubscribeTo<C>`].
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// fn log_mutation(mutation: On<Mutation<Health>>) {
+///     println!("{:?} reached {}", mutation.change, mutation.entity);
+/// }
+/// ```
+///
+/// [`TargetBudget`]: crate::prelude::TargetBudget
+/// [`DeliverIn`]: crate::prelude::DeliverIn
+/// [`SubscribeTo<C>`]: crate::prelude::SubscribeTo
+pub struct ChangeId(pub u64);
+
+#[derive(Resource, Default)]
+/// Hands out the next [`ChangeId`].
+pub(crate) struct ChangeIds {
+    next: u64,
+}
+impl ChangeIds {
+    pub(crate) fn next(&mut self) -> ChangeId {
+        self.next += 1;
+        ChangeId(self.next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Changes(Vec<(Entity, ChangeId)>);
+
+    #[test]
+    fn correlate_across_frames() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(First));
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Changes>();
+
+        let boss = world.spawn((Health(100), TargetBudget::new(1))).id();
+        for _ in 0..2 {
+            world
+                .spawn((Monitor(boss), NotifyChanged::<Health>::default()))
+                .observe(
+                    |mutation: On<Mutation<Health>>, mut changes: ResMut<Changes>| {
+                        changes.0.push((mutation.entity, mutation.change));
+                    },
+                );
+        }
+
+        world.run_schedule(First);
+        world.run_schedule(Update);
+        // The second monitor receives the held back mutation the next frame.
+        world.run_schedule(First);
+        world.run_schedule(Update);
+
+        let changes = &world.resource::<Changes>().0;
+
+        assert_eq!(changes.len(), 2);
+        assert_ne!(changes[0].0, changes[1].0);
+        assert_eq!(changes[0].1, changes[1].1);
+
+        let first = changes[0].1;
+        world.get_mut::<Health>(boss).unwrap().0 -= 10;
+        world.run_schedule(First);
+        world.run_schedule(Update);
+
+        assert!(world.resource::<Changes>().0[2].1 > first);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{
+    dispatch::Notifier,
+    mutation::{NotifySet, configure_notify_sets},
+    registry::{add_systems, register_once, register_teardown, unregister},
+};
+use bevy_app::Update;
+use bevy_ecs::{
+    lifecycle::HookContext, prelude::*, schedule::ScheduleCleanupPolicy, world::DeferredWorld,
+};
+use std::{any::TypeId, sync::Arc};
+
+#[derive(Component, Clone, Default, Debug)]
+#[component(
+    on_add = CompactChanges::register_compaction_system,
+    on_remove = CompactChanges::remove_compaction_system
+)]
+/// Adding this component to a monitor will merge the [`Mutation<C>`] it would receive for each of
+/// the selected component types into a single [`CompactedChanges`] per frame, containing which of
+/// them changed.
+///
+/// This keeps the number of observer invocations flat for monitors watching many component types,
+/// such as a character sheet watching every stat. The monitor still needs [`NotifyChanged<C>`] for
+/// each component type.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # #[derive(Component)]
+/// # pub struct Mana(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands
+///     .spawn((
+///         Monitor(player),
+///         NotifyChanged::<Health>::default(),
+///         NotifyChanged::<Mana>::default(),
+///         CompactChanges::default().with::<Health>().with::<Mana>(),
+///     ))
+///     .observe(|compacted: On<CompactedChanges>| {
+///         if compacted.changed.contains::<Health>() {
+///             // Redraw the health bar.
+///         }
+///     });
+/// # }
+/// ```
+///
+/// # Technical info
+///
+/// Adding this component to an entity will add a system to the [`Update`] schedule that runs in
+/// [`NotifySet::Dispatch`], this is only done once.
+///
+/// When all instances of this component in the world are removed the system will be removed.
+///
+/// [`Mutation<C>`]: crate::prelude::Mutation
+/// [`NotifyChanged<C>`]: crate::prelude::NotifyChanged
+pub struct CompactChanges {
+    types: Arc<[TypeId]>,
+    pending: u64,
+}
+impl CompactChanges {
+    /// Merges [`Mutation<C>`](crate::prelude::Mutation) into [`CompactedChanges`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than 64 component types are selected.
+    pub fn with<C: Component>(self) -> Self {
+        let id = TypeId::of::<C>();
+
+        if self.types.contains(&id) {
+            return self;
+        }
+
+        assert!(
+            self.types.len() < 64,
+            "`CompactChanges` supports at most 64 component types"
+        );
+
+        Self {
+            types: self.types.iter().copied().chain([id]).collect(),
+            pending: self.pending,
+        }
+    }
+    /// Records a change to [`C`], returning `false` if [`C`] is not being compacted.
+    pub(crate) fn compact<C: Component>(&mut self) -> bool {
+        let Some(index) = self.types.iter().position(|&id| id == TypeId::of::<C>()) else {
+            return false;
+        };
+
+        self.pending |= 1 << index;
+        true
+    }
+    fn register_compaction_system(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                configure_notify_sets(world, Update);
+                add_systems(world, Update, deliver_compacted.in_set(NotifySet::Dispatch));
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_compaction_system(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_compacting = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_compacting == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        world.schedule_scope(Update, |world, schedule| {
+            // # Safety
+            // `CompactChanges` being registered ensures this system exists in the
+            // `Update` schedule.
+            schedule
+                .remove_systems_in_set(
+                    deliver_compacted,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// The component types that changed, as selected with [`CompactChanges::with`].
+pub struct ChangedTypes {
+    types: Arc<[TypeId]>,
+    bits: u64,
+}
+impl ChangedTypes {
+    /// Whether [`C`] changed.
+    pub fn contains<C: Component>(&self) -> bool {
+        self.types
+            .iter()
+            .position(|&id| id == TypeId::of::<C>())
+            .is_some_and(|index| self.bits & (1 << index) != 0)
+    }
+    /// The changed types as a bitset, the order of the bits matches the order the types were
+    /// selected in.
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+    /// The number of types that changed.
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+}
+
+#[derive(EntityEvent)]
+/// Contains every component type selected by [`CompactChanges`] that changed this frame.
+pub struct CompactedChanges {
+    pub entity: Entity,
+    pub changed: ChangedTypes,
+}
+
+fn deliver_compacted(mut notifier: Notifier, mut monitors: Query<(Entity, &mut CompactChanges)>) {
+    monitors.iter_mut().for_each(|(entity, mut compact)| {
+        if compact.pending == 0 {
+            return;
+        }
+
+        let changed = ChangedTypes {
+            types: compact.types.clone(),
+            bits: std::mem::take(&mut compact.pending),
+        };
+
+        notifier.trigger(CompactedChanges { entity, changed });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Component)]
+    pub struct Mana;
+
+    #[derive(Component)]
+    pub struct Stamina(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Redraws(Vec<(bool, bool, bool)>);
+
+    #[test]
+    fn one_event_per_frame() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Redraws>();
+
+        let player = world.spawn((Health(100), Mana, Stamina(10))).id();
+        let sheet = world
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                NotifyChanged::<Mana>::default(),
+                NotifyChanged::<Stamina>::default(),
+                CompactChanges::default()
+                    .with::<Health>()
+                    .with::<Mana>()
+                    .with::<Stamina>(),
+                MonitorStats::default(),
+            ))
+            .observe(
+                |compacted: On<CompactedChanges>, mut redraws: ResMut<Redraws>| {
+                    redraws.0.push((
+                        compacted.changed.contains::<Health>(),
+                        compacted.changed.contains::<Mana>(),
+                        compacted.changed.contains::<Stamina>(),
+                    ));
+                },
+            )
+            .id();
+
+        world.run_schedule(Update);
+        world.resource_mut::<Redraws>().0.clear();
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.get_mut::<Stamina>(player).unwrap().0 -= 5;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Redraws>().0, vec![(true, false, true)]);
+
+        let stats = world.get::<MonitorStats>(sheet).unwrap();
+        assert_eq!((stats.received, stats.coalesced), (2, 5));
+    }
+}
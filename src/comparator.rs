@@ -0,0 +1,101 @@
+use crate::prelude::*;
+use bevy_ecs::{component::Mutable, entity::EntityHashMap, prelude::*};
+
+#[derive(Component)]
+#[require(NotifyChanged<C> = NotifyChanged::required())]
+/// Adding this component to a monitor will only deliver [`Mutation<C>`] when the watched value is
+/// considered different from the last one delivered, according to a comparator of your choosing.
+///
+/// This is useful when [`PartialEq`] isn't the right notion of a change, such as floats that jitter
+/// by tiny amounts, or components with a noisy member like a timestamp.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component, Clone)]
+/// # pub struct Position(pub Vec3);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands.spawn((
+///     Monitor(player),
+///     NotifyWithComparator::<Position>::new(|a, b| a.0.distance(b.0) < 0.01),
+/// ));
+/// # }
+/// ```
+///
+/// Mutations that are considered equal are counted as dropped by [`MonitorStats`]. The first
+/// mutation on each watched entity is always delivered.
+pub struct NotifyWithComparator<C: Component> {
+    equal: fn(&C, &C) -> bool,
+    snapshot: fn(&C) -> C,
+    last: EntityHashMap<C>,
+}
+impl<C: Component<Mutability = Mutable> + Clone> NotifyWithComparator<C> {
+    /// Considers two values of [`C`] equal when `equal` returns `true`.
+    pub fn new(equal: fn(&C, &C) -> bool) -> Self {
+        Self {
+            equal,
+            snapshot: C::clone,
+            last: EntityHashMap::default(),
+        }
+    }
+}
+impl<C: Component> NotifyWithComparator<C> {
+    /// Whether `value` on `watched` differs from the last value that was, remembering it if so.
+    pub(crate) fn differs(&mut self, watched: Entity, value: &C) -> bool {
+        if self
+            .last
+            .get(&watched)
+            .is_some_and(|last| (self.equal)(last, value))
+        {
+            return false;
+        }
+
+        self.last.insert(watched, (self.snapshot)(value));
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Clone)]
+    pub struct Position(f32);
+
+    #[derive(Resource, Default)]
+    pub struct Moves(Vec<f32>);
+
+    #[test]
+    fn ignore_jitter() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Moves>();
+
+        let player = world.spawn(Position(0.)).id();
+        world
+            .spawn((
+                Monitor(player),
+                NotifyWithComparator::<Position>::new(|a, b| (a.0 - b.0).abs() < 0.1),
+            ))
+            .observe(
+                |mutation: On<Mutation<Position>>,
+                 positions: Query<&Position>,
+                 mut moves: ResMut<Moves>|
+                 -> Result<(), BevyError> {
+                    moves.0.push(positions.get(mutation.mutated)?.0);
+                    Ok(())
+                },
+            );
+
+        world.run_schedule(Update);
+
+        for position in [0.05, 0.09, 1., 1.02] {
+            world.get_mut::<Position>(player).unwrap().0 = position;
+            world.run_schedule(Update);
+        }
+
+        assert_eq!(world.resource::<Moves>().0, vec![0., 1.]);
+    }
+}
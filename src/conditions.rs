@@ -0,0 +1,99 @@
+use crate::prelude::*;
+use bevy_ecs::{prelude::*, schedule::SystemCondition};
+use std::{any::TypeId, collections::HashMap};
+
+#[derive(Resource, Default)]
+/// How many of each notification have been delivered, keyed by the [`TypeId`] of the event.
+pub(crate) struct DeliveredNotifications {
+    counts: HashMap<TypeId, u64>,
+}
+impl DeliveredNotifications {
+    /// Counts a delivered [`E`].
+    pub(crate) fn record<E: 'static>(&mut self) {
+        *self.counts.entry(TypeId::of::<E>()).or_default() += 1;
+    }
+    fn count<E: 'static>(&self) -> u64 {
+        self.counts
+            .get(&TypeId::of::<E>())
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// A run condition that is true if any monitor was notified about [`C`] changing since it last
+/// ran, allowing plain systems to react to [`Mutation<C>`] without setting up observers.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn redraw_health_bars() {}
+/// # let mut app = App::new();
+/// app.add_systems(PostUpdate, redraw_health_bars.run_if(on_mutation::<Health>()));
+/// ```
+///
+/// Only notifications that are delivered count, those dropped by filters such as
+/// [`MonitorPaused`] don't.
+pub fn on_mutation<C: Component>() -> impl SystemCondition<()> {
+    on_notification::<Mutation<C>>()
+}
+
+/// A run condition that is true if any monitor was notified about [`C`] being added since it last
+/// ran, see [`on_mutation`].
+pub fn on_addition<C: Component>() -> impl SystemCondition<()> {
+    on_notification::<Addition<C>>()
+}
+
+/// A run condition that is true if any monitor was notified about [`C`] being removed since it
+/// last ran, see [`on_mutation`].
+pub fn on_removal<C: Component>() -> impl SystemCondition<()> {
+    on_notification::<Removal<C>>()
+}
+
+fn on_notification<E: 'static>() -> impl SystemCondition<()> {
+    IntoSystem::into_system(
+        |delivered: Option<Res<DeliveredNotifications>>, mut seen: Local<u64>| {
+            let count = delivered.map_or(0, |delivered| delivered.count::<E>());
+            let triggered = count != *seen;
+
+            *seen = count;
+            triggered
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Redraws(usize);
+
+    #[test]
+    fn run_on_mutation() {
+        let mut app = App::new();
+
+        app.init_resource::<Redraws>().add_systems(
+            PostUpdate,
+            (|mut redraws: ResMut<Redraws>| redraws.0 += 1).run_if(on_mutation::<Health>()),
+        );
+
+        let player = app.world_mut().spawn(Health(100)).id();
+        app.world_mut()
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()));
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<Redraws>().0, 1);
+
+        app.world_mut().get_mut::<Health>(player).unwrap().0 -= 10;
+        app.update();
+
+        assert_eq!(app.world().resource::<Redraws>().0, 2);
+    }
+}
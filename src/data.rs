@@ -0,0 +1,110 @@
+use bevy_ecs::prelude::*;
+use std::{any::Any, fmt, sync::Arc};
+
+#[derive(Component, Clone)]
+/// Adding this component to a monitor will attach `T` to every [`Mutation<C>`], [`Addition<C>`],
+/// and [`Removal<C>`] it receives, so observers shared between many similar monitors can tell them
+/// apart without looking anything up.
+///
+/// The value is stored behind an [`Arc`], so attaching it to an event is cheap regardless of its
+/// size.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Item;
+/// # fn showcase(mut commands: Commands, slots: [Entity; 8]) {
+/// let observer = Observer::new(|addition: On<Addition<Item>>| {
+///     let Some(&slot) = addition.data().and_then(|data| data.get::<usize>()) else {
+///         return;
+///     };
+///     // Redraw the slot.
+/// });
+///
+/// let monitors = slots
+///     .iter()
+///     .enumerate()
+///     .map(|(slot, &target)| {
+///         commands
+///             .spawn((
+///                 Monitor(target),
+///                 NotifyAdded::<Item>::default(),
+///                 MonitorData::new(slot),
+///             ))
+///             .id()
+///     })
+///     .collect::<Vec<_>>();
+/// commands.spawn(observer.with_entities(monitors));
+/// # }
+/// ```
+///
+/// [`Mutation<C>`]: crate::prelude::Mutation
+/// [`Addition<C>`]: crate::prelude::Addition
+/// [`Removal<C>`]: crate::prelude::Removal
+pub struct MonitorData(Arc<dyn Any + Send + Sync>);
+impl MonitorData {
+    /// Attaches `data` to the notifications the monitor receives.
+    pub fn new<T: Send + Sync + 'static>(data: T) -> Self {
+        Self(Arc::new(data))
+    }
+    /// The attached data, this is `None` if it isn't a [`T`].
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+impl fmt::Debug for MonitorData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MonitorData").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Item;
+
+    #[derive(Resource, Default)]
+    pub struct Redrawn(Vec<usize>);
+
+    #[test]
+    fn attach_data_to_events() {
+        let mut world = World::new();
+
+        world.init_resource::<Redrawn>();
+
+        let slots = [(); 3].map(|_| world.spawn_empty().id());
+        let monitors = slots
+            .iter()
+            .enumerate()
+            .map(|(index, &slot)| {
+                world
+                    .spawn((
+                        Monitor(slot),
+                        NotifyAdded::<Item>::default(),
+                        MonitorData::new(index),
+                    ))
+                    .id()
+            })
+            .collect::<Vec<_>>();
+
+        world.spawn(
+            Observer::new(
+                |addition: On<Addition<Item>>, mut redrawn: ResMut<Redrawn>| {
+                    if let Some(&index) = addition.data().and_then(|data| data.get::<usize>()) {
+                        redrawn.0.push(index);
+                    }
+                },
+            )
+            .with_entities(monitors),
+        );
+
+        world.entity_mut(slots[2]).insert(Item);
+        world.entity_mut(slots[0]).insert(Item);
+
+        assert_eq!(world.resource::<Redrawn>().0, vec![2, 0]);
+    }
+}
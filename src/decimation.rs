@@ -0,0 +1,97 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+
+#[derive(Component, Reflect, Clone, Hash, PartialEq, Eq, Debug)]
+/// Adding this component to a monitor will cause it to only receive every `n`th notification,
+/// counting all notifications the monitor would otherwise receive.
+///
+/// This is a cheap way to decimate extremely chatty sources, such as particle counters that change
+/// every frame, where time based filtering like [`NotifyPipeline::debounce`] is overkill.
+///
+/// Skipped notifications are counted as dropped by [`MonitorStats`].
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct ParticleCount(pub usize);
+/// # fn showcase(mut commands: Commands, emitter: Entity) {
+/// commands.spawn((
+///     Monitor(emitter),
+///     NotifyChanged::<ParticleCount>::default(),
+///     EveryN::new(10),
+/// ));
+/// # }
+/// ```
+///
+/// [`NotifyPipeline::debounce`]: crate::prelude::NotifyPipeline::debounce
+/// [`MonitorStats`]: crate::prelude::MonitorStats
+pub struct EveryN {
+    n: u32,
+    count: u32,
+}
+impl EveryN {
+    /// Deliver one out of every `n` notifications, an `n` of zero is treated as one.
+    pub fn new(n: u32) -> Self {
+        Self { n, count: 0 }
+    }
+    /// The `n` in every `n`th notification.
+    pub fn n(&self) -> u32 {
+        self.n
+    }
+    /// Counts a notification, returning whether it should be delivered.
+    pub(crate) fn advance(&mut self) -> bool {
+        self.count += 1;
+
+        if self.count < self.n.max(1) {
+            return false;
+        }
+
+        self.count = 0;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Spark;
+
+    #[derive(Resource, Default)]
+    pub struct SparksSeen(usize);
+
+    #[test]
+    fn every_third() {
+        let mut world = World::new();
+
+        world.init_resource::<SparksSeen>();
+
+        let emitter = world.spawn_empty().id();
+
+        let monitor = world
+            .spawn((
+                Monitor(emitter),
+                NotifyAdded::<Spark>::default(),
+                EveryN::new(3),
+                MonitorStats::default(),
+            ))
+            .observe(|_: On<Addition<Spark>>, mut seen: ResMut<SparksSeen>| {
+                seen.0 += 1;
+            })
+            .id();
+
+        for _ in 0..7 {
+            world.entity_mut(emitter).insert(Spark).remove::<Spark>();
+        }
+
+        assert_eq!(world.resource::<SparksSeen>().0, 2);
+
+        let stats = world.get::<MonitorStats>(monitor).unwrap();
+
+        assert_eq!(stats.received, 2);
+        assert_eq!(stats.dropped, 5);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::prelude::*;
+use bevy_app::App;
+use bevy_ecs::{component::Mutable, prelude::*, schedule::InternedScheduleLabel};
+
+/// The configuration every monitor of [`C`] starts out with, set through
+/// [`NotifyDefaults::notify_defaults`].
+///
+/// Fields left as [`None`] aren't configured.
+pub struct NotifyConfig<C: Component> {
+    /// Only deliver every `n`th notification, as done by [`EveryN`].
+    pub throttle: Option<u32>,
+    /// Only deliver mutations when values differ, as done by [`NotifyWithComparator<C>`].
+    pub dedup: Option<fn(&C, &C) -> bool>,
+    /// The schedule changes to [`C`] are detected in, like
+    /// [`AppNotifyExt::add_notify_changed_in`].
+    pub schedule: Option<InternedScheduleLabel>,
+    /// How important notifications are when a [`NotificationBudget`] is exceeded.
+    pub priority: Option<NotifyPriority>,
+}
+impl<C: Component> Default for NotifyConfig<C> {
+    fn default() -> Self {
+        Self {
+            throttle: None,
+            dedup: None,
+            schedule: None,
+            priority: None,
+        }
+    }
+}
+impl<C: Component> Clone for NotifyConfig<C> {
+    fn clone(&self) -> Self {
+        Self {
+            throttle: self.throttle,
+            dedup: self.dedup,
+            schedule: self.schedule,
+            priority: self.priority,
+        }
+    }
+}
+
+#[derive(Resource)]
+/// Contains the [`NotifyConfig<C>`] given to [`NotifyDefaults::notify_defaults`], along with how
+/// to insert it into a monitor.
+pub(crate) struct ConfiguredDefaults<C: Component> {
+    pub(crate) config: NotifyConfig<C>,
+    insert: fn(&mut EntityWorldMut, &NotifyConfig<C>),
+}
+
+/// Inserts the defaults configured for [`C`] into `monitor`, keeping any the monitor already has.
+pub(crate) fn apply_defaults<C: Component>(world: &mut World, monitor: Entity) {
+    let Some(defaults) = world.get_resource::<ConfiguredDefaults<C>>() else {
+        return;
+    };
+    let (config, insert) = (defaults.config.clone(), defaults.insert);
+
+    if let Ok(mut monitor) = world.get_entity_mut(monitor) {
+        insert(&mut monitor, &config);
+    }
+}
+
+fn insert_config<C: Component<Mutability = Mutable> + Clone>(
+    monitor: &mut EntityWorldMut,
+    config: &NotifyConfig<C>,
+) {
+    if let Some(n) = config.throttle {
+        monitor.insert_if_new(EveryN::new(n));
+    }
+    if let Some(equal) = config.dedup {
+        monitor.insert_if_new(NotifyWithComparator::<C>::new(equal));
+    }
+    if let Some(priority) = config.priority {
+        monitor.insert_if_new(priority);
+    }
+}
+
+/// Allows configuring every monitor of a component at once, rather than adding the same
+/// components to each of them.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # let mut app = App::new();
+/// app.notify_defaults::<Transform>(NotifyConfig {
+///     throttle: Some(4),
+///     dedup: Some(|a, b| a.translation.distance(b.translation) < 0.01),
+///     schedule: Some(PostUpdate.intern()),
+///     ..default()
+/// });
+/// ```
+///
+/// The defaults are inserted into every monitor when [`NotifyChanged<C>`] is added to it, unless
+/// the monitor already has its own [`EveryN`], [`NotifyWithComparator<C>`], or [`NotifyPriority`].
+///
+/// Like [`AppNotifyExt::add_notify_changed_in`] the schedule only takes effect if [`C`] hasn't been
+/// registered yet, so this should be called before any monitor for it is spawned.
+pub trait NotifyDefaults {
+    /// Sets the configuration every monitor of [`C`] starts out with.
+    fn notify_defaults<C: Component<Mutability = Mutable> + Clone>(
+        &mut self,
+        config: NotifyConfig<C>,
+    ) -> &mut Self;
+}
+
+impl NotifyDefaults for World {
+    fn notify_defaults<C: Component<Mutability = Mutable> + Clone>(
+        &mut self,
+        config: NotifyConfig<C>,
+    ) -> &mut Self {
+        self.insert_resource(ConfiguredDefaults {
+            config,
+            insert: insert_config::<C>,
+        });
+        self
+    }
+}
+
+impl NotifyDefaults for App {
+    fn notify_defaults<C: Component<Mutability = Mutable> + Clone>(
+        &mut self,
+        config: NotifyConfig<C>,
+    ) -> &mut Self {
+        self.world_mut().notify_defaults(config);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Clone)]
+    pub struct Position(f32);
+
+    #[derive(Resource, Default)]
+    pub struct Moves(Vec<Entity>);
+
+    #[test]
+    fn inherit_defaults() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Moves>();
+        world.notify_defaults::<Position>(NotifyConfig {
+            throttle: Some(2),
+            dedup: Some(|a, b| (a.0 - b.0).abs() < 1.),
+            ..default()
+        });
+
+        let player = world.spawn(Position(0.)).id();
+        let [defaulted, overridden] = [None, Some(EveryN::new(1))].map(|every_n| {
+            let mut monitor = world.spawn((Monitor(player), NotifyChanged::<Position>::default()));
+
+            if let Some(every_n) = every_n {
+                monitor.insert(every_n);
+            }
+            monitor
+                .observe(
+                    |mutation: On<Mutation<Position>>, mut moves: ResMut<Moves>| {
+                        moves.0.push(mutation.entity);
+                    },
+                )
+                .id()
+        });
+        world.flush();
+
+        assert_eq!(world.get::<EveryN>(defaulted).map(EveryN::n), Some(2));
+        assert_eq!(world.get::<EveryN>(overridden).map(EveryN::n), Some(1));
+
+        world.run_schedule(Update);
+        // Considered equal to the last value.
+        world.get_mut::<Position>(player).unwrap().0 = 0.5;
+        world.run_schedule(Update);
+        world.get_mut::<Position>(player).unwrap().0 = 5.;
+        world.run_schedule(Update);
+
+        let moves = &world.resource::<Moves>().0;
+
+        assert_eq!(
+            moves
+                .iter()
+                .filter(|&&monitor| monitor == defaulted)
+                .count(),
+            1
+        );
+        assert_eq!(
+            moves
+                .iter()
+                .filter(|&&monitor| monitor == overridden)
+                .count(),
+            2
+        );
+    }
+}
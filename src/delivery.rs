@@ -0,0 +1,145 @@
+use crate::registry::add_systems;
+use bevy_ecs::{
+    intern::Interned, lifecycle::HookContext, prelude::*, schedule::ScheduleLabel,
+    world::DeferredWorld,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Triggers a notification that was held back until its delivery schedule.
+type Delivery = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+#[derive(Resource, Default)]
+/// Contains the notifications waiting for each schedule used by [`DeliverIn`].
+struct PendingDeliveries {
+    /// The schedules a system delivering notifications has been added to.
+    registered: HashSet<Interned<dyn ScheduleLabel>>,
+    pending: HashMap<Interned<dyn ScheduleLabel>, Vec<Delivery>>,
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[component(on_add = DeliverIn::register_delivery_system)]
+/// Holds back the notifications a monitor receives until the given schedule runs.
+///
+/// This is useful for UI that should react after transforms and layout have been finalized, such
+/// as with `PostUpdate`, while gameplay monitors keep receiving notifications in `Update`.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands.spawn((
+///     Monitor(player),
+///     NotifyChanged::<Health>::default(),
+///     DeliverIn::new(PostUpdate),
+/// ));
+/// # }
+/// ```
+///
+/// Notifications are delivered in the order they were triggered. If the schedule has already run
+/// this frame they are delivered the next time it runs.
+///
+/// # Technical info
+///
+/// Adding this component to an entity will add a system to the given schedule, this is only done
+/// once per schedule. The system is kept when this component is removed.
+pub struct DeliverIn(pub Interned<dyn ScheduleLabel>);
+impl DeliverIn {
+    /// Delivers notifications when `schedule` runs.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self(schedule.intern())
+    }
+    fn register_delivery_system(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let Some(&DeliverIn(schedule)) = world.get::<Self>(entity) else {
+            return;
+        };
+
+        world.commands().queue(move |world: &mut World| {
+            if !world
+                .get_resource_or_init::<PendingDeliveries>()
+                .registered
+                .insert(schedule)
+            {
+                return;
+            }
+
+            add_systems(world, schedule, move |world: &mut World| {
+                deliver_pending(world, schedule);
+            });
+        });
+    }
+}
+
+/// Holds back `trigger` until `schedule` runs.
+pub(crate) fn defer_delivery(
+    commands: &mut Commands,
+    schedule: Interned<dyn ScheduleLabel>,
+    trigger: impl FnOnce(&mut World) + Send + Sync + 'static,
+) {
+    commands.queue(move |world: &mut World| {
+        world
+            .get_resource_or_init::<PendingDeliveries>()
+            .pending
+            .entry(schedule)
+            .or_default()
+            .push(Box::new(trigger));
+    });
+}
+
+fn deliver_pending(world: &mut World, schedule: Interned<dyn ScheduleLabel>) {
+    let Some(deliveries) = world
+        .get_resource_mut::<PendingDeliveries>()
+        .and_then(|mut pending| pending.pending.get_mut(&schedule).map(std::mem::take))
+    else {
+        return;
+    };
+
+    deliveries.into_iter().for_each(|delivery| delivery(world));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Redraws(usize);
+
+    #[test]
+    fn deliver_after_update() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.add_schedule(Schedule::new(PostUpdate));
+        world.init_resource::<Redraws>();
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                DeliverIn::new(PostUpdate),
+            ))
+            .observe(|_: On<Mutation<Health>>, mut redraws: ResMut<Redraws>| {
+                redraws.0 += 1;
+            });
+
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Redraws>().0, 0);
+
+        world.run_schedule(PostUpdate);
+
+        assert_eq!(world.resource::<Redraws>().0, 1);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+        world.run_schedule(PostUpdate);
+
+        assert_eq!(world.resource::<Redraws>().0, 2);
+    }
+}
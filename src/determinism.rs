@@ -0,0 +1,76 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+
+#[derive(Resource, Reflect, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+/// Inserting this resource makes the order in which notifications are triggered independent of
+/// Bevy's internal storage layout, so lockstep and replay based games can rely on observers being
+/// invoked in the same sequence across runs.
+///
+/// # Guarantees
+///
+/// While this resource exists:
+///
+/// - [`Addition<C>`] and [`Removal<C>`] are triggered on monitors in ascending [`Entity`] order.
+/// - [`Mutation<C>`] is triggered in ascending order of the monitor's [`Entity`], and then of the
+///   mutated [`Entity`] when a single monitor receives multiple mutations.
+/// - Every notification carries the change tick it was triggered at, which can be used to order
+///   notifications that were buffered across frames.
+///
+/// Notifications for different component types are ordered by the systems and observers that
+/// trigger them, which is stable as long as the component types are registered in the same order.
+///
+/// Entity ids must themselves be allocated deterministically for these guarantees to be useful.
+///
+/// [`Addition<C>`]: crate::prelude::Addition
+/// [`Removal<C>`]: crate::prelude::Removal
+/// [`Mutation<C>`]: crate::prelude::Mutation
+pub struct DeterministicNotifications;
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Poisoned;
+
+    #[derive(Component)]
+    pub struct Extra;
+
+    #[derive(Resource, Default)]
+    pub struct Notified(Vec<Entity>);
+
+    #[test]
+    fn sorted_dispatch() {
+        let mut world = World::new();
+
+        world.init_resource::<Notified>();
+        world.init_resource::<DeterministicNotifications>();
+
+        // Spread the monitors over multiple archetypes so that storage order differs from entity
+        // order.
+        let archetype = world
+            .spawn((NotifyAdded::<Poisoned>::default(), Extra))
+            .id();
+        world.entity_mut(archetype).despawn();
+
+        for extra in [false, true, false, true] {
+            let mut monitor = world.spawn(NotifyAdded::<Poisoned>::default());
+            if extra {
+                monitor.insert(Extra);
+            }
+            monitor.observe(
+                |addition: On<Addition<Poisoned>>, mut notified: ResMut<Notified>| {
+                    notified.0.push(addition.entity);
+                },
+            );
+        }
+
+        world.spawn(Poisoned);
+
+        let notified = &world.resource::<Notified>().0;
+
+        assert_eq!(notified.len(), 4);
+        assert!(notified.is_sorted());
+    }
+}
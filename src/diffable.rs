@@ -0,0 +1,115 @@
+use crate::prelude::*;
+use bevy_ecs::prelude::*;
+use std::ops::AddAssign;
+
+/// A component that can compute the numeric difference between two of its values.
+///
+/// This is used by features that care about the magnitude of a change rather than the fact that a
+/// change happened, see [`AccumulateChanges`] and [`Mutation::delta`].
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// pub struct Health(pub i32);
+///
+/// impl Diffable for Health {
+///     type Delta = i32;
+///
+///     fn delta(&self, previous: &Self) -> Self::Delta {
+///         self.0 - previous.0
+///     }
+/// }
+/// ```
+///
+/// [`AccumulateChanges`]: crate::prelude::AccumulateChanges
+pub trait Diffable: Component + Clone {
+    /// The type representing the difference between two values.
+    type Delta: Default + AddAssign + Clone + Send + Sync + 'static;
+
+    /// Computes `self - previous`.
+    fn delta(&self, previous: &Self) -> Self::Delta;
+}
+
+impl<C: Diffable> Mutation<C> {
+    /// The difference between the value [`C`] had before this change and after it.
+    ///
+    /// This is `None` unless the monitor has [`PreviousValue<C>`], and for the first mutation on
+    /// each watched entity.
+    ///
+    /// ```rust
+    /// # use bevy_notify::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # #[derive(Component, Clone)]
+    /// # pub struct Health(pub i32);
+    /// # impl Diffable for Health {
+    /// #     type Delta = i32;
+    /// #     fn delta(&self, previous: &Self) -> i32 {
+    /// #         self.0 - previous.0
+    /// #     }
+    /// # }
+    /// # fn showcase(mut commands: Commands, player: Entity) {
+    /// commands
+    ///     .spawn((Monitor(player), PreviousValue::<Health>::default()))
+    ///     .observe(|mutation: On<Mutation<Health>>| {
+    ///         if let Some(damage @ ..0) = mutation.delta() {
+    ///             println!("Took {} damage", -damage);
+    ///         }
+    ///     });
+    /// # }
+    /// ```
+    pub fn delta(&self) -> Option<C::Delta> {
+        self.value()
+            .zip(self.previous())
+            .map(|(value, previous)| value.delta(previous))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Clone)]
+    pub struct Score(i32);
+
+    impl Diffable for Score {
+        type Delta = i32;
+
+        fn delta(&self, previous: &Self) -> i32 {
+            self.0 - previous.0
+        }
+    }
+
+    #[derive(Resource, Default)]
+    pub struct Deltas(Vec<Option<i32>>);
+
+    #[test]
+    fn carry_delta() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Deltas>();
+
+        let player = world.spawn(Score(0)).id();
+        world
+            .spawn((Monitor(player), PreviousValue::<Score>::default()))
+            .observe(
+                |mutation: On<Mutation<Score>>, mut deltas: ResMut<Deltas>| {
+                    deltas.0.push(mutation.delta());
+                },
+            );
+        world.run_schedule(Update);
+
+        world.get_mut::<Score>(player).unwrap().0 += 50;
+        world.run_schedule(Update);
+
+        world.get_mut::<Score>(player).unwrap().0 -= 20;
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<Deltas>().0,
+            vec![None, Some(50), Some(-20)]
+        );
+    }
+}
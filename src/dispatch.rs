@@ -0,0 +1,285 @@
+use crate::{
+    backend::dispatch,
+    change_id::{ChangeId, ChangeIds},
+    conditions::DeliveredNotifications,
+    delivery::defer_delivery,
+    ordering::ensure_ordered_delivery,
+    prelude::*,
+    settings::TriggerDepth,
+    slot::Slots,
+    slow::trigger_timed,
+};
+#[cfg(feature = "diagnostic")]
+use bevy_diagnostic::FrameCount;
+use bevy_ecs::{
+    change_detection::{ComponentTicks, Tick},
+    event::SetEntityEventTarget,
+    prelude::*,
+    system::SystemChangeTick,
+    system::SystemParam,
+};
+#[cfg(feature = "log")]
+use bevy_log::info;
+#[cfg(feature = "time")]
+use bevy_time::Time;
+#[cfg(feature = "log")]
+use std::any::type_name;
+use std::{marker::PhantomData, time::Duration};
+
+/// The ticks at which `value` was added and last changed, as carried by notifications.
+pub(crate) fn component_ticks<C: Component>(value: &Ref<C>) -> ComponentTicks {
+    ComponentTicks {
+        added: value.added(),
+        changed: value.last_changed(),
+    }
+}
+
+#[derive(SystemParam)]
+/// The time and frame notifications are triggered at, as far as the enabled features can tell.
+///
+/// [`Time`] requires the `time` feature, and [`FrameCount`] the `diagnostic` feature.
+///
+/// [`Time`]: https://docs.rs/bevy/latest/bevy/time/struct.Time.html
+/// [`FrameCount`]: https://docs.rs/bevy/latest/bevy/diagnostic/struct.FrameCount.html
+pub(crate) struct Clock<'w> {
+    #[cfg(feature = "time")]
+    time: Option<Res<'w, Time>>,
+    #[cfg(feature = "diagnostic")]
+    frames: Option<Res<'w, FrameCount>>,
+    _phantom: PhantomData<&'w ()>,
+}
+impl Clock<'_> {
+    /// The [`Time`] elapsed since startup, if the world has any.
+    ///
+    /// [`Time`]: https://docs.rs/bevy/latest/bevy/time/struct.Time.html
+    #[cfg(feature = "time")]
+    pub(crate) fn elapsed(&self) -> Option<Duration> {
+        self.time.as_ref().map(|time| time.elapsed())
+    }
+    #[cfg(not(feature = "time"))]
+    pub(crate) fn elapsed(&self) -> Option<Duration> {
+        None
+    }
+    /// The [`FrameCount`], if the world has one.
+    ///
+    /// [`FrameCount`]: https://docs.rs/bevy/latest/bevy/diagnostic/struct.FrameCount.html
+    #[cfg(feature = "diagnostic")]
+    pub(crate) fn frame(&self) -> Option<u32> {
+        self.frames.as_ref().map(|frames| frames.0)
+    }
+    #[cfg(not(feature = "diagnostic"))]
+    pub(crate) fn frame(&self) -> Option<u32> {
+        None
+    }
+}
+
+#[derive(SystemParam)]
+/// Triggers notifications on monitors, taking care of the per-monitor bookkeeping such as
+/// [`MonitorStats`].
+///
+/// All notifications should be triggered through this rather than [`Commands::trigger`], so they
+/// reach any [`NotificationDispatcher`] registered for them.
+pub(crate) struct Notifier<'w, 's> {
+    commands: Commands<'w, 's>,
+    ticks: SystemChangeTick,
+    clock: Clock<'w>,
+    stats: Query<'w, 's, &'static mut MonitorStats>,
+    every_n: Query<'w, 's, &'static mut EveryN>,
+    paused: Query<'w, 's, (), With<MonitorPaused>>,
+    deliver_in: Query<'w, 's, &'static DeliverIn>,
+    data: Query<'w, 's, &'static MonitorData>,
+    budget: Option<ResMut<'w, NotificationBudget>>,
+    priorities: Query<'w, 's, &'static NotifyPriority>,
+    settled: Query<'w, 's, &'static mut NotifySettled>,
+    observer_budgets: Query<'w, 's, &'static ObserverBudget>,
+    slice: Option<ResMut<'w, NotificationSlice>>,
+    changes: Option<ResMut<'w, ChangeIds>>,
+    /// Used by [`Notifier::trigger_in_order`].
+    in_order: (Option<ResMut<'w, OrderedNotifications>>, Slots<'w, 's>),
+    timestamped: Option<Res<'w, TimestampedNotifications>>,
+}
+impl Notifier<'_, '_> {
+    /// The change tick notifications are being triggered at.
+    pub(crate) fn tick(&self) -> Tick {
+        self.ticks.this_run()
+    }
+    /// The [`Timestamp`] to attach to notifications, if they are being timestamped.
+    pub(crate) fn timestamp(&self) -> Option<Timestamp> {
+        self.timestamped.as_ref().map(|_| Timestamp {
+            elapsed: self.clock.elapsed().unwrap_or_default(),
+            frame: self.clock.frame().unwrap_or_default(),
+        })
+    }
+    /// Assigns a [`ChangeId`] to a newly detected change.
+    pub(crate) fn next_change(&mut self) -> ChangeId {
+        self.changes
+            .as_mut()
+            .map(|changes| changes.next())
+            .unwrap_or_default()
+    }
+    /// The [`MonitorData`] to attach to notifications for `monitor`.
+    pub(crate) fn data(&self, monitor: Entity) -> Option<MonitorData> {
+        self.data.get(monitor).ok().cloned()
+    }
+    /// Triggers `event` on the monitor it targets.
+    ///
+    /// Monitor wide filters such as [`MonitorPaused`] and [`EveryN`], or exceeding the
+    /// [`NotificationBudget`], may cause it to be dropped instead. Exceeding the
+    /// [`NotificationSlice`] holds it back for a later frame.
+    pub(crate) fn trigger<'t, E: EntityEvent<Trigger<'t>: Default>>(&mut self, event: E) {
+        self.trigger_ordered(event.event_target(), event, None);
+    }
+    /// Like [`Notifier::trigger`], but while [`OrderedNotifications`] exists `event` is held back
+    /// and delivered in order with the other notifications about `watched`.
+    ///
+    /// If the monitor has [`TargetSlots`], `event` is delivered to the [`Slot`] of `watched`
+    /// instead.
+    pub(crate) fn trigger_in_order<'t, E: SetEntityEventTarget<Trigger<'t>: Default>>(
+        &mut self,
+        mut event: E,
+        watched: Entity,
+        kind: NotificationKind,
+    ) {
+        let monitor = event.event_target();
+
+        if let Some(slot) = self.in_order.1.slot(monitor, watched) {
+            event.set_event_target(slot);
+        }
+
+        self.trigger_ordered(monitor, event, Some((watched, kind)));
+    }
+    /// Triggers `event` on its target, applying the filters of `monitor`.
+    fn trigger_ordered<'t, E: EntityEvent<Trigger<'t>: Default>>(
+        &mut self,
+        monitor: Entity,
+        event: E,
+        order: Option<(Entity, NotificationKind)>,
+    ) {
+        if self.paused.contains(monitor) {
+            self.drop(monitor);
+            return;
+        }
+
+        if let Ok(mut every_n) = self.every_n.get_mut(monitor)
+            && !every_n.advance()
+        {
+            self.drop(monitor);
+            return;
+        }
+
+        let priority = self.priorities.get(monitor).copied().unwrap_or_default();
+
+        if let Some(budget) = self.budget.as_mut()
+            && !budget.spend(priority)
+        {
+            self.drop(monitor);
+            return;
+        }
+
+        if let Ok(mut settled) = self.settled.get_mut(monitor) {
+            settled.unsettle();
+        }
+
+        let observer_budget = self.observer_budgets.get(monitor).ok().copied();
+        let (tick, now) = (self.ticks.this_run(), self.clock.elapsed());
+        let deliver = move |world: &mut World| {
+            // A monitor, or slot, despawned before delivery has already lost its observers.
+            if world.get_entity(monitor).is_err() || world.get_entity(event.event_target()).is_err()
+            {
+                return;
+            }
+
+            let settings = world
+                .get_resource::<NotifySettings>()
+                .cloned()
+                .unwrap_or_default();
+            let depth = world.get_resource_or_init::<TriggerDepth>().0;
+
+            if settings
+                .max_trigger_depth
+                .is_some_and(|max_depth| depth >= max_depth)
+            {
+                if let Some(mut stats) = world.get_mut::<MonitorStats>(monitor) {
+                    stats.dropped += 1;
+                }
+                return;
+            }
+            if let Some(mut stats) = world.get_mut::<MonitorStats>(monitor) {
+                stats.record(tick, now);
+            }
+            #[cfg(feature = "log")]
+            if settings.log_dispatches {
+                info!("Delivering {} to {monitor}", type_name::<E>());
+            }
+
+            world.resource_mut::<TriggerDepth>().0 += 1;
+            match observer_budget {
+                Some(observer_budget) => trigger_timed(world, monitor, observer_budget, |world| {
+                    dispatch(world, event);
+                }),
+                None => dispatch(world, event),
+            }
+            world
+                .get_resource_or_init::<DeliveredNotifications>()
+                .record::<E>();
+            // Applying the commands of the observers now makes any notification they cause nested
+            // within this one.
+            if settings.max_trigger_depth.is_some() {
+                world.flush();
+            }
+            world.resource_mut::<TriggerDepth>().0 -= 1;
+        };
+
+        if let Ok(&DeliverIn(schedule)) = self.deliver_in.get(monitor) {
+            defer_delivery(&mut self.commands, schedule, deliver);
+            return;
+        }
+        if let Some((watched, kind)) = order
+            && let Some(ordered) = self.in_order.0.as_mut()
+        {
+            ordered.push(watched, kind, deliver);
+            self.commands.queue(ensure_ordered_delivery);
+            return;
+        }
+
+        let deliver = match self.slice.as_mut() {
+            Some(slice) if priority != NotifyPriority::Critical => slice.admit(deliver),
+            _ => Some(deliver),
+        };
+
+        if let Some(deliver) = deliver {
+            self.commands.queue(deliver);
+        }
+    }
+    /// Records that a notification for `monitor` was merged into another rather than triggered.
+    pub(crate) fn coalesce(&mut self, monitor: Entity) {
+        if let Ok(mut stats) = self.stats.get_mut(monitor) {
+            stats.coalesced += 1;
+        }
+    }
+    /// Records that a notification for `monitor` was dropped rather than triggered.
+    pub(crate) fn drop(&mut self, monitor: Entity) {
+        if let Ok(mut stats) = self.stats.get_mut(monitor) {
+            stats.dropped += 1;
+        }
+    }
+}
+
+#[derive(SystemParam)]
+/// Decides whether a notification about component [`C`] should be delivered to a monitor, based on
+/// the filters the monitor has, such as [`NotifyPipeline<C>`].
+pub(crate) struct NotifyGate<'w, 's, C: Component> {
+    clock: Clock<'w>,
+    values: Query<'w, 's, &'static C>,
+    pipelines: Query<'w, 's, &'static mut NotifyPipeline<C>>,
+}
+impl<C: Component> NotifyGate<'_, '_, C> {
+    /// Whether `monitor` should be notified about a change to [`C`] on `watched`.
+    pub(crate) fn allows(&mut self, monitor: Entity, watched: Entity) -> bool {
+        let Ok(mut pipeline) = self.pipelines.get_mut(monitor) else {
+            return true;
+        };
+
+        pipeline.check(watched, self.values.get(watched).ok(), self.clock.elapsed())
+    }
+}
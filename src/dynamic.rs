@@ -0,0 +1,332 @@
+use crate::{
+    mutation::configure_notify_sets,
+    prelude::*,
+    registry::{add_systems, register_once, register_teardown, unregister},
+    settings::detection_schedule,
+};
+use bevy_app::App;
+use bevy_ecs::{
+    change_detection::Tick,
+    component::ComponentId,
+    prelude::*,
+    reflect::AppTypeRegistry,
+    schedule::{InternedScheduleLabel, ScheduleCleanupPolicy},
+    system::SystemChangeTick,
+};
+use std::{collections::HashMap, error::Error, fmt};
+
+#[derive(Event, Clone, Copy, PartialEq, Eq, Debug)]
+/// A type-erased notification about a component watched through [`WatchDynamic`].
+pub struct DynamicNotification {
+    pub kind: NotificationKind,
+    /// The entity the component belongs to.
+    pub watched: Entity,
+    pub component: ComponentId,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// The reasons a component can't be watched through [`WatchDynamic`].
+pub enum DynamicWatchError {
+    /// No component with this id has been registered in the world.
+    UnknownComponent(ComponentId),
+    /// The type path isn't registered in the [`AppTypeRegistry`] with `#[reflect(Component)]`.
+    Unregistered(String),
+}
+impl fmt::Display for DynamicWatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownComponent(id) => write!(f, "no component with the id {id:?} exists"),
+            Self::Unregistered(path) => write!(f, "{path} isn't registered as a component"),
+        }
+    }
+}
+impl Error for DynamicWatchError {}
+
+/// The observers catching additions and removals of a dynamically watched component.
+struct DynamicallyWatched {
+    observers: [Entity; 2],
+    /// Changes made before the component was watched aren't reported.
+    since: Tick,
+}
+
+#[derive(Resource)]
+/// Contains every component watched through [`WatchDynamic`], along with the schedule changes
+/// to them are detected in.
+struct WatchingDynamically {
+    schedule: InternedScheduleLabel,
+    components: HashMap<ComponentId, DynamicallyWatched>,
+}
+
+/// Allows tooling such as editors to watch a component chosen at runtime, by its [`ComponentId`]
+/// or by its type path, rather than through a compile-time generic.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component, Reflect)]
+/// # #[reflect(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(world: &mut World) -> Result<(), BevyError> {
+/// // Chosen by the user at runtime, such as from an inspector panel.
+/// let type_path = "my_game::Health";
+///
+/// world.watch_by_path(type_path)?;
+/// world.add_observer(|notification: On<DynamicNotification>| {
+///     println!("{:?} on {}", notification.kind, notification.watched);
+/// });
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Every entity with the component is watched, and a [`DynamicNotification`] is triggered for
+/// each addition, change, and removal.
+///
+/// # Technical info
+///
+/// Watching a component spawns [`Observer`]s for it being added and removed. The first
+/// component being watched also adds a system detecting changes to the schedule changes are
+/// detected in, which is removed once every component has been unwatched.
+///
+/// Changes are detected by comparing change ticks, so they are reported once [`MutationSet`] has
+/// run, like [`Mutation<C>`].
+pub trait WatchDynamic {
+    /// Watches the component with the id `component`, doing nothing if it's already watched.
+    fn watch_by_id(&mut self, component: ComponentId) -> Result<(), DynamicWatchError>;
+    /// Watches the component with the type path `type_path`, returning its [`ComponentId`].
+    ///
+    /// The component has to be registered in the [`AppTypeRegistry`] with
+    /// `#[reflect(Component)]`.
+    fn watch_by_path(&mut self, type_path: &str) -> Result<ComponentId, DynamicWatchError>;
+    /// Stops watching the component with the id `component`.
+    fn unwatch_by_id(&mut self, component: ComponentId);
+}
+
+impl WatchDynamic for World {
+    fn watch_by_id(&mut self, component: ComponentId) -> Result<(), DynamicWatchError> {
+        if self.components().get_info(component).is_none() {
+            return Err(DynamicWatchError::UnknownComponent(component));
+        }
+
+        register_once::<WatchingDynamically>(self, |world| {
+            let schedule = detection_schedule(world);
+
+            world.insert_resource(WatchingDynamically {
+                schedule,
+                components: HashMap::default(),
+            });
+            configure_notify_sets(world, schedule);
+            add_systems(world, schedule, detect_dynamic_changes.in_set(MutationSet));
+            register_teardown::<WatchingDynamically>(world, teardown);
+        });
+
+        if self
+            .resource::<WatchingDynamically>()
+            .components
+            .contains_key(&component)
+        {
+            return Ok(());
+        }
+
+        let observers = [
+            self.spawn(
+                Observer::new(move |add: On<Add>, mut commands: Commands| {
+                    commands.trigger(DynamicNotification {
+                        kind: NotificationKind::Added,
+                        watched: add.entity,
+                        component,
+                    });
+                })
+                .with_component(component),
+            )
+            .id(),
+            self.spawn(
+                Observer::new(move |remove: On<Remove>, mut commands: Commands| {
+                    commands.trigger(DynamicNotification {
+                        kind: NotificationKind::Removed,
+                        watched: remove.entity,
+                        component,
+                    });
+                })
+                .with_component(component),
+            )
+            .id(),
+        ];
+        // Changes made afterwards, even before any system runs, have to be newer than this.
+        let since = self.increment_change_tick();
+
+        self.resource_mut::<WatchingDynamically>()
+            .components
+            .insert(component, DynamicallyWatched { observers, since });
+        Ok(())
+    }
+    fn watch_by_path(&mut self, type_path: &str) -> Result<ComponentId, DynamicWatchError> {
+        let registry = self.get_resource_or_init::<AppTypeRegistry>().clone();
+        let reflect_component = {
+            let registry = registry.read();
+
+            registry
+                .get_with_type_path(type_path)
+                .and_then(|registration| registration.data::<ReflectComponent>())
+                .cloned()
+                .ok_or_else(|| DynamicWatchError::Unregistered(type_path.to_string()))?
+        };
+        let component = reflect_component.register_component(self);
+
+        self.watch_by_id(component)?;
+        Ok(component)
+    }
+    fn unwatch_by_id(&mut self, component: ComponentId) {
+        let Some(mut watching) = self.get_resource_mut::<WatchingDynamically>() else {
+            return;
+        };
+        let Some(DynamicallyWatched { observers, .. }) = watching.components.remove(&component)
+        else {
+            return;
+        };
+        let remaining = watching.components.len();
+
+        observers.into_iter().for_each(|observer| {
+            self.entity_mut(observer).despawn();
+        });
+        if remaining == 0 {
+            unregister::<WatchingDynamically>(self, teardown);
+        }
+    }
+}
+
+impl WatchDynamic for App {
+    fn watch_by_id(&mut self, component: ComponentId) -> Result<(), DynamicWatchError> {
+        self.world_mut().watch_by_id(component)
+    }
+    fn watch_by_path(&mut self, type_path: &str) -> Result<ComponentId, DynamicWatchError> {
+        self.world_mut().watch_by_path(type_path)
+    }
+    fn unwatch_by_id(&mut self, component: ComponentId) {
+        self.world_mut().unwatch_by_id(component);
+    }
+}
+
+fn teardown(world: &mut World) {
+    // # Safety
+    // Registering `WatchingDynamically` adds the resource.
+    let WatchingDynamically {
+        schedule,
+        components,
+    } = world.remove_resource::<WatchingDynamically>().unwrap();
+
+    components
+        .into_values()
+        .flat_map(|watched| watched.observers)
+        .for_each(|observer| {
+            world.entity_mut(observer).despawn();
+        });
+    world.schedule_scope(schedule, |world, schedule| {
+        // # Safety
+        // `WatchingDynamically` being registered ensures this system exists in the schedule.
+        schedule
+            .remove_systems_in_set(
+                detect_dynamic_changes,
+                world,
+                ScheduleCleanupPolicy::RemoveSystemsOnly,
+            )
+            .unwrap();
+    });
+}
+
+fn detect_dynamic_changes(
+    world: &World,
+    watching: Res<WatchingDynamically>,
+    ticks: SystemChangeTick,
+    mut commands: Commands,
+) {
+    watching
+        .components
+        .iter()
+        .for_each(|(&component, watched)| {
+            world
+                .archetypes()
+                .iter()
+                .filter(|archetype| archetype.contains(component))
+                .flat_map(|archetype| archetype.entities())
+                .filter(|entity| {
+                    world
+                        .entity(entity.id())
+                        .get_change_ticks_by_id(component)
+                        .is_some_and(|changes| {
+                            changes.is_changed(ticks.last_run(), ticks.this_run())
+                                && changes
+                                    .changed
+                                    .is_newer_than(watched.since, ticks.this_run())
+                        })
+                })
+                .for_each(|entity| {
+                    commands.trigger(DynamicNotification {
+                        kind: NotificationKind::Changed,
+                        watched: entity.id(),
+                        component,
+                    });
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Notified(Vec<(NotificationKind, Entity)>);
+
+    #[test]
+    fn watch_by_type_path() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Notified>();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+
+        let component = world.watch_by_path(Health::type_path()).unwrap();
+        world.add_observer(
+            move |notification: On<DynamicNotification>, mut notified: ResMut<Notified>| {
+                assert_eq!(notification.component, component);
+                notified.0.push((notification.kind, notification.watched));
+            },
+        );
+
+        let player = world.spawn(Health(100)).id();
+        world.flush();
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Notified>().0.len(), 2);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        world.entity_mut(player).remove::<Health>();
+        world.flush();
+
+        assert_eq!(
+            world.resource::<Notified>().0,
+            vec![
+                (NotificationKind::Added, player),
+                (NotificationKind::Changed, player),
+                (NotificationKind::Changed, player),
+                (NotificationKind::Removed, player),
+            ]
+        );
+
+        world.unwatch_by_id(component);
+        world.spawn(Health(50));
+        world.flush();
+
+        assert_eq!(world.resource::<Notified>().0.len(), 4);
+    }
+}
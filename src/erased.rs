@@ -0,0 +1,97 @@
+use crate::prelude::*;
+use bevy_ecs::{component::ComponentId, prelude::*};
+
+#[derive(EntityEvent, Clone, Copy, PartialEq, Eq, Debug)]
+/// A type-erased [`Mutation<C>`], triggered alongside every one that is delivered.
+///
+/// This allows tooling such as inspectors and debug overlays to observe changes to every watched
+/// component with a single observer, rather than one per component type.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # let mut app = App::new();
+/// app.add_observer(|mutation: On<AnyMutation>, world: &World| {
+///     let name = world.components().get_name(mutation.component_id);
+///
+///     println!("{name:?} changed on {}", mutation.mutated);
+/// });
+/// ```
+///
+/// Like [`Mutation<C>`] it targets the monitor that was notified, so it can also be observed on a
+/// single monitor. It is triggered after the [`Mutation<C>`] it mirrors has been delivered, so
+/// monitors that are paused or over budget don't produce one.
+pub struct AnyMutation {
+    /// The monitor that was notified.
+    pub entity: Entity,
+    /// The [`Entity`] the component belongs to.
+    pub mutated: Entity,
+    /// The id of the component that was changed.
+    pub component_id: ComponentId,
+}
+
+/// Spawns the observer triggering an [`AnyMutation`] for every [`Mutation<C>`].
+pub(crate) fn spawn_forwarder<C: Component>(world: &mut World) -> Entity {
+    let component_id = world.register_component::<C>();
+
+    world
+        .add_observer(move |mutation: On<Mutation<C>>, mut commands: Commands| {
+            commands.trigger(AnyMutation {
+                entity: mutation.entity,
+                mutated: mutation.mutated,
+                component_id,
+            });
+        })
+        .id()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::{ecs::component::ComponentId, prelude::*};
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Component)]
+    pub struct Mana(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Inspected(Vec<(Entity, ComponentId)>);
+
+    #[test]
+    fn observe_every_mutation() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Inspected>();
+        world.add_observer(
+            |mutation: On<AnyMutation>, mut inspected: ResMut<Inspected>| {
+                inspected.0.push((mutation.mutated, mutation.component_id));
+            },
+        );
+
+        let player = world.spawn((Health(100), Mana(50))).id();
+        world.spawn((
+            Monitor(player),
+            NotifyChanged::<Health>::default(),
+            NotifyChanged::<Mana>::default(),
+        ));
+        world.run_schedule(Update);
+        world.resource_mut::<Inspected>().0.clear();
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.get_mut::<Mana>(player).unwrap().0 -= 5;
+        world.run_schedule(Update);
+
+        let health = world.component_id::<Health>().unwrap();
+        let mana = world.component_id::<Mana>().unwrap();
+        let mut inspected = world.resource::<Inspected>().0.clone();
+        inspected.sort_unstable();
+
+        let mut expected = vec![(player, health), (player, mana)];
+        expected.sort_unstable();
+
+        assert_eq!(inspected, expected);
+    }
+}
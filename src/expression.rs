@@ -0,0 +1,605 @@
+use crate::{
+    dispatch::Notifier,
+    monitors::{Hierarchy, MonitorTargets},
+    mutation::configure_notify_sets,
+    prelude::*,
+    registry::{add_systems, register_once, register_teardown, unregister},
+};
+use bevy_app::Update;
+use bevy_ecs::{
+    entity::EntityHashMap, lifecycle::HookContext, prelude::*, schedule::ScheduleCleanupPolicy,
+    system::SystemState, world::DeferredWorld,
+};
+use bevy_reflect::{GetPath, PartialReflect, ReflectRef, TypeRegistry};
+use std::{error::Error, fmt};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// An error encountered while parsing a [`WatchExpression`].
+pub struct ExpressionError {
+    /// The byte offset into the expression the error was found at.
+    pub position: usize,
+    pub message: &'static str,
+}
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+impl Error for ExpressionError {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Literal {
+    Number(f64),
+    Bool(bool),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Token {
+    Path(String),
+    Literal(Literal),
+    Comparison(Comparison),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Node {
+    Or(Box<Node>, Box<Node>),
+    And(Box<Node>, Box<Node>),
+    Not(Box<Node>),
+    /// A component, or a field of one, on its own.
+    Value {
+        component: String,
+        field: Option<String>,
+    },
+    Compare {
+        component: String,
+        field: Option<String>,
+        comparison: Comparison,
+        literal: Literal,
+    },
+}
+
+#[derive(Clone, PartialEq, Debug)]
+/// A condition on the components of an entity, such as `"Health < 20 && !Shielded"`, evaluated
+/// through reflection.
+///
+/// Expressions are made up of:
+///
+/// - Components by their short or full type path, `Shielded`, which are true when the entity has
+///   the component.
+/// - Fields of components by their reflection path, `Transform.translation.y`, which are true when
+///   the field is a `bool` that is `true`.
+/// - Comparisons between a component or field and a number or `bool`, with `<`, `<=`, `>`, `>=`,
+///   `==`, and `!=`. Comparing a tuple struct with a single field, such as `Health(u8)`, compares
+///   the field.
+/// - `!`, `&&`, `||`, and parentheses, with the usual precedence.
+///
+/// Components need to be registered in the [`AppTypeRegistry`] with `#[reflect(Component)]`,
+/// comparisons against missing components or fields are false.
+pub struct WatchExpression {
+    source: String,
+    root: Node,
+}
+impl WatchExpression {
+    pub fn parse(source: &str) -> Result<Self, ExpressionError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            position: 0,
+            end: source.len(),
+        };
+        let root = parser.or()?;
+
+        if let Some(&(position, _)) = parser.peek() {
+            return Err(ExpressionError {
+                position,
+                message: "expected `&&` or `||`",
+            });
+        }
+
+        Ok(Self {
+            source: source.to_string(),
+            root,
+        })
+    }
+    /// The expression this was parsed from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+    /// Evaluates the expression against `entity`, looking up components in `registry`.
+    pub fn evaluate(&self, entity: EntityRef, registry: &TypeRegistry) -> bool {
+        evaluate(&self.root, entity, registry)
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<(usize, Token)>, ExpressionError> {
+    let mut tokens = Vec::new();
+    let mut position = 0;
+
+    while let Some(char) = source[position..].chars().next() {
+        let rest = &source[position..];
+
+        if char.is_whitespace() {
+            position += char.len_utf8();
+            continue;
+        }
+
+        let (len, token) = match (rest.get(..2), char) {
+            (Some("&&"), _) => (2, Token::And),
+            (Some("||"), _) => (2, Token::Or),
+            (Some("<="), _) => (2, Token::Comparison(Comparison::LessOrEqual)),
+            (Some(">="), _) => (2, Token::Comparison(Comparison::GreaterOrEqual)),
+            (Some("=="), _) => (2, Token::Comparison(Comparison::Equal)),
+            (Some("!="), _) => (2, Token::Comparison(Comparison::NotEqual)),
+            (_, '<') => (1, Token::Comparison(Comparison::Less)),
+            (_, '>') => (1, Token::Comparison(Comparison::Greater)),
+            (_, '!') => (1, Token::Not),
+            (_, '(') => (1, Token::Open),
+            (_, ')') => (1, Token::Close),
+            (_, '-' | '0'..='9') => {
+                let len = rest[1..]
+                    .find(|char: char| !(char.is_ascii_digit() || char == '.'))
+                    .map_or(rest.len(), |len| len + 1);
+                let number = rest[..len].parse().map_err(|_| ExpressionError {
+                    position,
+                    message: "invalid number",
+                })?;
+
+                (len, Token::Literal(Literal::Number(number)))
+            }
+            _ if char.is_alphabetic() || char == '_' => {
+                let len = rest
+                    .find(|char: char| !(char.is_alphanumeric() || "_:.".contains(char)))
+                    .unwrap_or(rest.len());
+                let token = match &rest[..len] {
+                    "true" => Token::Literal(Literal::Bool(true)),
+                    "false" => Token::Literal(Literal::Bool(false)),
+                    path => Token::Path(path.to_string()),
+                };
+
+                (len, token)
+            }
+            _ => {
+                return Err(ExpressionError {
+                    position,
+                    message: "unexpected character",
+                });
+            }
+        };
+
+        tokens.push((position, token));
+        position += len;
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'t> {
+    tokens: &'t [(usize, Token)],
+    position: usize,
+    /// The length of the expression, used as the position of errors at the end of it.
+    end: usize,
+}
+impl<'t> Parser<'t> {
+    fn peek(&self) -> Option<&'t (usize, Token)> {
+        self.tokens.get(self.position)
+    }
+    fn next(&mut self) -> Option<&'t (usize, Token)> {
+        let token = self.peek();
+        self.position += 1;
+        token
+    }
+    fn or(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.and()?;
+
+        while let Some((_, Token::Or)) = self.peek() {
+            self.position += 1;
+            node = Node::Or(Box::new(node), Box::new(self.and()?));
+        }
+        Ok(node)
+    }
+    fn and(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.unary()?;
+
+        while let Some((_, Token::And)) = self.peek() {
+            self.position += 1;
+            node = Node::And(Box::new(node), Box::new(self.unary()?));
+        }
+        Ok(node)
+    }
+    fn unary(&mut self) -> Result<Node, ExpressionError> {
+        match self.next() {
+            Some((_, Token::Not)) => Ok(Node::Not(Box::new(self.unary()?))),
+            Some((_, Token::Open)) => {
+                let node = self.or()?;
+
+                match self.next() {
+                    Some((_, Token::Close)) => Ok(node),
+                    other => Err(self.error(other, "expected `)`")),
+                }
+            }
+            Some((_, Token::Path(path))) => {
+                let (component, field) = match path.split_once('.') {
+                    Some((component, field)) => (component.to_string(), Some(format!(".{field}"))),
+                    None => (path.clone(), None),
+                };
+
+                let Some(&(_, Token::Comparison(comparison))) = self.peek() else {
+                    return Ok(Node::Value { component, field });
+                };
+                self.position += 1;
+
+                match self.next() {
+                    Some(&(_, Token::Literal(literal))) => Ok(Node::Compare {
+                        component,
+                        field,
+                        comparison,
+                        literal,
+                    }),
+                    other => Err(self.error(other, "expected a number or `bool`")),
+                }
+            }
+            other => Err(self.error(other, "expected a component")),
+        }
+    }
+    fn error(&self, token: Option<&(usize, Token)>, message: &'static str) -> ExpressionError {
+        ExpressionError {
+            position: token.map_or(self.end, |&(position, _)| position),
+            message,
+        }
+    }
+}
+
+fn evaluate(node: &Node, entity: EntityRef, registry: &TypeRegistry) -> bool {
+    match node {
+        Node::Or(a, b) => evaluate(a, entity, registry) || evaluate(b, entity, registry),
+        Node::And(a, b) => evaluate(a, entity, registry) && evaluate(b, entity, registry),
+        Node::Not(node) => !evaluate(node, entity, registry),
+        Node::Value {
+            component,
+            field: None,
+        } => resolve(component, None, entity, registry).is_some(),
+        Node::Value {
+            component,
+            field: Some(field),
+        } => resolve(component, Some(field), entity, registry)
+            .and_then(|value| value.try_downcast_ref::<bool>())
+            .is_some_and(|&value| value),
+        Node::Compare {
+            component,
+            field,
+            comparison,
+            literal,
+        } => resolve(component, field.as_deref(), entity, registry)
+            .is_some_and(|value| compare(value, *comparison, *literal)),
+    }
+}
+
+/// Finds the value of `component`, or `field` of it, on `entity`.
+fn resolve<'w>(
+    component: &str,
+    field: Option<&str>,
+    entity: EntityRef<'w>,
+    registry: &TypeRegistry,
+) -> Option<&'w dyn PartialReflect> {
+    let registration = registry
+        .get_with_short_type_path(component)
+        .or_else(|| registry.get_with_type_path(component))?;
+    let reflected = registration.data::<ReflectComponent>()?.reflect(entity)?;
+
+    match field {
+        Some(field) => reflected.reflect_path(field).ok(),
+        None => match reflected.reflect_ref() {
+            ReflectRef::TupleStruct(tuple) if tuple.field_len() == 1 => tuple.field(0),
+            _ => Some(reflected.as_partial_reflect()),
+        },
+    }
+}
+
+fn compare(value: &dyn PartialReflect, comparison: Comparison, literal: Literal) -> bool {
+    let ordering = match literal {
+        Literal::Number(number) => as_number(value).and_then(|value| value.partial_cmp(&number)),
+        Literal::Bool(bool) => value
+            .try_downcast_ref::<bool>()
+            .map(|value| value.cmp(&bool)),
+    };
+
+    ordering.is_some_and(|ordering| match comparison {
+        Comparison::Less => ordering.is_lt(),
+        Comparison::LessOrEqual => ordering.is_le(),
+        Comparison::Greater => ordering.is_gt(),
+        Comparison::GreaterOrEqual => ordering.is_ge(),
+        Comparison::Equal => ordering.is_eq(),
+        Comparison::NotEqual => ordering.is_ne(),
+    })
+}
+
+fn as_number(value: &dyn PartialReflect) -> Option<f64> {
+    macro_rules! numbers {
+        ($($number:ty),*) => {
+            $(
+                if let Some(&value) = value.try_downcast_ref::<$number>() {
+                    return Some(value as f64);
+                }
+            )*
+        };
+    }
+
+    numbers!(f32, f64, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    None
+}
+
+#[derive(EntityEvent)]
+/// Indicates that the [`WatchExpression`] of a [`NotifyExpression`] changed truth value on an
+/// entity watched by a monitor.
+pub struct ExpressionChanged {
+    pub entity: Entity,
+    /// The [`Entity`] the expression was evaluated against.
+    pub watched: Entity,
+    /// Whether the expression is now true.
+    pub value: bool,
+}
+
+#[derive(Component)]
+#[component(
+    on_add = NotifyExpression::register_expression_system,
+    on_remove = NotifyExpression::remove_expression_system
+)]
+/// Adding this component to a monitor will cause it to react with [`ExpressionChanged`] whenever
+/// a [`WatchExpression`] changes truth value on the entities it is watching.
+///
+/// This allows conditions to be created at runtime without any code, such as from a debug console
+/// or by designers.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # fn showcase(mut commands: Commands, player: Entity) -> Result<(), BevyError> {
+/// commands
+///     .spawn((
+///         Monitor(player),
+///         NotifyExpression::new("Health < 20 && !Shielded")?,
+///     ))
+///     .observe(|changed: On<ExpressionChanged>| {
+///         // Show or hide the low health warning.
+///     });
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Unlike other monitors only [`Monitor`], [`MonitorSelf`], [`MonitorMany`], and [`MonitorChildren`]
+/// are watched, the expression is never evaluated against all entities. Expressions start off as
+/// false, so one that is already true when first evaluated will react.
+///
+/// # Technical info
+///
+/// Adding this component to an entity will add a system to the [`Update`] schedule that runs in
+/// [`NotifySet::Dispatch`], this is only done once. Expressions are evaluated every time it runs.
+///
+/// When all instances of this component in the world are removed the system will be removed.
+pub struct NotifyExpression {
+    expression: WatchExpression,
+    previous: EntityHashMap<bool>,
+}
+impl NotifyExpression {
+    /// Parses `expression` to be watched, see [`WatchExpression`] for the syntax.
+    pub fn new(expression: &str) -> Result<Self, ExpressionError> {
+        Ok(Self::from(WatchExpression::parse(expression)?))
+    }
+    pub fn expression(&self) -> &WatchExpression {
+        &self.expression
+    }
+    fn register_expression_system(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                configure_notify_sets(world, Update);
+                add_systems(world, Update, watch_expressions.in_set(NotifySet::Dispatch));
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_expression_system(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_expressions = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_expressions == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        world.schedule_scope(Update, |world, schedule| {
+            // # Safety
+            // `NotifyExpression` being registered ensures this system exists in the
+            // `Update` schedule.
+            schedule
+                .remove_systems_in_set(
+                    watch_expressions,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+    }
+}
+impl From<WatchExpression> for NotifyExpression {
+    fn from(expression: WatchExpression) -> Self {
+        Self {
+            expression,
+            previous: EntityHashMap::default(),
+        }
+    }
+}
+
+fn watch_expressions(
+    world: &mut World,
+    monitors: &mut QueryState<(MonitorTargets, &NotifyExpression)>,
+    hierarchy: &mut SystemState<Hierarchy>,
+    notifier: &mut SystemState<Notifier>,
+) {
+    let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return;
+    };
+    let registry = registry.read();
+    let hierarchy = hierarchy.get(world);
+
+    let changes = monitors
+        .iter(world)
+        .flat_map(|(targets, notify)| {
+            let monitor = targets.entity;
+
+            targets
+                .resolve(&hierarchy)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|watched| {
+                    let value = world
+                        .get_entity(watched)
+                        .is_ok_and(|entity| notify.expression.evaluate(entity, &registry));
+                    let previous = notify.previous.get(&watched).copied().unwrap_or_default();
+
+                    (value != previous).then_some((monitor, watched, value))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    drop(registry);
+
+    changes.iter().for_each(|&(monitor, watched, value)| {
+        if let Some(mut notify) = world.get_mut::<NotifyExpression>(monitor) {
+            notify.previous.insert(watched, value);
+        }
+    });
+
+    let mut param = notifier.get_mut(world);
+    changes.into_iter().for_each(|(entity, watched, value)| {
+        param.trigger(ExpressionChanged {
+            entity,
+            watched,
+            value,
+        });
+    });
+    notifier.apply(world);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    pub struct Health(u8);
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    pub struct Shielded;
+
+    #[derive(Resource, Default)]
+    pub struct Warnings(Vec<bool>);
+
+    #[test]
+    fn react_to_truth_value() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Warnings>();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Shielded>();
+        }
+
+        assert_eq!(
+            WatchExpression::parse("Health < && Shielded").unwrap_err(),
+            ExpressionError {
+                position: 9,
+                message: "expected a number or `bool`",
+            }
+        );
+
+        let player = world.spawn(Health(50)).id();
+        world
+            .spawn((
+                Monitor(player),
+                NotifyExpression::new("Health < 20 && !(Shielded || Health.0 == 0)").unwrap(),
+            ))
+            .observe(
+                |changed: On<ExpressionChanged>, mut warnings: ResMut<Warnings>| {
+                    warnings.0.push(changed.value);
+                },
+            );
+
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(player).unwrap().0 = 10;
+        world.run_schedule(Update);
+
+        // Still true.
+        world.get_mut::<Health>(player).unwrap().0 = 5;
+        world.run_schedule(Update);
+
+        world.entity_mut(player).insert(Shielded);
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Warnings>().0, vec![true, false]);
+    }
+
+    #[test]
+    fn react_for_many_targets() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Warnings>();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+
+        let party = [world.spawn(Health(50)).id(), world.spawn(Health(50)).id()];
+        world
+            .spawn((
+                MonitorMany(party.to_vec()),
+                NotifyExpression::new("Health < 20").unwrap(),
+            ))
+            .observe(
+                |changed: On<ExpressionChanged>, mut warnings: ResMut<Warnings>| {
+                    warnings.0.push(changed.value);
+                },
+            );
+
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(party[0]).unwrap().0 = 10;
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(party[1]).unwrap().0 = 10;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Warnings>().0, vec![true, true]);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::prelude::*;
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypePath;
+use std::ffi::c_void;
+
+/// A callback registered by a host application, see [`FfiNotifications`].
+///
+/// `user_data` is the pointer given when the callback was registered. `notification` is only valid
+/// for the duration of the call.
+pub type FfiCallback =
+    unsafe extern "C" fn(user_data: *mut c_void, notification: *const FfiNotification);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(C)]
+/// A notification passed to [`FfiCallback`]s.
+pub struct FfiNotification {
+    pub kind: NotificationKind,
+    /// The monitor that received the notification, as returned by [`Entity::to_bits`].
+    pub monitor: u64,
+    /// The entity the component belongs to, as returned by [`Entity::to_bits`].
+    pub watched: u64,
+    /// The type path of the component as UTF-8, this is not nul terminated.
+    pub component: *const u8,
+    /// The length of `component` in bytes.
+    pub component_len: usize,
+}
+
+/// A registered callback along with its user data.
+#[derive(Clone, Copy)]
+struct FfiSink {
+    callback: FfiCallback,
+    user_data: *mut c_void,
+}
+
+// # Safety
+// Whoever registers the sink guarantees `user_data` can be used from any thread.
+unsafe impl Send for FfiSink {}
+unsafe impl Sync for FfiSink {}
+
+#[derive(Resource, Default)]
+/// Contains the callbacks registered with [`FfiNotifications::register_ffi_callback`].
+struct FfiSinks(Vec<FfiSink>);
+
+/// Allows host applications embedding Bevy, such as editors or simulation frontends written in
+/// other languages, to receive notifications through a C callback.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::ffi::c_void;
+/// # #[derive(Component, Reflect)]
+/// # pub struct Health(pub u8);
+/// unsafe extern "C" fn on_notification(_: *mut c_void, notification: *const FfiNotification) {
+///     // Hand the notification over to the host.
+/// }
+///
+/// # let mut app = App::new();
+/// // Safety: `on_notification` doesn't use its user data.
+/// unsafe {
+///     app.forward_to_ffi::<Health>()
+///         .register_ffi_callback(on_notification, std::ptr::null_mut());
+/// }
+/// ```
+pub trait FfiNotifications {
+    /// Forwards every [`Addition<C>`], [`Mutation<C>`], and [`Removal<C>`] triggered in this world
+    /// to the registered callbacks.
+    ///
+    /// Only the notifications themselves are forwarded, monitors still need to be set up as usual.
+    fn forward_to_ffi<C: Component + TypePath>(&mut self) -> &mut Self;
+    /// Registers `callback` to be called with `user_data` for every forwarded notification.
+    ///
+    /// # Safety
+    ///
+    /// `callback` must be safe to call with `user_data` from any thread, for as long as this world
+    /// exists.
+    unsafe fn register_ffi_callback(
+        &mut self,
+        callback: FfiCallback,
+        user_data: *mut c_void,
+    ) -> &mut Self;
+}
+
+impl FfiNotifications for World {
+    fn forward_to_ffi<C: Component + TypePath>(&mut self) -> &mut Self {
+        self.init_resource::<FfiSinks>();
+
+        self.add_observer(|addition: On<Addition<C>>, sinks: Res<FfiSinks>| {
+            forward::<C>(
+                &sinks,
+                NotificationKind::Added,
+                addition.entity,
+                addition.added,
+            );
+        });
+        self.add_observer(|mutation: On<Mutation<C>>, sinks: Res<FfiSinks>| {
+            forward::<C>(
+                &sinks,
+                NotificationKind::Changed,
+                mutation.entity,
+                mutation.mutated,
+            );
+        });
+        self.add_observer(|removal: On<Removal<C>>, sinks: Res<FfiSinks>| {
+            forward::<C>(
+                &sinks,
+                NotificationKind::Removed,
+                removal.entity,
+                removal.removed,
+            );
+        });
+        self
+    }
+    unsafe fn register_ffi_callback(
+        &mut self,
+        callback: FfiCallback,
+        user_data: *mut c_void,
+    ) -> &mut Self {
+        self.get_resource_or_init::<FfiSinks>().0.push(FfiSink {
+            callback,
+            user_data,
+        });
+        self
+    }
+}
+
+impl FfiNotifications for App {
+    fn forward_to_ffi<C: Component + TypePath>(&mut self) -> &mut Self {
+        self.world_mut().forward_to_ffi::<C>();
+        self
+    }
+    unsafe fn register_ffi_callback(
+        &mut self,
+        callback: FfiCallback,
+        user_data: *mut c_void,
+    ) -> &mut Self {
+        // # Safety
+        // Upheld by the caller.
+        unsafe {
+            self.world_mut().register_ffi_callback(callback, user_data);
+        }
+        self
+    }
+}
+
+fn forward<C: Component + TypePath>(
+    sinks: &FfiSinks,
+    kind: NotificationKind,
+    monitor: Entity,
+    watched: Entity,
+) {
+    let component = C::type_path();
+    let notification = FfiNotification {
+        kind,
+        monitor: monitor.to_bits(),
+        watched: watched.to_bits(),
+        component: component.as_ptr(),
+        component_len: component.len(),
+    };
+
+    sinks.0.iter().for_each(|sink| {
+        // # Safety
+        // Upheld by whoever registered the sink, the notification outlives the call.
+        unsafe { (sink.callback)(sink.user_data, &notification) }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+    use std::{ffi::c_void, sync::Mutex};
+
+    #[derive(Component, Reflect)]
+    pub struct Health(u8);
+
+    type Log = Mutex<Vec<(NotificationKind, Entity, String)>>;
+
+    unsafe extern "C" fn record(user_data: *mut c_void, notification: *const FfiNotification) {
+        // # Safety
+        // The test passes a `Mutex` that outlives the world.
+        let (log, notification) = unsafe { (&*(user_data as *const Log), &*notification) };
+        // # Safety
+        // The type path is valid for the duration of the call.
+        let component = unsafe {
+            std::slice::from_raw_parts(notification.component, notification.component_len)
+        };
+
+        log.lock().unwrap().push((
+            notification.kind,
+            Entity::from_bits(notification.watched),
+            String::from_utf8(component.to_vec()).unwrap(),
+        ));
+    }
+
+    #[test]
+    fn forward_to_host() {
+        let log = Log::default();
+        let mut world = World::new();
+
+        // # Safety
+        // `log` outlives `world` and is only accessed through the mutex.
+        unsafe {
+            world
+                .forward_to_ffi::<Health>()
+                .register_ffi_callback(record, &log as *const _ as *mut c_void);
+        }
+
+        let player = world.spawn_empty().id();
+        world.spawn((
+            Monitor(player),
+            NotifyAdded::<Health>::default(),
+            NotifyRemoved::<Health>::default(),
+        ));
+
+        world.entity_mut(player).insert(Health(100));
+        world.entity_mut(player).remove::<Health>();
+
+        drop(world);
+
+        assert_eq!(
+            log.into_inner().unwrap(),
+            vec![
+                (
+                    NotificationKind::Added,
+                    player,
+                    Health::type_path().to_owned()
+                ),
+                (
+                    NotificationKind::Removed,
+                    player,
+                    Health::type_path().to_owned()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,474 @@
+use crate::{
+    monitors::resolve_targets,
+    prelude::*,
+    registry::{register_once, register_teardown, unregister},
+};
+use bevy_ecs::{
+    component::Mutable, entity::EntityHashMap, lifecycle::HookContext, prelude::*,
+    world::DeferredWorld,
+};
+use bevy_reflect::{PartialReflect, Reflect, ReflectRef};
+use std::marker::PhantomData;
+
+/// The fields of a component that changed, as pairs of a reflect path such as `.position.x` and a
+/// reflected copy of the new value.
+///
+/// A path of `""` means the whole component changed.
+pub type FieldDiff = Vec<(String, Box<dyn PartialReflect>)>;
+
+#[derive(Resource)]
+/// Contains the observer forgetting the values of [`C`] removed from watched entities.
+struct PruningDiffs<C: Component> {
+    observer: Entity,
+    _phantom: PhantomData<C>,
+}
+
+#[derive(Component)]
+#[component(
+    on_add = DiffFields::<C>::snapshot_targets,
+    on_remove = DiffFields::<C>::remove_pruning
+)]
+#[require(NotifyChanged<C> = NotifyChanged::required())]
+/// Adding this component to a monitor will cause the [`Mutation<C>`] it receives to contain the
+/// fields of [`C`] that changed, see [`Mutation::diff`] and [`Mutation::changed_fields`].
+///
+/// This is useful for editors and network layers that need to know exactly which fields changed
+/// without keeping their own copies of [`C`].
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component, Reflect)]
+/// # pub struct Stats { health: u8, mana: u8 }
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands.spawn((Monitor(player), DiffFields::<Stats>::default())).observe(
+///     |mutation: On<Mutation<Stats>>| {
+///         for (path, value) in mutation.diff().into_iter().flatten() {
+///             println!("{path} is now {value:?}");
+///         }
+///     },
+/// );
+/// # }
+/// ```
+///
+/// # Technical info
+///
+/// The diff is computed against a reflected copy of the value of [`C`] last seen by this monitor.
+/// The watched entity's value is recorded when this component is added, entities that are not
+/// known at that point (such as those watched by a global monitor) report the whole component as
+/// changed the first time.
+///
+/// Fields of structs, tuples, tuple structs, and lists or arrays of the same length are compared
+/// individually, anything else is compared as a whole.
+///
+/// Adding this component to an entity will spawn an [`Observer`] for [`Remove`] of [`C`], which
+/// forgets the last seen value of entities [`C`] is removed from or that are despawned. This is
+/// only done once, and the observer is despawned when all instances of this component in the
+/// world are removed.
+pub struct DiffFields<C: Component> {
+    reflect: fn(&C) -> &dyn PartialReflect,
+    previous: EntityHashMap<Box<dyn PartialReflect>>,
+}
+impl<C: Component<Mutability = Mutable> + Reflect> Default for DiffFields<C> {
+    fn default() -> Self {
+        Self {
+            reflect: |value| value.as_partial_reflect(),
+            previous: EntityHashMap::default(),
+        }
+    }
+}
+impl<C: Component> DiffFields<C> {
+    fn snapshot_targets(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        world.commands().queue(move |world: &mut World| {
+            let Some(&DiffFields { reflect, .. }) = world.get::<Self>(entity) else {
+                return;
+            };
+
+            let targets = resolve_targets(world, entity).unwrap_or_default();
+
+            let previous = targets
+                .into_iter()
+                .filter_map(|target| Some((target, reflect(world.get::<C>(target)?).to_dynamic())))
+                .collect::<Vec<_>>();
+
+            if let Some(mut diff) = world.get_mut::<Self>(entity) {
+                diff.previous.extend(previous);
+            }
+        });
+
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                let observer = world.add_observer(forget_removed::<C>).id();
+                world.insert_resource(PruningDiffs::<C> {
+                    observer,
+                    _phantom: PhantomData,
+                });
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_pruning(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_diffing = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_diffing == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `DiffFields::<C>` adds the `PruningDiffs` resource.
+        let PruningDiffs { observer, .. } = world.remove_resource::<PruningDiffs<C>>().unwrap();
+        world.entity_mut(observer).despawn();
+    }
+    /// Computes the fields that changed since the last value of [`C`] seen on `watched`, and
+    /// records `value` as the new last seen value.
+    pub(crate) fn diff(&mut self, watched: Entity, value: &C) -> FieldDiff {
+        let current = (self.reflect)(value);
+        let mut diff = FieldDiff::new();
+
+        match self.previous.get(&watched) {
+            Some(previous) => diff_into(&mut String::new(), previous.as_ref(), current, &mut diff),
+            None => diff.push((String::new(), current.to_dynamic())),
+        }
+
+        self.previous.insert(watched, current.to_dynamic());
+        diff
+    }
+}
+
+fn forget_removed<C: Component>(remove: On<Remove, C>, mut diffs: Query<&mut DiffFields<C>>) {
+    diffs
+        .iter_mut()
+        .filter(|diff| diff.previous.contains_key(&remove.entity))
+        .for_each(|mut diff| {
+            diff.previous.remove(&remove.entity);
+        });
+}
+
+impl<C: Component> Mutation<C> {
+    /// The reflect paths of the fields of [`C`] that changed, such as `.position.x`, without their
+    /// new values. This is empty unless the monitor has [`DiffFields<C>`].
+    ///
+    /// ```rust
+    /// # use bevy_notify::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # #[derive(Component, Reflect)]
+    /// # pub struct Stats { health: u8, mana: u8 }
+    /// # fn showcase(mut commands: Commands, player: Entity) {
+    /// commands.spawn((Monitor(player), DiffFields::<Stats>::default())).observe(
+    ///     |mutation: On<Mutation<Stats>>| {
+    ///         // A path of `""` means the whole component changed.
+    ///         if mutation.changed_fields().any(|path| path.is_empty() || path == ".health") {
+    ///             println!("Redraw the health bar");
+    ///         }
+    ///     },
+    /// );
+    /// # }
+    /// ```
+    pub fn changed_fields(&self) -> impl Iterator<Item = &str> {
+        self.diff()
+            .into_iter()
+            .flatten()
+            .map(|(path, _)| path.as_str())
+    }
+}
+
+fn diff_into(
+    path: &mut String,
+    previous: &dyn PartialReflect,
+    current: &dyn PartialReflect,
+    diff: &mut FieldDiff,
+) {
+    let fields = match (previous.reflect_ref(), current.reflect_ref()) {
+        (ReflectRef::Struct(previous), ReflectRef::Struct(current))
+            if previous.field_len() == current.field_len() =>
+        {
+            (0..current.field_len())
+                .map(|index| {
+                    let name = current.name_at(index)?;
+                    Some((
+                        format!(".{name}"),
+                        previous.field(name)?,
+                        current.field_at(index)?,
+                    ))
+                })
+                .collect::<Option<Vec<_>>>()
+        }
+        (ReflectRef::TupleStruct(previous), ReflectRef::TupleStruct(current))
+            if previous.field_len() == current.field_len() =>
+        {
+            (0..current.field_len())
+                .map(|index| {
+                    Some((
+                        format!(".{index}"),
+                        previous.field(index)?,
+                        current.field(index)?,
+                    ))
+                })
+                .collect()
+        }
+        (ReflectRef::Tuple(previous), ReflectRef::Tuple(current))
+            if previous.field_len() == current.field_len() =>
+        {
+            (0..current.field_len())
+                .map(|index| {
+                    Some((
+                        format!(".{index}"),
+                        previous.field(index)?,
+                        current.field(index)?,
+                    ))
+                })
+                .collect()
+        }
+        (ReflectRef::List(previous), ReflectRef::List(current))
+            if previous.len() == current.len() =>
+        {
+            (0..current.len())
+                .map(|index| {
+                    Some((
+                        format!("[{index}]"),
+                        previous.get(index)?,
+                        current.get(index)?,
+                    ))
+                })
+                .collect()
+        }
+        (ReflectRef::Array(previous), ReflectRef::Array(current))
+            if previous.len() == current.len() =>
+        {
+            (0..current.len())
+                .map(|index| {
+                    Some((
+                        format!("[{index}]"),
+                        previous.get(index)?,
+                        current.get(index)?,
+                    ))
+                })
+                .collect()
+        }
+        _ => None,
+    };
+
+    let Some(fields) = fields else {
+        if previous.reflect_partial_eq(current) != Some(true) {
+            diff.push((path.clone(), current.to_dynamic()));
+        }
+        return;
+    };
+
+    fields.into_iter().for_each(|(segment, previous, current)| {
+        let len = path.len();
+        path.push_str(&segment);
+        diff_into(path, previous, current, diff);
+        path.truncate(len);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::{prelude::*, reflect::FromReflect};
+
+    #[derive(Component, Reflect, Clone, PartialEq, Debug)]
+    pub struct Stats {
+        health: u8,
+        mana: u8,
+        position: (f32, f32),
+    }
+
+    #[derive(Resource, Default)]
+    pub struct Changes(Vec<(String, Option<f32>, Option<u8>)>);
+
+    #[test]
+    fn changed_fields() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Changes>();
+
+        let player = world
+            .spawn(Stats {
+                health: 100,
+                mana: 50,
+                position: (0., 0.),
+            })
+            .id();
+        world
+            .spawn((Monitor(player), DiffFields::<Stats>::default()))
+            .observe(
+                |mutation: On<Mutation<Stats>>, mut changes: ResMut<Changes>| {
+                    changes
+                        .0
+                        .extend(mutation.diff().into_iter().flatten().map(|(path, value)| {
+                            (
+                                path.clone(),
+                                f32::from_reflect(value.as_ref()),
+                                u8::from_reflect(value.as_ref()),
+                            )
+                        }));
+                },
+            );
+
+        world.run_schedule(Update);
+
+        let mut stats = world.get_mut::<Stats>(player).unwrap();
+        stats.health = 80;
+        stats.position.1 = 2.5;
+
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<Changes>().0,
+            vec![
+                (".health".to_string(), None, Some(80)),
+                (".position.1".to_string(), Some(2.5), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn changed_fields_for_many_targets() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Changes>();
+
+        let stats = Stats {
+            health: 100,
+            mana: 50,
+            position: (0., 0.),
+        };
+        let party = [world.spawn(stats.clone()).id(), world.spawn(stats).id()];
+        world
+            .spawn((MonitorMany(party.to_vec()), DiffFields::<Stats>::default()))
+            .observe(
+                |mutation: On<Mutation<Stats>>, mut changes: ResMut<Changes>| {
+                    changes.0.extend(
+                        mutation
+                            .changed_fields()
+                            .map(|path| (path.to_string(), None, None)),
+                    );
+                },
+            );
+
+        world.run_schedule(Update);
+
+        world.get_mut::<Stats>(party[1]).unwrap().mana = 20;
+        world.run_schedule(Update);
+
+        // Without a snapshot of each target the whole component would be reported as changed.
+        assert_eq!(
+            world.resource::<Changes>().0,
+            vec![(".mana".to_string(), None, None)]
+        );
+    }
+
+    #[test]
+    fn changed_fields_for_children() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Changes>();
+
+        let party = world.spawn_empty().id();
+        let stats = Stats {
+            health: 100,
+            mana: 50,
+            position: (0., 0.),
+        };
+        let members = [
+            world.spawn((stats.clone(), ChildOf(party))).id(),
+            world.spawn((stats, ChildOf(party))).id(),
+        ];
+        world
+            .spawn((MonitorChildren(party), DiffFields::<Stats>::default()))
+            .observe(
+                |mutation: On<Mutation<Stats>>, mut changes: ResMut<Changes>| {
+                    changes.0.extend(
+                        mutation
+                            .changed_fields()
+                            .map(|path| (path.to_string(), None, None)),
+                    );
+                },
+            );
+
+        world.run_schedule(Update);
+
+        world.get_mut::<Stats>(members[0]).unwrap().health = 20;
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<Changes>().0,
+            vec![(".health".to_string(), None, None)]
+        );
+    }
+
+    #[test]
+    fn forget_removed_values() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Changes>();
+
+        let stats = Stats {
+            health: 100,
+            mana: 50,
+            position: (0., 0.),
+        };
+        let player = world.spawn(stats.clone()).id();
+        let monitor = world
+            .spawn((Monitor(player), DiffFields::<Stats>::default()))
+            .observe(
+                |mutation: On<Mutation<Stats>>, mut changes: ResMut<Changes>| {
+                    changes.0.extend(
+                        mutation
+                            .changed_fields()
+                            .map(|path| (path.to_string(), None, None)),
+                    );
+                },
+            )
+            .id();
+
+        world.run_schedule(Update);
+        world.resource_mut::<Changes>().0.clear();
+
+        world.entity_mut(player).remove::<Stats>();
+
+        assert!(
+            world
+                .get::<DiffFields<Stats>>(monitor)
+                .unwrap()
+                .previous
+                .is_empty()
+        );
+
+        // Added back with a different health, which is reported as a whole new value.
+        world.entity_mut(player).insert(Stats {
+            health: 20,
+            ..stats
+        });
+        world.run_schedule(Update);
+        world.entity_mut(player).despawn();
+
+        assert_eq!(
+            world.resource::<Changes>().0,
+            vec![(String::new(), None, None)]
+        );
+        assert!(
+            world
+                .get::<DiffFields<Stats>>(monitor)
+                .unwrap()
+                .previous
+                .is_empty()
+        );
+    }
+}
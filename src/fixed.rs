@@ -0,0 +1,163 @@
+use bevy_ecs::{entity::EntityHashMap, prelude::*};
+use bevy_reflect::Reflect;
+use std::marker::PhantomData;
+
+#[derive(Component, Reflect, Clone, Copy, Hash, PartialEq, Eq, Default, Debug)]
+/// Controls how a monitor is notified about changes made in [`FixedUpdate`], which may run any
+/// number of times per frame.
+///
+/// Monitors detect changes once per frame in [`Update`], so by default a component changed in
+/// several fixed steps is reported exactly once. Adding [`FixedGranularity::PerStep`] to a monitor
+/// will instead report a [`Mutation<C>`] for every fixed step that changed the component.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Velocity(pub Vec3);
+/// # fn showcase(mut commands: Commands, ball: Entity) {
+/// commands.spawn((
+///     Monitor(ball),
+///     NotifyChanged::<Velocity>::default(),
+///     FixedGranularity::PerStep,
+/// ));
+/// # }
+/// ```
+///
+/// Every [`Mutation<C>`] is still delivered in [`Update`] and sees the value [`C`] has at that
+/// point, only the last one of a frame has a [`FieldDiff`].
+///
+/// To detect changes within the fixed step that made them, with the value they had at the time,
+/// register the component with [`AppNotifyExt::add_notify_changed_fixed`] instead.
+///
+/// # Technical info
+///
+/// [`NotifyChanged<C>`] adds a system to the [`FixedPostUpdate`] schedule that counts the steps
+/// [`C`] changed in, it only runs while there are monitors with this component.
+///
+/// [`FixedUpdate`]: bevy_app::FixedUpdate
+/// [`FixedPostUpdate`]: bevy_app::FixedPostUpdate
+/// [`Update`]: bevy_app::Update
+/// [`Mutation<C>`]: crate::prelude::Mutation
+/// [`NotifyChanged<C>`]: crate::prelude::NotifyChanged
+/// [`FieldDiff`]: crate::prelude::FieldDiff
+/// [`AppNotifyExt::add_notify_changed_fixed`]: crate::AppNotifyExt::add_notify_changed_fixed
+pub enum FixedGranularity {
+    /// One notification per frame, however many fixed steps changed the component.
+    #[default]
+    PerFrame,
+    /// One notification per fixed step that changed the component, or a single one if it was only
+    /// changed outside of fixed steps.
+    PerStep,
+}
+
+#[derive(Resource)]
+/// Contains the number of fixed steps [`C`] changed in on each entity since changes were last
+/// detected.
+pub(crate) struct FixedSteps<C: Component> {
+    pub(crate) steps: EntityHashMap<u32>,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component> Default for FixedSteps<C> {
+    fn default() -> Self {
+        Self {
+            steps: EntityHashMap::default(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+pub(crate) fn count_fixed_steps<C: Component>(
+    mut fixed_steps: ResMut<FixedSteps<C>>,
+    changed: Query<Entity, Changed<C>>,
+) {
+    changed.iter().for_each(|entity| {
+        *fixed_steps.steps.entry(entity).or_default() += 1;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Velocity(f32);
+
+    #[derive(Resource, Default)]
+    pub struct Bounces(usize, usize);
+
+    #[test]
+    fn notify_per_step() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(FixedPostUpdate));
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Bounces>();
+
+        let ball = world.spawn(Velocity(1.)).id();
+        world
+            .spawn((Monitor(ball), NotifyChanged::<Velocity>::default()))
+            .observe(|_: On<Mutation<Velocity>>, mut bounces: ResMut<Bounces>| {
+                bounces.0 += 1;
+            });
+        world
+            .spawn((
+                Monitor(ball),
+                NotifyChanged::<Velocity>::default(),
+                FixedGranularity::PerStep,
+            ))
+            .observe(|_: On<Mutation<Velocity>>, mut bounces: ResMut<Bounces>| {
+                bounces.1 += 1;
+            });
+
+        world.run_schedule(Update);
+        *world.resource_mut::<Bounces>() = Bounces::default();
+
+        // Three fixed steps in a single frame.
+        for _ in 0..3 {
+            world.get_mut::<Velocity>(ball).unwrap().0 *= -1.;
+            world.run_schedule(FixedPostUpdate);
+        }
+        world.run_schedule(Update);
+
+        let bounces = world.resource::<Bounces>();
+        assert_eq!((bounces.0, bounces.1), (1, 3));
+    }
+
+    #[derive(Resource, Default)]
+    pub struct Seen(Vec<f32>);
+
+    #[test]
+    fn detect_within_step() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(FixedPostUpdate));
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Seen>();
+        world.add_notify_changed_fixed::<Velocity>();
+
+        let ball = world.spawn(Velocity(1.)).id();
+        world
+            .spawn((Monitor(ball), NotifyChanged::<Velocity>::default()))
+            .observe(
+                |mutation: On<Mutation<Velocity>>,
+                 velocities: Query<&Velocity>,
+                 mut seen: ResMut<Seen>| {
+                    seen.0.push(velocities.get(mutation.mutated).unwrap().0);
+                },
+            );
+
+        world.run_schedule(FixedPostUpdate);
+        world.resource_mut::<Seen>().0.clear();
+
+        // Two fixed steps in a single frame.
+        for velocity in [2., 3.] {
+            world.get_mut::<Velocity>(ball).unwrap().0 = velocity;
+            world.run_schedule(FixedPostUpdate);
+        }
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Seen>().0, vec![2., 3.]);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::mutation::watch_for_change;
+use bevy_ecs::{change_detection::Tick, prelude::*};
+use std::marker::PhantomData;
+
+#[derive(Resource)]
+/// When changes to [`C`] were last detected by the schedules they are detected in, and by
+/// [`FlushNotifications`], so that neither reports a change the other already has.
+pub(crate) struct DetectionTicks<C: Component> {
+    /// The last time a schedule detected changes.
+    pub(crate) detected: Tick,
+    /// The last time changes were flushed.
+    pub(crate) flushed: Tick,
+    /// Whether changes are being flushed right now.
+    pub(crate) flushing: bool,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component> Default for DetectionTicks<C> {
+    fn default() -> Self {
+        Self {
+            detected: Tick::new(0),
+            flushed: Tick::new(0),
+            flushing: false,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Component> DetectionTicks<C> {
+    /// Returns the tick changes must be newer than to be reported, and records `this_run` as the
+    /// last time changes were detected.
+    pub(crate) fn advance(&mut self, this_run: Tick) -> Tick {
+        if self.flushing {
+            self.flushed = this_run;
+            self.detected
+        } else {
+            self.detected = this_run;
+            self.flushed
+        }
+    }
+}
+
+/// Allows detecting changes immediately rather than waiting for the schedule they are detected in
+/// to run, such as within exclusive systems and tests.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// fn apply_damage(world: &mut World) {
+///     let mut players = world.query::<&mut Health>();
+///
+///     players.iter_mut(world).for_each(|mut health| health.0 -= 10);
+///     // Observers have reacted to the damage by the time this returns.
+///     world.flush_notifications::<Health>();
+/// }
+/// ```
+///
+/// Changes reported by a flush aren't reported again when the schedule runs, and the other way
+/// around. [`Addition<C>`] and [`Removal<C>`] are always triggered immediately, so only
+/// [`Mutation<C>`] needs flushing.
+///
+/// Nothing happens if [`NotifyChanged<C>`] hasn't been registered.
+pub trait FlushNotifications {
+    /// Triggers [`Mutation<C>`] for every change to [`C`] that hasn't been reported yet.
+    fn flush_notifications<C: Component>(&mut self);
+}
+
+impl FlushNotifications for World {
+    fn flush_notifications<C: Component>(&mut self) {
+        let Some(mut ticks) = self.get_resource_mut::<DetectionTicks<C>>() else {
+            return;
+        };
+        ticks.flushing = true;
+
+        // # Safety
+        // `watch_for_change` never fails to run, as registering `NotifyChanged::<C>` adds every
+        // resource it relies on.
+        self.run_system_cached(watch_for_change::<C>).unwrap();
+        self.resource_mut::<DetectionTicks<C>>().flushing = false;
+    }
+}
+
+impl FlushNotifications for Commands<'_, '_> {
+    fn flush_notifications<C: Component>(&mut self) {
+        self.queue(|world: &mut World| world.flush_notifications::<C>());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Mutations(usize);
+
+    #[test]
+    fn flush_before_schedule() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Mutations>();
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(
+                |_: On<Mutation<Health>>, mut mutations: ResMut<Mutations>| {
+                    mutations.0 += 1;
+                },
+            );
+        world.flush();
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.flush_notifications::<Health>();
+
+        assert_eq!(world.resource::<Mutations>().0, 2);
+
+        // The change has already been reported.
+        world.run_schedule(Update);
+        world.flush_notifications::<Health>();
+
+        assert_eq!(world.resource::<Mutations>().0, 2);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Mutations>().0, 3);
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{
+    prelude::*,
+    registry::{add_systems, register_once},
+};
+use bevy_app::{App, AppLabel};
+use bevy_ecs::{prelude::*, schedule::ScheduleLabel};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// Notifications waiting to be delivered to another world.
+type ForwardingQueue<C> = Arc<Mutex<Vec<ForwardedNotification<C>>>>;
+
+#[derive(Event)]
+/// An [`Addition<C>`], [`Mutation<C>`], or [`Removal<C>`] triggered in another world, forwarded by
+/// [`ForwardNotifications`].
+///
+/// The entities belong to the world the notification was triggered in, so they have to be mapped
+/// before being used, such as through `MainEntity` in the render world.
+pub struct ForwardedNotification<C: Component> {
+    pub kind: NotificationKind,
+    /// The monitor that received the notification.
+    pub monitor: Entity,
+    /// The entity the component belongs to.
+    pub watched: Entity,
+    /// The change that caused the notification, unique to the world it was triggered in.
+    pub change: ChangeId,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component> Clone for ForwardedNotification<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<C: Component> Copy for ForwardedNotification<C> {}
+
+#[derive(Resource)]
+/// Contains the queues notifications about [`C`] are forwarded to.
+struct ForwardingTo<C: Component> {
+    queues: Vec<ForwardingQueue<C>>,
+}
+impl<C: Component> ForwardingTo<C> {
+    fn forward(&self, kind: NotificationKind, monitor: Entity, watched: Entity, change: ChangeId) {
+        self.queues.iter().for_each(|queue| {
+            queue.lock().unwrap().push(ForwardedNotification {
+                kind,
+                monitor,
+                watched,
+                change,
+                _phantom: PhantomData,
+            });
+        });
+    }
+}
+
+#[derive(Resource)]
+/// Contains the queues notifications about [`C`] are forwarded from.
+struct ForwardedFrom<C: Component> {
+    queues: Vec<ForwardingQueue<C>>,
+}
+
+/// Allows forwarding notifications between the main world and the world of a [`SubApp`], such as
+/// reacting in the render world to changes made in the main world.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::{app::AppLabel, prelude::*};
+/// # #[derive(AppLabel, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+/// # pub struct RenderApp;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # let mut app = App::new();
+/// app.forward_to_sub_app::<Health>(RenderApp, Update);
+/// ```
+///
+/// Every [`Addition<C>`], [`Mutation<C>`], and [`Removal<C>`] triggered in one world is triggered
+/// as a [`ForwardedNotification<C>`] the next time `schedule` runs in the other. Monitors still
+/// need to be set up in the world the notifications are triggered in, see
+/// [`NotifyPlugin::in_sub_app`] for registering components in a sub app.
+///
+/// [`SubApp`]: bevy_app::SubApp
+///
+/// # Panics
+///
+/// Panics if the sub app doesn't exist.
+pub trait ForwardNotifications {
+    /// Forwards notifications about [`C`] from the main world to the world of `sub_app`.
+    fn forward_to_sub_app<C: Component>(
+        &mut self,
+        sub_app: impl AppLabel,
+        schedule: impl ScheduleLabel,
+    ) -> &mut Self;
+    /// Forwards notifications about [`C`] from the world of `sub_app` to the main world.
+    fn forward_from_sub_app<C: Component>(
+        &mut self,
+        sub_app: impl AppLabel,
+        schedule: impl ScheduleLabel,
+    ) -> &mut Self;
+}
+
+impl ForwardNotifications for App {
+    fn forward_to_sub_app<C: Component>(
+        &mut self,
+        sub_app: impl AppLabel,
+        schedule: impl ScheduleLabel,
+    ) -> &mut Self {
+        let queue = ForwardingQueue::<C>::default();
+
+        forward_from::<C>(self.world_mut(), queue.clone());
+        forward_into::<C>(self.sub_app_mut(sub_app).world_mut(), schedule, queue);
+        self
+    }
+    fn forward_from_sub_app<C: Component>(
+        &mut self,
+        sub_app: impl AppLabel,
+        schedule: impl ScheduleLabel,
+    ) -> &mut Self {
+        let queue = ForwardingQueue::<C>::default();
+
+        forward_from::<C>(self.sub_app_mut(sub_app).world_mut(), queue.clone());
+        forward_into::<C>(self.world_mut(), schedule, queue);
+        self
+    }
+}
+
+/// Pushes every notification about [`C`] triggered in `world` to `queue`.
+fn forward_from<C: Component>(world: &mut World, queue: ForwardingQueue<C>) {
+    register_once::<ForwardingTo<C>>(world, |world| {
+        world.insert_resource(ForwardingTo::<C> { queues: Vec::new() });
+        world.add_observer(
+            |addition: On<Addition<C>>, forwarding: Res<ForwardingTo<C>>| {
+                forwarding.forward(
+                    NotificationKind::Added,
+                    addition.entity,
+                    addition.added,
+                    addition.change,
+                );
+            },
+        );
+        world.add_observer(
+            |mutation: On<Mutation<C>>, forwarding: Res<ForwardingTo<C>>| {
+                forwarding.forward(
+                    NotificationKind::Changed,
+                    mutation.entity,
+                    mutation.mutated,
+                    mutation.change,
+                );
+            },
+        );
+        world.add_observer(
+            |removal: On<Removal<C>>, forwarding: Res<ForwardingTo<C>>| {
+                forwarding.forward(
+                    NotificationKind::Removed,
+                    removal.entity,
+                    removal.removed,
+                    removal.change,
+                );
+            },
+        );
+    });
+    world.resource_mut::<ForwardingTo<C>>().queues.push(queue);
+}
+
+/// Triggers every notification about [`C`] pushed to `queue` when `schedule` runs in `world`.
+fn forward_into<C: Component>(
+    world: &mut World,
+    schedule: impl ScheduleLabel,
+    queue: ForwardingQueue<C>,
+) {
+    register_once::<ForwardedFrom<C>>(world, |world| {
+        world.insert_resource(ForwardedFrom::<C> { queues: Vec::new() });
+        add_systems(world, schedule, deliver_forwarded::<C>);
+    });
+    world.resource_mut::<ForwardedFrom<C>>().queues.push(queue);
+}
+
+fn deliver_forwarded<C: Component>(mut commands: Commands, forwarded: Res<ForwardedFrom<C>>) {
+    forwarded.queues.iter().for_each(|queue| {
+        std::mem::take(&mut *queue.lock().unwrap())
+            .into_iter()
+            .for_each(|notification| commands.trigger(notification));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::{
+        app::{AppLabel, SubApp},
+        ecs::schedule::ScheduleLabel,
+        prelude::*,
+    };
+
+    #[derive(AppLabel, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    pub struct SimulationApp;
+
+    #[derive(Component)]
+    pub struct Health;
+
+    #[derive(Resource, Default)]
+    pub struct Forwarded(Vec<(NotificationKind, Entity)>);
+
+    #[test]
+    fn forward_from_sub_app() {
+        let mut app = App::new();
+        let mut simulation = SubApp::new();
+
+        simulation.update_schedule = Some(Update.intern());
+        app.insert_sub_app(SimulationApp, simulation);
+        app.init_resource::<Forwarded>()
+            .add_plugins(
+                NotifyPlugin::default()
+                    .in_sub_app(SimulationApp)
+                    .watch_in::<Health>(Update),
+            )
+            .forward_from_sub_app::<Health>(SimulationApp, First)
+            .add_observer(
+                |forwarded: On<ForwardedNotification<Health>>, mut received: ResMut<Forwarded>| {
+                    received.0.push((forwarded.kind, forwarded.watched));
+                },
+            );
+
+        let world = app.sub_app_mut(SimulationApp).world_mut();
+        let player = world.spawn(Health).id();
+        world.spawn((Monitor(player), NotifyChanged::<Health>::default()));
+        world.flush();
+
+        // Notifications triggered in the sub app are received the next frame.
+        app.update();
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Forwarded>().0,
+            vec![(NotificationKind::Changed, player)]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{
+    mutation::{MutationSet, configure_notify_sets},
+    prelude::*,
+    registry::{add_systems, register_once},
+};
+use bevy_app::{App, Update};
+use bevy_ecs::{
+    change_detection::Tick,
+    prelude::*,
+    system::{IntoObserverSystem, SystemChangeTick},
+};
+use std::marker::PhantomData;
+
+#[derive(Event)]
+/// Indicates that the component [`C`] has been changed on an entity, without any monitor involved.
+///
+/// See [`ObserveChanged`] for more information on how this is triggered.
+pub struct GlobalMutation<C: Component> {
+    /// The [`Entity`] that [`C`] belongs to.
+    pub mutated: Entity,
+    /// The change tick at which this was triggered.
+    pub tick: Tick,
+    _phantom: PhantomData<C>,
+}
+
+/// Allows observing changes to a component type across the whole world without spawning a monitor,
+/// for services such as analytics or achievements that don't belong to any entity.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Gold(pub u32);
+/// # let mut app = App::new();
+/// app.observe_changed(|mutation: On<GlobalMutation<Gold>>| {
+///     // Check for the "hoarder" achievement.
+/// });
+/// ```
+///
+/// # Technical info
+///
+/// Observing [`C`] will add a system to the [`Update`] schedule in [`MutationSet`], this is only
+/// done once per component type. The system is kept when the observer is despawned.
+pub trait ObserveChanged {
+    /// Adds `observer` as a global observer of [`GlobalMutation<C>`], which is triggered whenever
+    /// [`C`] changes on any entity.
+    fn observe_changed<C: Component, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<GlobalMutation<C>, B, M>,
+    ) -> &mut Self;
+}
+
+impl ObserveChanged for World {
+    fn observe_changed<C: Component, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<GlobalMutation<C>, B, M>,
+    ) -> &mut Self {
+        register_once::<GlobalMutation<C>>(self, |world| {
+            configure_notify_sets(world, Update);
+            add_systems(
+                world,
+                Update,
+                trigger_global_mutations::<C>.in_set(MutationSet),
+            );
+        });
+
+        self.add_observer(observer);
+        self
+    }
+}
+
+impl ObserveChanged for App {
+    fn observe_changed<C: Component, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<GlobalMutation<C>, B, M>,
+    ) -> &mut Self {
+        self.world_mut().observe_changed(observer);
+        self
+    }
+}
+
+fn trigger_global_mutations<C: Component>(
+    mut commands: Commands,
+    ticks: SystemChangeTick,
+    suppressed: Option<Res<SuppressedNotifications<C>>>,
+    changed: Populated<Entity, Changed<C>>,
+) {
+    if suppressed.is_some() {
+        return;
+    }
+
+    changed.iter().for_each(|mutated| {
+        commands.trigger(GlobalMutation::<C> {
+            mutated,
+            tick: ticks.this_run(),
+            _phantom: PhantomData,
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Gold(u32);
+
+    #[derive(Resource, Default)]
+    pub struct Achievements(Vec<Entity>);
+
+    #[test]
+    fn observe_without_monitor() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Achievements>();
+
+        world.observe_changed(
+            |mutation: On<GlobalMutation<Gold>>,
+             gold: Query<&Gold>,
+             mut achievements: ResMut<Achievements>| {
+                if gold.get(mutation.mutated).is_ok_and(|gold| gold.0 >= 1000) {
+                    achievements.0.push(mutation.mutated);
+                }
+            },
+        );
+
+        let player = world.spawn(Gold(0)).id();
+        world.spawn(Gold(10));
+
+        world.run_schedule(Update);
+        world.get_mut::<Gold>(player).unwrap().0 = 1000;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Achievements>().0, vec![player]);
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{catch_up::catch_up, prelude::*};
+use bevy_ecs::{
+    intern::{Internable, Interned, Interner},
+    label::{DynEq, DynHash},
+    prelude::*,
+};
+use bevy_reflect::Reflect;
+use std::{
+    any::Any,
+    borrow::Cow,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    ptr,
+};
+
+/// Identifies a [`MonitorGroup`], implemented for every type that can be cloned, compared, and
+/// hashed.
+///
+/// Enums make the best labels, as unlike strings they survive refactors and can be matched
+/// exhaustively.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+/// pub enum Hud {
+///     Selection,
+///     Minimap,
+/// }
+///
+/// # fn showcase(mut commands: Commands, selected: Entity) {
+/// commands.retarget_group(Hud::Selection, selected);
+/// # }
+/// ```
+///
+/// Labels of different types are never equal, so `"hud"` and `String::from("hud")` are different
+/// groups.
+///
+/// Labels are interned into an [`InternedMonitorGroupLabel`] when placed in a [`MonitorGroup`], so
+/// comparing groups is as cheap as comparing pointers.
+pub trait MonitorGroupLabel: Send + Sync + Debug + DynEq + DynHash {
+    /// Clones this label into a box.
+    fn dyn_clone_group_label(&self) -> Box<dyn MonitorGroupLabel>;
+}
+impl<L: Clone + Eq + Hash + Debug + Send + Sync + 'static> MonitorGroupLabel for L {
+    fn dyn_clone_group_label(&self) -> Box<dyn MonitorGroupLabel> {
+        Box::new(self.clone())
+    }
+}
+
+impl PartialEq for dyn MonitorGroupLabel {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other)
+    }
+}
+impl Eq for dyn MonitorGroupLabel {}
+impl Hash for dyn MonitorGroupLabel {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.dyn_hash(state);
+    }
+}
+impl Internable for dyn MonitorGroupLabel {
+    fn leak(&self) -> &'static Self {
+        Box::leak(self.dyn_clone_group_label())
+    }
+    fn ref_eq(&self, other: &Self) -> bool {
+        self.type_id() == other.type_id() && ptr::addr_eq(ptr::from_ref(self), ptr::from_ref(other))
+    }
+    fn ref_hash<H: Hasher>(&self, state: &mut H) {
+        self.type_id().hash(state);
+        ptr::from_ref(self).cast::<()>().hash(state);
+    }
+}
+
+// Interned labels only identify a group and hold no per-world state, so sharing them between worlds
+// is fine, as it is for Bevy's own labels.
+static MONITOR_GROUP_LABEL_INTERNER: Interner<dyn MonitorGroupLabel> = Interner::new();
+
+/// A [`MonitorGroupLabel`] that has been interned.
+pub type InternedMonitorGroupLabel = Interned<dyn MonitorGroupLabel>;
+
+/// The name of `label` as shown by [`MonitorGraph`], strings are shown as they are while any other
+/// label is shown through [`Debug`].
+pub(crate) fn group_name(label: &dyn MonitorGroupLabel) -> String {
+    let label: &dyn Any = label;
+
+    label
+        .downcast_ref::<&'static str>()
+        .map(|name| name.to_string())
+        .or_else(|| label.downcast_ref::<String>().cloned())
+        .or_else(|| {
+            label
+                .downcast_ref::<Cow<'static, str>>()
+                .map(|name| name.to_string())
+        })
+        .unwrap_or_else(|| format!("{label:?}"))
+}
+
+#[derive(Component, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+#[reflect(opaque, Component, Clone, Hash, PartialEq, Debug)]
+/// Places a monitor in a group, allowing every monitor in the group to be retargeted at once with
+/// [`RetargetGroup::retarget_group`].
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+/// # pub enum Hud { Selection }
+/// # fn showcase(mut commands: Commands, selected: Entity) {
+/// commands.spawn((
+///     MonitorGroup::new(Hud::Selection),
+///     Monitor(selected),
+///     NotifyChanged::<Health>::default(),
+/// ));
+/// # }
+/// ```
+///
+/// See [`MonitorGroupLabel`] for what can be used as a group.
+pub struct MonitorGroup(pub InternedMonitorGroupLabel);
+impl MonitorGroup {
+    pub fn new(label: impl MonitorGroupLabel) -> Self {
+        Self(MONITOR_GROUP_LABEL_INTERNER.intern(&label))
+    }
+}
+
+/// Allows retargeting every monitor in a [`MonitorGroup`] at once, such as when the selection
+/// changes in an RTS or editor.
+pub trait RetargetGroup {
+    /// Points the [`Monitor`] of every monitor in `group` at `target`.
+    ///
+    /// Each monitor is then caught up with the current state of `target`, receiving
+    /// [`Mutation<C>`] for each [`NotifyChanged<C>`] and [`Addition<C>`] for each
+    /// [`NotifyAdded<C>`] where `target` has [`C`].
+    ///
+    /// All monitors are retargeted before any catch-up notifications are triggered.
+    fn retarget_group(&mut self, group: impl MonitorGroupLabel, target: Entity);
+}
+
+impl RetargetGroup for Commands<'_, '_> {
+    fn retarget_group(&mut self, group: impl MonitorGroupLabel, target: Entity) {
+        let MonitorGroup(group) = MonitorGroup::new(group);
+
+        self.queue(move |world: &mut World| {
+            let monitors = world
+                .query::<(Entity, &MonitorGroup)>()
+                .iter(world)
+                .filter(|(_, MonitorGroup(label))| *label == group)
+                .map(|(monitor, _)| monitor)
+                .collect::<Vec<_>>();
+
+            monitors.iter().for_each(|&monitor| {
+                world.entity_mut(monitor).insert(Monitor(target));
+            });
+            monitors
+                .into_iter()
+                .for_each(|monitor| catch_up(world, monitor, target));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health;
+
+    #[derive(Resource, Default)]
+    pub struct Refreshed(Vec<(Entity, Entity)>);
+
+    #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    pub enum Hud {
+        Selection,
+        Minimap,
+    }
+
+    #[test]
+    fn retarget_selection() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Refreshed>();
+
+        let previous = world.spawn(Health).id();
+        let selected = world.spawn(Health).id();
+        let unselected = world.spawn(Health).id();
+
+        let hud = [(); 2].map(|_| {
+            world
+                .spawn((
+                    MonitorGroup::new(Hud::Selection),
+                    Monitor(previous),
+                    NotifyChanged::<Health>::default(),
+                ))
+                .observe(
+                    |mutation: On<Mutation<Health>>, mut refreshed: ResMut<Refreshed>| {
+                        refreshed.0.push((mutation.entity, mutation.mutated));
+                    },
+                )
+                .id()
+        });
+        let other = world
+            .spawn((
+                MonitorGroup::new(Hud::Minimap),
+                Monitor(unselected),
+                NotifyChanged::<Health>::default(),
+            ))
+            .id();
+
+        world.run_schedule(Update);
+        world.resource_mut::<Refreshed>().0.clear();
+
+        world.commands().retarget_group(Hud::Selection, selected);
+        world.flush();
+
+        assert!(
+            hud.iter()
+                .all(|&monitor| world.get::<Monitor>(monitor) == Some(&Monitor(selected)))
+        );
+        assert_eq!(world.get::<Monitor>(other), Some(&Monitor(unselected)));
+
+        let mut refreshed = world.resource::<Refreshed>().0.clone();
+        refreshed.sort_unstable();
+
+        let mut expected = hud.map(|monitor| (monitor, selected)).to_vec();
+        expected.sort_unstable();
+
+        assert_eq!(refreshed, expected);
+    }
+}
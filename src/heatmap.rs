@@ -0,0 +1,182 @@
+use crate::{
+    prelude::*,
+    registry::{add_systems, register_once},
+};
+use bevy_app::{App, Last, PostUpdate};
+use bevy_color::Color;
+use bevy_ecs::{entity::EntityHashMap, prelude::*};
+use bevy_gizmos::{config::GizmoConfigStore, gizmos::Gizmos};
+use bevy_time::Time;
+use bevy_transform::{TransformSystems, components::GlobalTransform};
+use std::time::Duration;
+
+#[derive(Resource, Clone, Debug)]
+/// How recently watched entities have generated notifications, inserted by
+/// [`ChangeHeatmap::show_change_heatmap`].
+///
+/// Every notification about an entity adds one to its heat, which then halves every
+/// [`ChangeHeat::half_life`]. Entities with a [`GlobalTransform`] are drawn as spheres colored from
+/// blue to red, reaching red once their heat is [`ChangeHeat::hot`].
+pub struct ChangeHeat {
+    pub half_life: Duration,
+    /// The heat at which an entity is drawn fully red.
+    pub hot: f32,
+    /// The radius of the spheres drawn around entities.
+    pub radius: f32,
+    heat: EntityHashMap<f32>,
+}
+impl Default for ChangeHeat {
+    fn default() -> Self {
+        Self {
+            half_life: Duration::from_secs(1),
+            hot: 30.,
+            radius: 0.5,
+            heat: EntityHashMap::default(),
+        }
+    }
+}
+impl ChangeHeat {
+    /// The current heat of `entity`.
+    pub fn heat(&self, entity: Entity) -> f32 {
+        self.heat.get(&entity).copied().unwrap_or_default()
+    }
+    /// Every entity with any heat, and its heat.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, f32)> + '_ {
+        self.heat.iter().map(|(&entity, &heat)| (entity, heat))
+    }
+    fn warm(&mut self, entity: Entity) {
+        *self.heat.entry(entity).or_default() += 1.;
+    }
+}
+
+/// Allows drawing how often monitored entities generate notifications, making unexpectedly hot
+/// reactive sources easy to spot.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # let mut app = App::new();
+/// app.show_change_heatmap::<Health>();
+/// ```
+///
+/// # Technical info
+///
+/// This adds a system to the [`PostUpdate`] schedule drawing the heat map, which only runs while
+/// gizmos are available, and a system to the [`Last`] schedule cooling entities down. These are
+/// only added once.
+pub trait ChangeHeatmap {
+    /// Counts every [`Addition<C>`], [`Mutation<C>`], and [`Removal<C>`] towards the heat of the
+    /// entity the component belongs to.
+    ///
+    /// Only the notifications themselves are counted, monitors still need to be set up as usual.
+    fn show_change_heatmap<C: Component>(&mut self) -> &mut Self;
+}
+
+impl ChangeHeatmap for World {
+    fn show_change_heatmap<C: Component>(&mut self) -> &mut Self {
+        register_once::<ChangeHeat>(self, |world| {
+            world.init_resource::<ChangeHeat>();
+            add_systems(
+                world,
+                PostUpdate,
+                draw_change_heat
+                    .after(TransformSystems::Propagate)
+                    .run_if(resource_exists::<GizmoConfigStore>),
+            );
+            add_systems(world, Last, cool_change_heat);
+        });
+        register_once::<(ChangeHeat, C)>(self, |world| {
+            world.add_observer(|addition: On<Addition<C>>, mut heat: ResMut<ChangeHeat>| {
+                heat.warm(addition.added);
+            });
+            world.add_observer(|mutation: On<Mutation<C>>, mut heat: ResMut<ChangeHeat>| {
+                heat.warm(mutation.mutated);
+            });
+            world.add_observer(|removal: On<Removal<C>>, mut heat: ResMut<ChangeHeat>| {
+                heat.warm(removal.removed);
+            });
+        });
+        self
+    }
+}
+
+impl ChangeHeatmap for App {
+    fn show_change_heatmap<C: Component>(&mut self) -> &mut Self {
+        self.world_mut().show_change_heatmap::<C>();
+        self
+    }
+}
+
+fn draw_change_heat(
+    mut gizmos: Gizmos,
+    heat: Res<ChangeHeat>,
+    transforms: Query<&GlobalTransform>,
+) {
+    heat.iter().for_each(|(entity, amount)| {
+        let Ok(transform) = transforms.get(entity) else {
+            return;
+        };
+        let warmth = (amount / heat.hot.max(f32::EPSILON)).clamp(0., 1.);
+
+        gizmos.sphere(
+            transform.translation(),
+            heat.radius,
+            Color::hsl(240. * (1. - warmth), 1., 0.5),
+        );
+    });
+}
+
+fn cool_change_heat(mut heat: ResMut<ChangeHeat>, time: Option<Res<Time>>) {
+    let Some(time) = time else {
+        return;
+    };
+    let half_life = heat.half_life.as_secs_f32().max(f32::EPSILON);
+    let cooling = 0.5f32.powf(time.delta_secs() / half_life);
+
+    heat.heat.retain(|_, amount| {
+        *amount *= cooling;
+        *amount > 0.01
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+    use std::time::Duration;
+
+    #[derive(Component)]
+    pub struct Health;
+
+    #[test]
+    fn warm_and_cool() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.add_schedule(Schedule::new(PostUpdate));
+        world.add_schedule(Schedule::new(Last));
+        world.init_resource::<Time>();
+        world.show_change_heatmap::<Health>();
+
+        let boss = world.spawn(Health).id();
+        let minion = world.spawn(Health).id();
+        world.spawn((Monitor(boss), NotifyChanged::<Health>::default()));
+        world.spawn((Monitor(boss), NotifyChanged::<Health>::default()));
+
+        world.run_schedule(Update);
+        // Nothing is drawn without gizmos.
+        world.run_schedule(PostUpdate);
+
+        let heat = world.resource::<ChangeHeat>();
+        assert_eq!((heat.heat(boss), heat.heat(minion)), (2., 0.));
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        world.run_schedule(Last);
+
+        assert_eq!(world.resource::<ChangeHeat>().heat(boss), 1.);
+    }
+}
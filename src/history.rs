@@ -0,0 +1,212 @@
+use crate::{
+    prelude::*,
+    registry::{add_systems, register_once},
+};
+use bevy_app::{App, First};
+use bevy_ecs::{change_detection::Tick, prelude::*};
+use std::marker::PhantomData;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// A notification retained by [`PreviousFrameNotifications<C>`].
+pub struct RetainedNotification {
+    pub kind: NotificationKind,
+    /// The monitor that received the notification.
+    pub monitor: Entity,
+    /// The entity the component belongs to.
+    pub watched: Entity,
+    /// The change tick at which the notification was triggered.
+    pub tick: Tick,
+    /// The change that caused the notification.
+    pub change: ChangeId,
+}
+
+#[derive(Resource)]
+/// Contains every [`Addition<C>`], [`Mutation<C>`], and [`Removal<C>`] triggered during the
+/// previous frame, inserted by [`RetainPreviousFrame::retain_previous_frame`].
+///
+/// This allows systems that run before notifications are triggered, such as those in [`First`]
+/// or [`PreUpdate`], to react to what happened last frame without reordering the schedule.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// fn replay_damage(previous: Res<PreviousFrameNotifications<Health>>) {
+///     previous
+///         .iter()
+///         .filter(|notification| notification.kind == NotificationKind::Changed)
+///         .for_each(|notification| {
+///             // Spawn a damage number above `notification.watched`.
+///         });
+/// }
+/// ```
+///
+/// [`PreUpdate`]: bevy_app::PreUpdate
+pub struct PreviousFrameNotifications<C: Component> {
+    previous: Vec<RetainedNotification>,
+    current: Vec<RetainedNotification>,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component> Default for PreviousFrameNotifications<C> {
+    fn default() -> Self {
+        Self {
+            previous: Vec::new(),
+            current: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Component> PreviousFrameNotifications<C> {
+    /// The notifications triggered during the previous frame, in the order they were triggered.
+    pub fn iter(&self) -> impl Iterator<Item = &RetainedNotification> {
+        self.previous.iter()
+    }
+    pub fn len(&self) -> usize {
+        self.previous.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.previous.is_empty()
+    }
+    fn retain(
+        &mut self,
+        kind: NotificationKind,
+        monitor: Entity,
+        watched: Entity,
+        tick: Tick,
+        change: ChangeId,
+    ) {
+        self.current.push(RetainedNotification {
+            kind,
+            monitor,
+            watched,
+            tick,
+            change,
+        });
+    }
+}
+
+/// Allows keeping the notifications of the previous frame around for a whole frame.
+pub trait RetainPreviousFrame {
+    /// Retains every [`Addition<C>`], [`Mutation<C>`], and [`Removal<C>`] in
+    /// [`PreviousFrameNotifications<C>`] for one frame.
+    ///
+    /// Only the notifications themselves are retained, monitors still need to be set up as usual.
+    ///
+    /// # Technical info
+    ///
+    /// This adds a system to the [`First`] schedule swapping the buffers, this is only done once per
+    /// component type.
+    fn retain_previous_frame<C: Component>(&mut self) -> &mut Self;
+}
+
+impl RetainPreviousFrame for World {
+    fn retain_previous_frame<C: Component>(&mut self) -> &mut Self {
+        register_once::<PreviousFrameNotifications<C>>(self, |world| {
+            world.init_resource::<PreviousFrameNotifications<C>>();
+            add_systems(world, First, swap_notification_buffers::<C>);
+
+            world.add_observer(
+                |addition: On<Addition<C>>, mut history: ResMut<PreviousFrameNotifications<C>>| {
+                    history.retain(
+                        NotificationKind::Added,
+                        addition.entity,
+                        addition.added,
+                        addition.tick,
+                        addition.change,
+                    );
+                },
+            );
+            world.add_observer(
+                |mutation: On<Mutation<C>>, mut history: ResMut<PreviousFrameNotifications<C>>| {
+                    history.retain(
+                        NotificationKind::Changed,
+                        mutation.entity,
+                        mutation.mutated,
+                        mutation.tick,
+                        mutation.change,
+                    );
+                },
+            );
+            world.add_observer(
+                |removal: On<Removal<C>>, mut history: ResMut<PreviousFrameNotifications<C>>| {
+                    history.retain(
+                        NotificationKind::Removed,
+                        removal.entity,
+                        removal.removed,
+                        removal.tick,
+                        removal.change,
+                    );
+                },
+            );
+        });
+        self
+    }
+}
+
+impl RetainPreviousFrame for App {
+    fn retain_previous_frame<C: Component>(&mut self) -> &mut Self {
+        self.world_mut().retain_previous_frame::<C>();
+        self
+    }
+}
+
+fn swap_notification_buffers<C: Component>(mut history: ResMut<PreviousFrameNotifications<C>>) {
+    let history = &mut *history;
+
+    std::mem::swap(&mut history.previous, &mut history.current);
+    history.current.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health;
+
+    #[test]
+    fn read_last_frame() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(First));
+        world.add_schedule(Schedule::new(Update));
+        world.retain_previous_frame::<Health>();
+
+        let player = world.spawn(Health).id();
+        let monitor = world
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .id();
+
+        world.run_schedule(First);
+        world.run_schedule(Update);
+
+        // Nothing happened before this frame.
+        assert!(
+            world
+                .resource::<PreviousFrameNotifications<Health>>()
+                .is_empty()
+        );
+
+        world.run_schedule(First);
+
+        assert_eq!(
+            world
+                .resource::<PreviousFrameNotifications<Health>>()
+                .iter()
+                .map(|notification| (notification.kind, notification.monitor))
+                .collect::<Vec<_>>(),
+            vec![(NotificationKind::Changed, monitor)]
+        );
+
+        world.run_schedule(Update);
+        world.run_schedule(First);
+
+        assert!(
+            world
+                .resource::<PreviousFrameNotifications<Health>>()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::prelude::*;
+use bevy_ecs::{
+    component::Mutable, lifecycle::HookContext, prelude::*, reflect::AppTypeRegistry,
+    world::DeferredWorld,
+};
+use bevy_log::{Level, debug, error, info, trace, warn};
+use std::{
+    any::{TypeId, type_name},
+    marker::PhantomData,
+};
+
+#[derive(Component)]
+#[component(on_replace = LoggingChanges::<C>::despawn_monitor)]
+/// Points to the monitor logging changes to [`C`] on this entity, despawning it once removed.
+struct LoggingChanges<C: Component> {
+    monitor: Entity,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component> LoggingChanges<C> {
+    fn despawn_monitor(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        // # Safety
+        // This hook only runs for entities that have this component.
+        let monitor = world.get::<Self>(entity).unwrap().monitor;
+
+        world.commands().entity(monitor).try_despawn();
+    }
+}
+
+/// Allows logging every change made to a component of an entity, the monitor everyone ends up
+/// writing while chasing down where a mysterious mutation comes from.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::{log::Level, prelude::*};
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands.entity(player).log_changes::<Health>(Level::WARN);
+/// // Once the culprit has been found.
+/// commands.entity(player).stop_logging::<Health>();
+/// # }
+/// ```
+///
+/// The new value is included in the log if [`C`] is registered in the [`AppTypeRegistry`] with
+/// `#[reflect(Component)]`.
+///
+/// # Technical info
+///
+/// The changes are detected by a separate monitor, so that logging doesn't interfere with any
+/// monitor components the entity already has. The monitor is despawned along with the entity.
+pub trait LogChanges {
+    /// Logs every change made to [`C`] at `level`, replacing any logging already set up for it.
+    fn log_changes<C: Component<Mutability = Mutable>>(&mut self, level: Level) -> &mut Self;
+    /// Stops logging changes made to [`C`].
+    fn stop_logging<C: Component>(&mut self) -> &mut Self;
+}
+
+impl LogChanges for EntityCommands<'_> {
+    fn log_changes<C: Component<Mutability = Mutable>>(&mut self, level: Level) -> &mut Self {
+        let target = self.id();
+        let monitor = self
+            .commands()
+            .spawn((Monitor(target), NotifyChanged::<C>::default()))
+            .observe(move |mutation: On<Mutation<C>>, world: &World| {
+                log_change::<C>(world, level, mutation.mutated);
+            })
+            .id();
+
+        self.insert(LoggingChanges::<C> {
+            monitor,
+            _phantom: PhantomData,
+        })
+    }
+    fn stop_logging<C: Component>(&mut self) -> &mut Self {
+        self.remove::<LoggingChanges<C>>()
+    }
+}
+
+fn log_change<C: Component>(world: &World, level: Level, entity: Entity) {
+    let value = world
+        .get_resource::<AppTypeRegistry>()
+        .and_then(|registry| {
+            let registry = registry.read();
+            let reflect_component = registry.get_type_data::<ReflectComponent>(TypeId::of::<C>())?;
+
+            reflect_component
+                .reflect(world.get_entity(entity).ok()?)
+                .map(|value| format!(" to {value:?}"))
+        });
+    let message = format!(
+        "{} changed on {entity}{}",
+        type_name::<C>(),
+        value.unwrap_or_default()
+    );
+
+    match level {
+        Level::ERROR => error!("{message}"),
+        Level::WARN => warn!("{message}"),
+        Level::INFO => info!("{message}"),
+        Level::DEBUG => debug!("{message}"),
+        _ => trace!("{message}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::{log::Level, prelude::*};
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[test]
+    fn stop_logging() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+
+        let player = world.spawn(Health(100)).id();
+        let monitors = |world: &mut World| {
+            world
+                .query::<&Monitor>()
+                .iter(world)
+                .filter(|&&Monitor(target)| target == player)
+                .count()
+        };
+
+        world
+            .commands()
+            .entity(player)
+            .log_changes::<Health>(Level::INFO);
+        world.flush();
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert_eq!(monitors(&mut world), 1);
+
+        // Logging again replaces the monitor rather than adding another.
+        world
+            .commands()
+            .entity(player)
+            .log_changes::<Health>(Level::WARN);
+        world.flush();
+
+        assert_eq!(monitors(&mut world), 1);
+
+        world.commands().entity(player).stop_logging::<Health>();
+        world.flush();
+
+        assert_eq!(monitors(&mut world), 0);
+    }
+}
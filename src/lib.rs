@@ -3,8 +3,780 @@
     reason = "Bevy often makes use of complex types so it's useful to allow them globally"
 )]
 
+#[cfg(feature = "time")]
+pub mod accumulate;
+pub mod adapt;
 pub mod addition;
+pub mod aggregate;
+#[cfg(feature = "animation")]
+pub mod animation;
+pub mod backend;
+pub mod batch;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod broadcast;
+pub mod bulk;
+pub mod callback;
+#[cfg(feature = "dev-tools")]
+pub mod capture;
+mod catch_up;
+pub mod change_count;
+pub mod change_id;
+pub mod compact;
+pub mod comparator;
+pub mod conditions;
+pub mod data;
+pub mod decimation;
+pub mod defaults;
+pub mod delivery;
+pub mod determinism;
+pub mod diffable;
+mod dispatch;
+pub mod dynamic;
+pub mod erased;
+#[cfg(feature = "expressions")]
+pub mod expression;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod field_diff;
+pub mod fixed;
+pub mod flush;
+pub mod forward;
+pub mod global;
+pub mod group;
+#[cfg(feature = "dev-tools")]
+pub mod heatmap;
+pub mod history;
+#[cfg(feature = "log")]
+pub mod inspect;
+pub mod lifetime;
+pub mod messages;
+pub mod mirror;
 pub mod monitors;
 pub mod mutation;
+pub mod ordering;
+pub mod pause;
+mod payload;
+#[cfg(feature = "persistence")]
+pub mod persistence;
+pub mod pipeline;
 pub mod prelude;
+pub mod previous;
+pub mod priority;
+pub mod projection;
+pub mod provenance;
+#[cfg(feature = "proximity")]
+pub mod proximity;
+pub mod reactions;
+pub mod reattach;
+pub mod reflection;
+mod registry;
+pub mod relation;
 pub mod removal;
+pub mod required;
+pub mod rollback;
+#[cfg(feature = "scripting")]
+pub mod scripting;
+pub mod sequence;
+pub mod settings;
+pub mod settled;
+pub mod slicing;
+pub mod slot;
+pub mod slow;
+pub mod snapshot;
+pub mod sorted_view;
+pub mod stats;
+pub mod suppress;
+pub mod swap;
+pub mod target_budget;
+pub mod timestamp;
+pub mod topology;
+pub mod versioned;
+
+use crate::{
+    prelude::*,
+    registry::{register_pinned, require_explicit, unregister_all, unregister_pinned},
+};
+use bevy_app::{App, AppLabel, FixedPostUpdate, InternedAppLabel, Plugin};
+use bevy_ecs::{
+    component::Component, reflect::AppTypeRegistry, resource::Resource, schedule::ScheduleLabel,
+    world::World,
+};
+use bevy_reflect::TypePath;
+use std::{error::Error, fmt};
+
+/// Registers the systems and observers monitors rely on up front, rather than when the first
+/// monitor needing them is spawned.
+///
+/// Monitors work without this plugin, it is for apps that would rather have every schedule set up
+/// by the time [`App::run`] is called than rely on component hooks doing so lazily.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # #[derive(Component)]
+/// # pub struct Mana(pub u8);
+/// # let mut app = App::new();
+/// app.add_plugins(
+///     NotifyPlugin::default()
+///         .watch::<Health>()
+///         .watch::<Mana>(),
+/// );
+/// ```
+///
+/// This also registers the reflection types of the crate, such as [`Monitor`] and
+/// [`MonitorGroup`].
+///
+/// Registrations made by this plugin are never torn down, even once every monitor using them has
+/// been despawned, until [`NotifyPlugin::cleanup`] is called.
+///
+/// See [`NotifyPlugin::explicit_only`] for disabling registration by component hooks entirely,
+/// or [`NotifyPlugin::explicit_only_for`] for disabling it for a single component.
+#[derive(Default)]
+pub struct NotifyPlugin {
+    components: Vec<Box<dyn Fn(&mut World) + Send + Sync>>,
+    explicit: bool,
+    sub_app: Option<InternedAppLabel>,
+}
+impl NotifyPlugin {
+    /// Inserts [`ExplicitRegistration`], requiring every component to be registered through this
+    /// plugin or [`AppNotifyExt`].
+    pub fn explicit_only(mut self) -> Self {
+        self.explicit = true;
+        self
+    }
+    /// Requires [`C`] to be registered through this plugin or [`AppNotifyExt`], while other
+    /// components are still registered by component hooks, see [`AppNotifyExt::require_explicit`].
+    pub fn explicit_only_for<C: Component>(mut self) -> Self {
+        self.components.push(Box::new(|world| {
+            world.require_explicit::<C>();
+        }));
+        self
+    }
+    /// Registers components in the world of `sub_app`, such as the render world, rather than the
+    /// main world. Reflection types are still registered in the main app.
+    ///
+    /// ```rust
+    /// # use bevy_notify::prelude::*;
+    /// # use bevy::{app::AppLabel, prelude::*};
+    /// # #[derive(AppLabel, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    /// # pub struct RenderApp;
+    /// # #[derive(bevy::ecs::schedule::ScheduleLabel, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    /// # pub struct Render;
+    /// # #[derive(Component)]
+    /// # pub struct Health(pub u8);
+    /// # let mut app = App::new();
+    /// app.add_plugins(
+    ///     NotifyPlugin::default()
+    ///         .in_sub_app(RenderApp)
+    ///         .watch_in::<Health>(Render),
+    /// );
+    /// ```
+    ///
+    /// Sub apps usually don't run [`Update`](bevy_app::Update), so components should be registered
+    /// with [`NotifyPlugin::watch_in`]. See [`ForwardNotifications`] for delivering notifications
+    /// to the main world.
+    ///
+    /// # Panics
+    ///
+    /// Building the plugin panics if the sub app doesn't exist.
+    pub fn in_sub_app(mut self, sub_app: impl AppLabel) -> Self {
+        self.sub_app = Some(sub_app.intern());
+        self
+    }
+    /// Registers everything needed by [`NotifyAdded<C>`], [`NotifyChanged<C>`], and
+    /// [`NotifyRemoved<C>`].
+    pub fn watch<C: Component>(mut self) -> Self {
+        self.components.push(Box::new(register_component::<C>));
+        self
+    }
+    /// Like [`NotifyPlugin::watch`], but changes to [`C`] are detected in `schedule` rather than
+    /// [`Update`](bevy_app::Update).
+    pub fn watch_in<C: Component>(mut self, schedule: impl ScheduleLabel) -> Self {
+        let schedule = schedule.intern();
+
+        self.components.push(Box::new(move |world| {
+            world
+                .add_notify_added::<C>()
+                .add_notify_changed_in::<C>(schedule)
+                .add_notify_removed::<C>();
+        }));
+        self
+    }
+    /// Removes every system, observer, and resource added to `world` to detect and deliver
+    /// notifications, including those registered up front, allowing tests and editor tooling to
+    /// reset worlds.
+    ///
+    /// ```rust
+    /// # use bevy_notify::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # let mut world = World::new();
+    /// world.clear_entities();
+    /// NotifyPlugin::cleanup(&mut world);
+    /// ```
+    ///
+    /// Monitors left in the world stop receiving notifications until another is spawned, see
+    /// [`AppNotifyExt::remove_notifications`] for tearing down a single component.
+    pub fn cleanup(world: &mut World) {
+        unregister_all(world);
+    }
+}
+
+impl Plugin for NotifyPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Monitor>()
+            .register_type::<MonitorSelf>()
+            .register_type::<MonitorMany>()
+            .register_type::<MonitorChildren>()
+            .register_type::<MonitoredBy>()
+            .register_type::<MonitorNamed>()
+            .register_type::<MonitorGroup>()
+            .register_type::<MonitorPaused>()
+            .register_type::<MonitorStats>()
+            .register_type::<DespawnWithTarget>()
+            .register_type::<DespawnTargetWithMonitor>()
+            .register_type::<MirrorOf>()
+            .register_type::<NotificationKind>()
+            .register_type::<EveryN>()
+            .register_type::<FixedGranularity>()
+            .register_type::<FilterProvenance>()
+            .register_type::<Provenance>()
+            .register_type::<NotifyPriority>()
+            .register_type::<NotificationBudget>()
+            .register_type::<TargetBudget>()
+            .register_type::<NotifySettled>()
+            .register_type::<ObserverBudget>()
+            .register_type::<MonitorGraph>()
+            .register_type::<ChangeId>();
+
+        let world = match self.sub_app {
+            Some(sub_app) => app.sub_app_mut(sub_app).world_mut(),
+            None => app.world_mut(),
+        };
+
+        if self.explicit {
+            world.init_resource::<ExplicitRegistration>();
+        }
+
+        self.components.iter().for_each(|register| register(world));
+    }
+}
+
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Default, Debug)]
+/// Disables registering systems and observers when the first [`NotifyAdded<C>`],
+/// [`NotifyChanged<C>`], or [`NotifyRemoved<C>`] is added to the world.
+///
+/// Rather than being hidden away in component hooks every schedule change is then made at app
+/// build time, through [`NotifyPlugin::watch`] or [`AppNotifyExt`].
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # let mut app = App::new();
+/// app.add_plugins(NotifyPlugin::default().explicit_only())
+///     .add_notify_changed::<Health>();
+/// ```
+///
+/// Adding a component that wasn't registered returns an [`UnregisteredNotify`] error, which is
+/// handled by the [`DefaultErrorHandler`](bevy_ecs::error::DefaultErrorHandler).
+pub struct ExplicitRegistration;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// A [`NotifyAdded<C>`], [`NotifyChanged<C>`], or [`NotifyRemoved<C>`] was added without being
+/// registered while [`ExplicitRegistration`] exists, or after [`AppNotifyExt::require_explicit`].
+pub struct UnregisteredNotify {
+    /// The type name of the component that was added.
+    pub component: &'static str,
+}
+impl fmt::Display for UnregisteredNotify {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} was added without being registered, register it through `NotifyPlugin` or \
+            `AppNotifyExt`",
+            self.component
+        )
+    }
+}
+impl Error for UnregisteredNotify {}
+
+fn register_component<C: Component>(world: &mut World) {
+    world
+        .add_notify_added::<C>()
+        .add_notify_changed::<C>()
+        .add_notify_removed::<C>();
+}
+
+/// Allows registering what a single kind of monitor relies on at app build time, rather than
+/// when the first monitor needing it is spawned.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn apply_damage() {}
+/// # let mut app = App::new();
+/// app.add_notify_changed::<Health>()
+///     .add_systems(Update, apply_damage.before(MutationSet));
+/// ```
+///
+/// As the systems exist from the start they can be ordered against, such as making sure damage is
+/// applied before [`MutationSet`] runs.
+///
+/// Changes are detected in [`Update`](bevy_app::Update) by default, use
+/// [`AppNotifyExt::add_notify_changed_in`] to pick another schedule. Such as [`PostUpdate`] for
+/// monitors driving UI, or [`FixedUpdate`] for gameplay monitors.
+///
+/// Calling [`AppNotifyExt::add_notify_changed_in`] for a component that is already registered
+/// detects changes in that schedule as well, such as in both [`Update`](bevy_app::Update) and
+/// [`FixedUpdate`]. Each schedule tracks changes independently, so a change is reported once by
+/// each of them.
+///
+/// [`PostUpdate`]: bevy_app::PostUpdate
+/// [`FixedUpdate`]: bevy_app::FixedUpdate
+///
+/// Registrations made by this trait are only torn down by [`AppNotifyExt::remove_notifications`]
+/// or [`NotifyPlugin::cleanup`], see [`NotifyPlugin`] for registering several components at once.
+pub trait AppNotifyExt {
+    /// Registers everything needed by [`NotifyAdded<C>`].
+    fn add_notify_added<C: Component>(&mut self) -> &mut Self;
+    /// Registers everything needed by [`NotifyChanged<C>`].
+    fn add_notify_changed<C: Component>(&mut self) -> &mut Self;
+    /// Registers everything needed by [`NotifyChanged<C>`], detecting changes in `schedule`.
+    ///
+    /// If [`C`] is already registered changes are detected in `schedule` as well.
+    fn add_notify_changed_in<C: Component>(&mut self, schedule: impl ScheduleLabel) -> &mut Self;
+    /// Registers everything needed by [`NotifyChanged<C>`], detecting changes after every fixed
+    /// step in [`FixedPostUpdate`] rather than once per frame.
+    ///
+    /// Changes made in [`FixedUpdate`] are then reported within the same step, including
+    /// intermediate values that would otherwise be overwritten by the next step. Changes made
+    /// outside of fixed steps are reported after the next one.
+    ///
+    /// [`FixedUpdate`]: bevy_app::FixedUpdate
+    fn add_notify_changed_fixed<C: Component>(&mut self) -> &mut Self {
+        self.add_notify_changed_in::<C>(FixedPostUpdate)
+    }
+    /// Registers everything needed by [`NotifyRemoved<C>`].
+    fn add_notify_removed<C: Component>(&mut self) -> &mut Self;
+    /// Removes everything registered for [`NotifyAdded<C>`], [`NotifyChanged<C>`], and
+    /// [`NotifyRemoved<C>`], including registrations made up front.
+    fn remove_notifications<C: Component>(&mut self) -> &mut Self;
+    /// Prevents adding [`NotifyAdded<C>`], [`NotifyChanged<C>`], or [`NotifyRemoved<C>`] from
+    /// registering anything, as if [`ExplicitRegistration`] existed for [`C`] alone.
+    ///
+    /// This is useful for components whose schedules are audited, where a system appearing mid-run
+    /// because a monitor was spawned isn't acceptable. Adding them without registering them
+    /// through [`AppNotifyExt`] returns an [`UnregisteredNotify`] error.
+    fn require_explicit<C: Component>(&mut self) -> &mut Self;
+    /// Registers [`Mutation<C>`], [`Addition<C>`], and [`Removal<C>`] in the [`AppTypeRegistry`],
+    /// allowing editor tooling to inspect them.
+    fn register_notification_types<C: Component + TypePath>(&mut self) -> &mut Self;
+}
+
+impl AppNotifyExt for World {
+    fn add_notify_added<C: Component>(&mut self) -> &mut Self {
+        register_pinned::<NotifyAdded<C>>(self, NotifyAdded::<C>::register);
+        self
+    }
+    fn add_notify_changed<C: Component>(&mut self) -> &mut Self {
+        register_pinned::<NotifyChanged<C>>(self, NotifyChanged::<C>::register);
+        self
+    }
+    fn add_notify_changed_in<C: Component>(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
+        let schedule = schedule.intern();
+
+        register_pinned::<NotifyChanged<C>>(self, |world| {
+            NotifyChanged::<C>::register_in(world, schedule);
+        });
+        NotifyChanged::<C>::detect_in(self, schedule);
+        self
+    }
+    fn add_notify_removed<C: Component>(&mut self) -> &mut Self {
+        register_pinned::<NotifyRemoved<C>>(self, NotifyRemoved::<C>::register);
+        self
+    }
+    fn remove_notifications<C: Component>(&mut self) -> &mut Self {
+        unregister_pinned::<NotifyAdded<C>>(self, NotifyAdded::<C>::teardown);
+        unregister_pinned::<NotifyChanged<C>>(self, NotifyChanged::<C>::teardown);
+        unregister_pinned::<NotifyRemoved<C>>(self, NotifyRemoved::<C>::teardown);
+        self
+    }
+    fn require_explicit<C: Component>(&mut self) -> &mut Self {
+        require_explicit::<NotifyAdded<C>>(self);
+        require_explicit::<NotifyChanged<C>>(self);
+        require_explicit::<NotifyRemoved<C>>(self);
+        self
+    }
+    fn register_notification_types<C: Component + TypePath>(&mut self) -> &mut Self {
+        let registry = self.get_resource_or_init::<AppTypeRegistry>().clone();
+        let mut registry = registry.write();
+
+        registry.register::<Mutation<C>>();
+        registry.register::<Addition<C>>();
+        registry.register::<Removal<C>>();
+        self
+    }
+}
+
+impl AppNotifyExt for App {
+    fn add_notify_added<C: Component>(&mut self) -> &mut Self {
+        self.world_mut().add_notify_added::<C>();
+        self
+    }
+    fn add_notify_changed<C: Component>(&mut self) -> &mut Self {
+        self.world_mut().add_notify_changed::<C>();
+        self
+    }
+    fn add_notify_changed_in<C: Component>(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
+        self.world_mut().add_notify_changed_in::<C>(schedule);
+        self
+    }
+    fn add_notify_removed<C: Component>(&mut self) -> &mut Self {
+        self.world_mut().add_notify_removed::<C>();
+        self
+    }
+    fn remove_notifications<C: Component>(&mut self) -> &mut Self {
+        self.world_mut().remove_notifications::<C>();
+        self
+    }
+    fn require_explicit<C: Component>(&mut self) -> &mut Self {
+        self.world_mut().require_explicit::<C>();
+        self
+    }
+    fn register_notification_types<C: Component + TypePath>(&mut self) -> &mut Self {
+        self.world_mut().register_notification_types::<C>();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::{
+        ecs::{
+            error::{DefaultErrorHandler, ignore},
+            schedule::ScheduleLabel,
+        },
+        prelude::*,
+    };
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Mutations(usize);
+
+    #[test]
+    fn register_up_front() {
+        let mut app = App::new();
+
+        app.init_resource::<Mutations>()
+            .add_plugins(NotifyPlugin::default().watch::<Health>());
+
+        let update_systems = |app: &App| {
+            app.world()
+                .resource::<Schedules>()
+                .get(Update)
+                .map_or(0, |schedule| schedule.systems_len())
+        };
+
+        assert!(update_systems(&app) > 0);
+
+        let player = app.world_mut().spawn(Health(100)).id();
+        let monitor = app
+            .world_mut()
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .id();
+        app.update();
+        app.world_mut().entity_mut(monitor).despawn();
+        app.update();
+
+        // Despawning the last monitor doesn't tear anything down.
+        assert!(update_systems(&app) > 0);
+
+        app.world_mut()
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(
+                |_: On<Mutation<Health>>, mut mutations: ResMut<Mutations>| {
+                    mutations.0 += 1;
+                },
+            );
+        app.world_mut().get_mut::<Health>(player).unwrap().0 -= 10;
+        app.update();
+
+        assert_eq!(app.world().resource::<Mutations>().0, 1);
+    }
+
+    #[derive(Component)]
+    pub struct Armor;
+
+    #[derive(Resource, Default)]
+    pub struct Order(Vec<&'static str>);
+
+    #[test]
+    fn order_against_registered_systems() {
+        let mut app = App::new();
+
+        app.init_resource::<Order>()
+            .add_notify_changed::<Armor>()
+            .add_systems(
+                Update,
+                (|mut order: ResMut<Order>| order.0.push("before")).before(MutationSet),
+            );
+
+        let player = app.world_mut().spawn(Armor).id();
+        app.world_mut()
+            .spawn((Monitor(player), NotifyChanged::<Armor>::default()))
+            .observe(|_: On<Mutation<Armor>>, mut order: ResMut<Order>| {
+                order.0.push("mutation");
+            });
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Order>().0,
+            vec!["before", "mutation"]
+        );
+    }
+
+    #[derive(Component)]
+    pub struct Stamina(u8);
+
+    #[test]
+    fn detect_in_schedule() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.add_schedule(Schedule::new(PostUpdate));
+        world.init_resource::<Mutations>();
+        world.add_notify_changed_in::<Stamina>(PostUpdate);
+
+        let player = world.spawn(Stamina(100)).id();
+        world
+            .spawn((Monitor(player), NotifyChanged::<Stamina>::default()))
+            .observe(
+                |_: On<Mutation<Stamina>>, mut mutations: ResMut<Mutations>| {
+                    mutations.0 += 1;
+                },
+            );
+        world.get_mut::<Stamina>(player).unwrap().0 -= 10;
+
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Mutations>().0, 0);
+
+        world.run_schedule(PostUpdate);
+        assert_eq!(world.resource::<Mutations>().0, 1);
+    }
+
+    #[derive(Component)]
+    pub struct Focus(u8);
+
+    #[test]
+    fn detect_in_several_schedules() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.add_schedule(Schedule::new(PostUpdate));
+        world.init_resource::<Mutations>();
+        world
+            .add_notify_changed_in::<Focus>(Update)
+            .add_notify_changed_in::<Focus>(PostUpdate);
+
+        let player = world.spawn(Focus(100)).id();
+        world
+            .spawn((Monitor(player), NotifyChanged::<Focus>::default()))
+            .observe(|_: On<Mutation<Focus>>, mut mutations: ResMut<Mutations>| {
+                mutations.0 += 1;
+            });
+        world.get_mut::<Focus>(player).unwrap().0 -= 10;
+
+        world.run_schedule(Update);
+        world.run_schedule(PostUpdate);
+        assert_eq!(world.resource::<Mutations>().0, 2);
+
+        // Each schedule keeps track of what it has already seen.
+        world.run_schedule(Update);
+        world.get_mut::<Focus>(player).unwrap().0 -= 10;
+        world.run_schedule(PostUpdate);
+        assert_eq!(world.resource::<Mutations>().0, 3);
+    }
+
+    #[derive(Component)]
+    pub struct Mana;
+
+    #[test]
+    fn explicit_only() {
+        let mut app = App::new();
+
+        app.init_resource::<Mutations>()
+            .insert_resource(DefaultErrorHandler(ignore))
+            .add_plugins(NotifyPlugin::default().explicit_only().watch::<Health>());
+
+        let player = app.world_mut().spawn((Health(100), Mana)).id();
+        app.world_mut()
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(
+                |_: On<Mutation<Health>>, mut mutations: ResMut<Mutations>| {
+                    mutations.0 += 1;
+                },
+            );
+        app.update();
+
+        assert_eq!(app.world().resource::<Mutations>().0, 1);
+
+        let update_systems = |app: &App| {
+            app.world()
+                .resource::<Schedules>()
+                .get(Update)
+                .map_or(0, |schedule| schedule.systems_len())
+        };
+        let registered = update_systems(&app);
+
+        app.world_mut()
+            .spawn((Monitor(player), NotifyChanged::<Mana>::default()));
+        app.update();
+
+        // `Mana` was never registered, so nothing is added behind the app's back.
+        assert_eq!(update_systems(&app), registered);
+    }
+
+    #[derive(Component)]
+    pub struct Energy(u8);
+
+    #[test]
+    fn explicit_only_for() {
+        let mut app = App::new();
+
+        app.init_resource::<Mutations>()
+            .insert_resource(DefaultErrorHandler(ignore))
+            .add_plugins(NotifyPlugin::default().explicit_only_for::<Energy>());
+
+        let player = app.world_mut().spawn((Health(100), Energy(100))).id();
+        app.world_mut()
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                NotifyChanged::<Energy>::default(),
+            ))
+            .observe(
+                |_: On<Mutation<Health>>, mut mutations: ResMut<Mutations>| {
+                    mutations.0 += 1;
+                },
+            )
+            .observe(
+                |_: On<Mutation<Energy>>, mut mutations: ResMut<Mutations>| {
+                    mutations.0 += 10;
+                },
+            );
+        app.update();
+
+        // `Health` is still registered by the hook, `Energy` is left to the app.
+        assert_eq!(app.world().resource::<Mutations>().0, 1);
+
+        app.add_notify_changed::<Energy>();
+        app.world_mut().get_mut::<Energy>(player).unwrap().0 -= 10;
+        app.update();
+
+        assert_eq!(app.world().resource::<Mutations>().0, 11);
+    }
+
+    #[derive(Component, Reflect, Clone, Debug)]
+    pub struct Gold(u32);
+
+    #[derive(Resource, Default)]
+    pub struct Logged(Vec<Mutation<Gold>>);
+
+    #[test]
+    fn reflect_notifications() {
+        let mut app = App::new();
+
+        app.init_resource::<Logged>()
+            .register_notification_types::<Gold>();
+
+        let player = app.world_mut().spawn(Gold(10)).id();
+        app.world_mut()
+            .spawn((Monitor(player), NotifyWithValue::<Gold>::default()))
+            .observe(|mutation: On<Mutation<Gold>>, mut logged: ResMut<Logged>| {
+                logged.0.push(mutation.clone());
+            });
+        app.update();
+
+        let logged = &app.world().resource::<Logged>().0;
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+
+        assert!(registry.contains(std::any::TypeId::of::<Addition<Gold>>()));
+        assert!(format!("{:?}", logged[0]).contains("Gold(10)"));
+        assert_eq!(
+            logged[0]
+                .reflect_ref()
+                .as_struct()
+                .unwrap()
+                .field("mutated")
+                .and_then(|mutated| mutated.try_downcast_ref::<Entity>()),
+            Some(&player)
+        );
+    }
+
+    #[derive(Component)]
+    pub struct Shield;
+
+    #[test]
+    fn cleanup_world() {
+        let mut app = App::new();
+
+        app.init_resource::<Mutations>()
+            .add_plugins(NotifyPlugin::default().watch::<Shield>().watch::<Health>())
+            .add_notify_changed_in::<Stamina>(PostUpdate);
+
+        let systems = |app: &App| {
+            let schedules = app.world().resource::<Schedules>();
+
+            [Update.intern(), PostUpdate.intern()].map(|label| {
+                schedules
+                    .get(label)
+                    .map_or(0, |schedule| schedule.systems_len())
+            })
+        };
+        let observers = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(), With<Observer>>()
+                .iter(app.world())
+                .count()
+        };
+
+        app.update();
+
+        let [update, post_update] = systems(&app);
+        let total_observers = observers(&mut app);
+
+        app.remove_notifications::<Shield>();
+        app.update();
+
+        assert_eq!(systems(&app), [update - 1, post_update]);
+        assert_eq!(observers(&mut app), total_observers - 6);
+
+        NotifyPlugin::cleanup(app.world_mut());
+        app.update();
+
+        assert_eq!(systems(&app), [update - 2, post_update - 1]);
+        assert_eq!(observers(&mut app), total_observers - 13);
+
+        // Spawning a monitor registers everything again.
+        let player = app.world_mut().spawn(Health(100)).id();
+        app.world_mut()
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(
+                |_: On<Mutation<Health>>, mut mutations: ResMut<Mutations>| {
+                    mutations.0 += 1;
+                },
+            );
+        app.update();
+
+        assert_eq!(app.world().resource::<Mutations>().0, 1);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::prelude::*;
+use bevy_ecs::{lifecycle::HookContext, prelude::*, world::DeferredWorld};
+use bevy_reflect::Reflect;
+
+#[derive(Component, Reflect, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+/// Despawns a monitor when the entity specified by its [`Monitor`] is despawned.
+///
+/// Without this the monitor only loses its [`Monitor`], causing it to react to all entities.
+/// Despawning the monitor also cleans up anything attached to it, such as its observers and its
+/// [`MonitorGroup`].
+///
+/// Explicitly removing [`Monitor`] while the target still exists will not despawn the monitor.
+///
+/// # Teardown order
+///
+/// Despawning an entity that is both a monitor and watched by other monitors happens in a fixed
+/// order:
+///
+/// 1. The entity and its own observers are removed, any notifications still addressed to it are
+///    dropped rather than delivered.
+/// 2. Monitors watching it receive the notifications caused by its removal, such as
+///    [`Removal<C>`].
+/// 3. Monitors watching it are despawned if they have [`DespawnWithTarget`], otherwise they lose
+///    their [`Monitor`].
+///
+/// So observers never run against a monitor that has already been despawned.
+///
+/// [`Removal<C>`]: crate::prelude::Removal
+pub struct DespawnWithTarget;
+
+/// Despawns the [`DespawnWithTarget`] monitors of an entity once it has been despawned.
+///
+/// This runs when [`MonitoredBy`] is removed rather than when the entity starts despawning, so
+/// the despawns are queued after the notifications caused by the rest of its components being
+/// removed.
+pub(crate) fn despawn_with_target(
+    mut world: DeferredWorld,
+    HookContext { entity, .. }: HookContext,
+) {
+    let Some(monitors) = world.get::<MonitoredBy>(entity) else {
+        return;
+    };
+    let coupled = monitors
+        .iter()
+        .filter(|&monitor| world.get::<DespawnWithTarget>(monitor).is_some())
+        .collect::<Vec<_>>();
+
+    if coupled.is_empty() {
+        return;
+    }
+
+    world.commands().queue(move |world: &mut World| {
+        // The target only lost its monitors rather than being despawned.
+        if world.get_entity(entity).is_ok() {
+            return;
+        }
+
+        coupled.into_iter().for_each(|monitor| {
+            if let Ok(monitor) = world.get_entity_mut(monitor) {
+                monitor.despawn();
+            }
+        });
+    });
+}
+
+#[derive(Component, Reflect, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+#[component(on_despawn = DespawnTargetWithMonitor::despawn_target)]
+/// Despawns the entity specified by a monitor's [`Monitor`] when the monitor is despawned.
+///
+/// This is useful for proxy entities that only exist to be watched by a single monitor.
+pub struct DespawnTargetWithMonitor;
+impl DespawnTargetWithMonitor {
+    fn despawn_target(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let Some(&Monitor(target)) = world.get::<Monitor>(entity) else {
+            return;
+        };
+
+        world.commands().entity(target).try_despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[test]
+    fn couple_lifetimes() {
+        let mut world = World::new();
+
+        let player = world.spawn_empty().id();
+        let health_bar = world.spawn((Monitor(player), DespawnWithTarget)).id();
+
+        let detached = world.spawn_empty().id();
+        let stays = world.spawn((Monitor(detached), DespawnWithTarget)).id();
+
+        world.entity_mut(stays).remove::<Monitor>();
+        world.despawn(player);
+
+        assert!(world.get_entity(health_bar).is_err());
+        assert!(world.get_entity(stays).is_ok());
+
+        // The reverse.
+
+        let proxy = world.spawn_empty().id();
+        let inspector = world.spawn((Monitor(proxy), DespawnTargetWithMonitor)).id();
+
+        world.despawn(inspector);
+
+        assert!(world.get_entity(proxy).is_err());
+    }
+
+    #[derive(Component)]
+    pub struct Health;
+
+    #[derive(Resource, Default)]
+    pub struct Notified(Vec<&'static str>);
+
+    #[test]
+    fn ordered_teardown() {
+        let mut world = World::new();
+
+        world.init_resource::<Notified>();
+
+        let player = world.spawn(Health).id();
+        let health_bar = world
+            .spawn((
+                Health,
+                Monitor(player),
+                MonitorSelf,
+                NotifyRemoved::<Health>::default(),
+            ))
+            .observe(|_: On<Removal<Health>>, mut notified: ResMut<Notified>| {
+                notified.0.push("health bar");
+            })
+            .id();
+        let tooltip = world
+            .spawn((
+                Monitor(health_bar),
+                NotifyRemoved::<Health>::default(),
+                DespawnWithTarget,
+            ))
+            .observe(
+                |removal: On<Removal<Health>>,
+                 mut commands: Commands,
+                 mut notified: ResMut<Notified>| {
+                    // Panics if the tooltip has already been despawned.
+                    commands.entity(removal.entity).insert(Name::new("Stale"));
+                    notified.0.push("tooltip");
+                },
+            )
+            .id();
+
+        world.flush();
+        world.despawn(health_bar);
+
+        assert_eq!(world.resource::<Notified>().0, vec!["tooltip"]);
+        assert!(world.get_entity(tooltip).is_err());
+    }
+}
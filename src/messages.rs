@@ -0,0 +1,100 @@
+use crate::{prelude::*, registry::register_once};
+use bevy_app::App;
+use bevy_ecs::{message::Messages, prelude::*};
+
+/// Allows reading notifications as [`Message`]s, for systems that prefer iterating over them once
+/// per frame rather than reacting to each one in an observer.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component, Clone)]
+/// # pub struct Health(pub u8);
+/// fn redraw_health_bars(mut mutations: MessageReader<Mutation<Health>>) {
+///     for mutation in mutations.read() {
+///         println!("{} changed health", mutation.mutated);
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_notification_messages::<Health>()
+///     .add_systems(PostUpdate, redraw_health_bars);
+/// ```
+///
+/// Every [`Addition<C>`], [`Mutation<C>`], and [`Removal<C>`] delivered to a monitor is also
+/// written as a message, so monitors still need to be set up as usual. Those dropped by filters
+/// such as [`MonitorPaused`] aren't written.
+///
+/// # Technical info
+///
+/// This adds the three notifications as messages, and spawns an [`Observer`] for each that writes
+/// a clone of it, this is only done once per component type.
+pub trait NotificationMessages {
+    /// Writes every notification about [`C`] as a message.
+    fn add_notification_messages<C: Component + Clone>(&mut self) -> &mut Self;
+}
+
+impl NotificationMessages for App {
+    fn add_notification_messages<C: Component + Clone>(&mut self) -> &mut Self {
+        self.add_message::<Addition<C>>()
+            .add_message::<Mutation<C>>()
+            .add_message::<Removal<C>>();
+
+        register_once::<Messages<Mutation<C>>>(self.world_mut(), |world| {
+            world.add_observer(
+                |addition: On<Addition<C>>, mut messages: MessageWriter<Addition<C>>| {
+                    messages.write(addition.event().clone());
+                },
+            );
+            world.add_observer(
+                |mutation: On<Mutation<C>>, mut messages: MessageWriter<Mutation<C>>| {
+                    messages.write(mutation.event().clone());
+                },
+            );
+            world.add_observer(
+                |removal: On<Removal<C>>, mut messages: MessageWriter<Removal<C>>| {
+                    messages.write(removal.event().clone());
+                },
+            );
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Clone)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Redraws(Vec<Entity>);
+
+    #[test]
+    fn read_mutations_as_messages() {
+        let mut app = App::new();
+
+        app.init_resource::<Redraws>()
+            .add_notification_messages::<Health>()
+            .add_systems(
+                PostUpdate,
+                |mut mutations: MessageReader<Mutation<Health>>, mut redraws: ResMut<Redraws>| {
+                    redraws
+                        .0
+                        .extend(mutations.read().map(|mutation| mutation.mutated));
+                },
+            );
+
+        let player = app.world_mut().spawn(Health(100)).id();
+        app.world_mut()
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()));
+        app.update();
+
+        app.world_mut().get_mut::<Health>(player).unwrap().0 -= 10;
+        app.update();
+
+        assert_eq!(app.world().resource::<Redraws>().0, vec![player, player]);
+    }
+}
@@ -0,0 +1,512 @@
+use crate::{prelude::*, registry::register_once};
+use bevy_ecs::{
+    entity::{EntityHashMap, EntityHashSet},
+    prelude::*,
+};
+use bevy_reflect::{PartialReflect, Reflect, TypePath};
+use std::{
+    collections::{HashSet, VecDeque},
+    marker::PhantomData,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+#[derive(Reflect, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[repr(u8)]
+/// Which kind of notification was mirrored.
+pub enum NotificationKind {
+    /// Mirrors [`Addition<C>`].
+    Added,
+    /// Mirrors [`Mutation<C>`].
+    Changed,
+    /// Mirrors [`Removal<C>`].
+    Removed,
+}
+
+/// A notification copied out of the world it was triggered in.
+struct MirroredNotification {
+    kind: NotificationKind,
+    monitor: Entity,
+    watched: Entity,
+    component: &'static str,
+    value: Option<Box<dyn PartialReflect>>,
+    /// Whether the watched entity was despawned, only set for [`NotificationKind::Removed`].
+    despawned: bool,
+}
+
+/// An entry in the queue of a [`NotificationMirror`].
+enum MirrorEntry {
+    /// A notification to trigger [`Mirrored`] for.
+    Notification(MirroredNotification),
+    /// A monitor that no longer has any mirrored notification components.
+    MonitorReleased(Entity),
+}
+
+#[derive(Resource, Clone, Default)]
+/// A handle to a queue of notifications shared between a world being mirrored and a tooling world,
+/// such as an in-process editor or a headless analyzer.
+///
+/// Insert a clone of this into the world to mirror, select which component types to mirror with
+/// [`MirrorNotifications::mirror_notifications`], and then call [`NotificationMirror::apply`] on
+/// the tooling world to trigger [`Mirrored`] for every notification that has happened since.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component, Reflect)]
+/// # pub struct Health(pub u8);
+/// # let mut game = World::new();
+/// # let mut editor = World::new();
+/// let mirror = NotificationMirror::default();
+///
+/// game.insert_resource(mirror.clone());
+/// game.mirror_notifications::<Health>();
+///
+/// editor.add_observer(|mirrored: On<Mirrored>| {
+///     println!("{} {:?} on {}", mirrored.component, mirrored.kind, mirrored.watched);
+/// });
+///
+/// // Every frame.
+/// mirror.apply(&mut editor);
+/// ```
+pub struct NotificationMirror(Arc<Mutex<VecDeque<MirrorEntry>>>);
+impl NotificationMirror {
+    /// Triggers [`Mirrored`] in `world` for every notification queued since the last call.
+    ///
+    /// Entities from the mirrored world are represented by proxy entities in `world`, see
+    /// [`MirrorOf`]. The proxy of a watched entity is despawned once it has been despawned, or no
+    /// longer has any of the mirrored components. The proxy of a monitor is despawned once it no
+    /// longer has any [`NotifyAdded<C>`], [`NotifyChanged<C>`], or [`NotifyRemoved<C>`] of the
+    /// mirrored components.
+    pub fn apply(&self, world: &mut World) {
+        let entries = std::mem::take(&mut *self.0.lock().unwrap_or_else(PoisonError::into_inner));
+
+        world.init_resource::<MirroredEntities>();
+
+        // Proxies are only released once every notification has been triggered, so observers can
+        // still look up the proxy of a removal, and one removal reaching several monitors doesn't
+        // spawn a new proxy for each.
+        let mut released = EntityHashSet::default();
+
+        entries.into_iter().for_each(|entry| {
+            let notification = match entry {
+                MirrorEntry::Notification(notification) => notification,
+                MirrorEntry::MonitorReleased(source) => {
+                    world
+                        .resource_mut::<MirroredEntities>()
+                        .monitors
+                        .remove(&source);
+                    released.insert(source);
+                    return;
+                }
+            };
+            let monitor = proxy_for(world, notification.monitor);
+            let watched = proxy_for(world, notification.watched);
+
+            let mut entities = world.resource_mut::<MirroredEntities>();
+            entities.monitors.insert(notification.monitor);
+            match notification.kind {
+                NotificationKind::Removed if notification.despawned => {
+                    entities.components.remove(&notification.watched);
+                    released.insert(notification.watched);
+                }
+                NotificationKind::Removed => {
+                    if let Some(components) = entities.components.get_mut(&notification.watched) {
+                        components.remove(notification.component);
+                    }
+                    released.insert(notification.watched);
+                }
+                NotificationKind::Added | NotificationKind::Changed => {
+                    entities
+                        .components
+                        .entry(notification.watched)
+                        .or_default()
+                        .insert(notification.component);
+                }
+            }
+
+            world.trigger(Mirrored {
+                entity: monitor,
+                watched,
+                kind: notification.kind,
+                component: notification.component,
+                value: notification.value,
+            });
+        });
+
+        released
+            .into_iter()
+            .for_each(|source| release_proxy(world, source));
+    }
+    fn push(&self, entry: MirrorEntry) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push_back(entry);
+    }
+}
+
+#[derive(Component, Reflect, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+/// Placed on proxy entities in a tooling world, containing the entity in the mirrored world it
+/// stands in for.
+pub struct MirrorOf(pub Entity);
+
+#[derive(Resource, Default)]
+/// Maps entities in the mirrored world to their proxies.
+struct MirroredEntities {
+    proxies: EntityHashMap<Entity>,
+    /// The type paths of the mirrored components each entity in the mirrored world has.
+    components: EntityHashMap<HashSet<&'static str>>,
+    /// The monitors in the mirrored world that haven't been released.
+    monitors: EntityHashSet,
+}
+
+fn proxy_for(world: &mut World, source: Entity) -> Entity {
+    if let Some(&proxy) = world.resource::<MirroredEntities>().proxies.get(&source) {
+        return proxy;
+    }
+
+    let proxy = world.spawn(MirrorOf(source)).id();
+    world
+        .resource_mut::<MirroredEntities>()
+        .proxies
+        .insert(source, proxy);
+    proxy
+}
+
+/// Despawns the proxy of `source` if it no longer has any mirrored components and isn't a monitor.
+fn release_proxy(world: &mut World, source: Entity) {
+    let mut entities = world.resource_mut::<MirroredEntities>();
+
+    if entities.monitors.contains(&source)
+        || entities
+            .components
+            .get(&source)
+            .is_some_and(|components| !components.is_empty())
+    {
+        return;
+    }
+
+    entities.components.remove(&source);
+    if let Some(proxy) = entities.proxies.remove(&source) {
+        // The proxy may have already been despawned by the tooling world.
+        world.try_despawn(proxy).ok();
+    }
+}
+
+#[derive(EntityEvent)]
+/// A notification mirrored from another world by [`NotificationMirror::apply`].
+///
+/// Targets the proxy of the monitor that received the original notification.
+pub struct Mirrored {
+    pub entity: Entity,
+    /// The proxy of the entity the component belongs to.
+    pub watched: Entity,
+    pub kind: NotificationKind,
+    /// The type path of the component.
+    pub component: &'static str,
+    /// A reflected copy of the component as it was when the notification was mirrored.
+    ///
+    /// This is `None` for [`NotificationKind::Removed`], or if the component was removed before
+    /// the notification was mirrored.
+    pub value: Option<Box<dyn PartialReflect>>,
+}
+
+/// Allows selecting which notifications are copied into a [`NotificationMirror`].
+pub trait MirrorNotifications {
+    /// Mirrors every [`Addition<C>`], [`Mutation<C>`], and [`Removal<C>`] triggered in this world.
+    ///
+    /// Only the notifications themselves are mirrored, monitors still need to be set up as usual.
+    /// Mirroring the same component again does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the world doesn't contain a [`NotificationMirror`].
+    fn mirror_notifications<C: Component + Reflect + TypePath>(&mut self) -> &mut Self;
+}
+
+impl MirrorNotifications for World {
+    fn mirror_notifications<C: Component + Reflect + TypePath>(&mut self) -> &mut Self {
+        assert!(
+            self.contains_resource::<NotificationMirror>(),
+            "A `NotificationMirror` must be inserted before mirroring notifications"
+        );
+
+        register_once::<MirroredComponent<C>>(self, |world| {
+            world.add_observer(
+                |addition: On<Addition<C>>, mirror: Res<NotificationMirror>, values: Query<&C>| {
+                    mirror.push(MirrorEntry::Notification(MirroredNotification {
+                        kind: NotificationKind::Added,
+                        monitor: addition.entity,
+                        watched: addition.added,
+                        component: C::type_path(),
+                        value: values
+                            .get(addition.added)
+                            .ok()
+                            .map(|value| value.to_dynamic()),
+                        despawned: false,
+                    }));
+                },
+            );
+            world.add_observer(
+                |mutation: On<Mutation<C>>, mirror: Res<NotificationMirror>, values: Query<&C>| {
+                    mirror.push(MirrorEntry::Notification(MirroredNotification {
+                        kind: NotificationKind::Changed,
+                        monitor: mutation.entity,
+                        watched: mutation.mutated,
+                        component: C::type_path(),
+                        value: values
+                            .get(mutation.mutated)
+                            .ok()
+                            .map(|value| value.to_dynamic()),
+                        despawned: false,
+                    }));
+                },
+            );
+            world.add_observer(|removal: On<Removal<C>>, mirror: Res<NotificationMirror>| {
+                mirror.push(MirrorEntry::Notification(MirroredNotification {
+                    kind: NotificationKind::Removed,
+                    monitor: removal.entity,
+                    watched: removal.removed,
+                    component: C::type_path(),
+                    value: None,
+                    despawned: removal.cause == RemovalCause::Despawned,
+                }));
+            });
+            track_monitors::<NotifyAdded<C>>(world);
+            track_monitors::<NotifyChanged<C>>(world);
+            track_monitors::<NotifyRemoved<C>>(world);
+        });
+        self
+    }
+}
+
+/// Marks [`C`] as being mirrored, so its observers are only added once.
+struct MirroredComponent<C>(PhantomData<C>);
+
+#[derive(Resource, Default)]
+/// The number of mirrored notification components each monitor in the mirrored world has.
+struct MirroredMonitors(EntityHashMap<usize>);
+
+/// Counts the monitors with [`N`], both existing ones and those added later, releasing their
+/// proxies once they have no mirrored notification components left.
+fn track_monitors<N: Component>(world: &mut World) {
+    let existing = world
+        .query_filtered::<Entity, With<N>>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    let mut monitors = world.get_resource_or_init::<MirroredMonitors>();
+
+    existing
+        .into_iter()
+        .for_each(|monitor| *monitors.0.entry(monitor).or_default() += 1);
+
+    world.add_observer(|add: On<Add, N>, mut monitors: ResMut<MirroredMonitors>| {
+        *monitors.0.entry(add.entity).or_default() += 1;
+    });
+    world.add_observer(
+        |remove: On<Remove, N>,
+         mut monitors: ResMut<MirroredMonitors>,
+         mirror: Res<NotificationMirror>| {
+            let Some(count) = monitors.0.get_mut(&remove.entity) else {
+                return;
+            };
+
+            *count -= 1;
+            if *count == 0 {
+                monitors.0.remove(&remove.entity);
+                mirror.push(MirrorEntry::MonitorReleased(remove.entity));
+            }
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::{prelude::*, reflect::FromReflect};
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Seen(Vec<(Entity, NotificationKind, Option<Health>)>);
+
+    #[test]
+    fn mirror_into_tool_world() {
+        let mut game = World::new();
+        let mut tool = World::new();
+
+        let mirror = NotificationMirror::default();
+
+        game.insert_resource(mirror.clone());
+        game.mirror_notifications::<Health>();
+
+        let player = game.spawn_empty().id();
+        game.spawn((
+            Monitor(player),
+            NotifyAdded::<Health>::default(),
+            NotifyRemoved::<Health>::default(),
+        ));
+
+        tool.init_resource::<Seen>();
+        tool.add_observer(
+            |mirrored: On<Mirrored>, proxies: Query<&MirrorOf>, mut seen: ResMut<Seen>| {
+                let MirrorOf(watched) = proxies.get(mirrored.watched).unwrap();
+                let value = mirrored.value.as_deref().and_then(Health::from_reflect);
+                seen.0.push((*watched, mirrored.kind, value));
+            },
+        );
+
+        game.entity_mut(player).insert(Health(100));
+        game.entity_mut(player).remove::<Health>();
+
+        mirror.apply(&mut tool);
+
+        assert_eq!(
+            tool.resource::<Seen>().0,
+            vec![
+                (player, NotificationKind::Added, Some(Health(100))),
+                (player, NotificationKind::Removed, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn release_proxies() {
+        let mut game = World::new();
+        let mut tool = World::new();
+
+        let mirror = NotificationMirror::default();
+
+        game.insert_resource(mirror.clone());
+        game.mirror_notifications::<Health>();
+
+        let player = game.spawn_empty().id();
+        let monitor = game
+            .spawn((
+                Monitor(player),
+                NotifyAdded::<Health>::default(),
+                NotifyRemoved::<Health>::default(),
+            ))
+            .id();
+        game.spawn((
+            Monitor(player),
+            NotifyAdded::<Health>::default(),
+            NotifyRemoved::<Health>::default(),
+        ));
+
+        let proxies = |tool: &mut World| {
+            let mut proxies = tool
+                .query::<&MirrorOf>()
+                .iter(tool)
+                .map(|&MirrorOf(source)| source)
+                .collect::<Vec<_>>();
+            proxies.sort_unstable();
+            proxies
+        };
+
+        game.entity_mut(player).insert(Health(100));
+        mirror.apply(&mut tool);
+
+        assert_eq!(proxies(&mut tool).len(), 3);
+
+        // Removed from the player, both monitors are told but only one proxy is spawned.
+        game.entity_mut(player).remove::<Health>();
+        mirror.apply(&mut tool);
+
+        assert!(!proxies(&mut tool).contains(&player));
+
+        game.entity_mut(player).insert(Health(100));
+        mirror.apply(&mut tool);
+
+        assert!(proxies(&mut tool).contains(&player));
+
+        game.entity_mut(player).despawn();
+        mirror.apply(&mut tool);
+
+        assert!(!proxies(&mut tool).contains(&player));
+        assert!(proxies(&mut tool).contains(&monitor));
+        assert_eq!(proxies(&mut tool).len(), 2);
+    }
+
+    #[test]
+    fn mirror_once() {
+        let mut game = World::new();
+        let mut tool = World::new();
+
+        let mirror = NotificationMirror::default();
+
+        game.insert_resource(mirror.clone());
+        game.mirror_notifications::<Health>();
+        game.mirror_notifications::<Health>();
+
+        let player = game.spawn_empty().id();
+        game.spawn((Monitor(player), NotifyAdded::<Health>::default()));
+
+        tool.init_resource::<Seen>();
+        tool.add_observer(|mirrored: On<Mirrored>, mut seen: ResMut<Seen>| {
+            seen.0.push((mirrored.watched, mirrored.kind, None));
+        });
+
+        game.entity_mut(player).insert(Health(100));
+        mirror.apply(&mut tool);
+
+        assert_eq!(tool.resource::<Seen>().0.len(), 1);
+    }
+
+    #[test]
+    fn release_monitor_proxies() {
+        let mut game = World::new();
+        let mut tool = World::new();
+
+        let mirror = NotificationMirror::default();
+
+        game.insert_resource(mirror.clone());
+
+        let player = game.spawn(Health(100)).id();
+        // Monitors from before mirroring began are released too.
+        let hud = game
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .id();
+
+        game.mirror_notifications::<Health>();
+
+        let minimap = game
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                NotifyRemoved::<Health>::default(),
+            ))
+            .id();
+        game.flush();
+
+        let proxies = |tool: &mut World| {
+            let mut proxies = tool
+                .query::<&MirrorOf>()
+                .iter(tool)
+                .map(|&MirrorOf(source)| source)
+                .collect::<Vec<_>>();
+            proxies.sort_unstable();
+            proxies
+        };
+
+        game.get_mut::<Health>(player).unwrap().0 -= 10;
+        run_notify_systems(&mut game);
+        mirror.apply(&mut tool);
+
+        assert_eq!(proxies(&mut tool).len(), 3);
+
+        game.entity_mut(hud).despawn();
+        // Still watching removals.
+        game.entity_mut(minimap).remove::<NotifyChanged<Health>>();
+        mirror.apply(&mut tool);
+
+        assert!(!proxies(&mut tool).contains(&hud));
+        assert!(proxies(&mut tool).contains(&minimap));
+
+        game.entity_mut(minimap).remove::<NotifyRemoved<Health>>();
+        mirror.apply(&mut tool);
+
+        assert_eq!(proxies(&mut tool), vec![player]);
+    }
+}
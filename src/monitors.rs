@@ -1,12 +1,18 @@
-use bevy_ecs::prelude::*;
+use crate::{lifetime::despawn_with_target, prelude::*};
+use bevy_ecs::{
+    prelude::*,
+    query::QueryData,
+    system::{SystemParam, SystemState},
+};
 use bevy_reflect::Reflect;
 
 #[derive(Component, Reflect, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
 #[relationship_target(relationship = Monitor)]
+#[component(on_remove = despawn_with_target)]
 /// Contains all the monitors that are watching this entity.
 pub struct MonitoredBy(Vec<Entity>);
 
-#[derive(Component, Reflect, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Component, Reflect, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 #[relationship(relationship_target = MonitoredBy)]
 /// Component used to specify the entity that a monitor reacts to.
 ///
@@ -21,6 +27,8 @@ pub struct MonitoredBy(Vec<Entity>);
 ///
 /// See [`NotifyChanged`], [`NotifyAdded`], and [`NotifyRemoved`], for how to define reactions.
 ///
+/// Monitors can be cloned, the clone will watch the same entity as the original.
+///
 /// ```rust
 /// # use bevy_notify::prelude::*;
 /// # use bevy::{prelude::*, ui_widgets::observe};
@@ -44,7 +52,7 @@ pub struct MonitoredBy(Vec<Entity>);
 ///         |mutation: On<Mutation<Health>>,
 ///         mut health: Query<&mut Health>|
 ///         -> Result<(), BevyError> {
-///             let mut health = health.get_mut(mutation.mutated)?;
+///             let mut health = mutation.component_mut(&mut health)?;
 ///
 ///             if health.0 <= 20 {
 ///                 health.0 += 20;
@@ -58,7 +66,7 @@ pub struct MonitoredBy(Vec<Entity>);
 /// ```
 pub struct Monitor(pub Entity);
 
-#[derive(Component, Reflect, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+#[derive(Component, Reflect, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
 /// Component used to specify that an entity will react to itself.
 ///
 /// If you want to react to changes on other entities see [`Monitor`].
@@ -73,6 +81,144 @@ pub struct Monitor(pub Entity);
 ///
 pub struct MonitorSelf;
 
+#[derive(Component, Reflect, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+/// Component used to specify several entities that a monitor reacts to.
+///
+/// Unlike [`Monitor`] this isn't a [`Relationship`], the targets aren't tracked and despawning one
+/// of them won't despawn the monitor. It can be combined with [`Monitor`] and [`MonitorSelf`], in
+/// which case the monitor reacts to all of them.
+///
+/// Like [`Monitor`], a monitor with [`MonitorMany`] won't react to all entities, even if the list is
+/// empty.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, party: Vec<Entity>) {
+/// commands
+///     .spawn((MonitorMany(party), NotifyChanged::<Health>::default()))
+///     .observe(|mutation: On<Mutation<Health>>| {
+///         println!("{} in the party changed health", mutation.mutated);
+///     });
+/// # }
+/// ```
+pub struct MonitorMany(pub Vec<Entity>);
+
+#[derive(Component, Reflect, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+/// Component used to specify an entity whose direct [`Children`] a monitor reacts to.
+///
+/// The children are looked up whenever a notification is dispatched, so children added to or
+/// removed from the entity later on are picked up without touching the monitor. The entity itself
+/// isn't watched, combine this with [`Monitor`] for that.
+///
+/// Like [`Monitor`], a monitor with [`MonitorChildren`] won't react to all entities, even if the
+/// entity has no children.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Item;
+/// # fn showcase(mut commands: Commands, inventory: Entity) {
+/// commands
+///     .spawn((MonitorChildren(inventory), NotifyAdded::<Item>::default()))
+///     .observe(|addition: On<Addition<Item>>| {
+///         println!("{} was put in the inventory", addition.added);
+///     });
+/// # }
+/// ```
+pub struct MonitorChildren(pub Entity);
+
+#[derive(SystemParam)]
+/// Looks up the entities watched through [`MonitorChildren`].
+pub(crate) struct Hierarchy<'w, 's> {
+    children: Query<'w, 's, &'static Children>,
+    parents: Query<'w, 's, &'static ChildOf>,
+}
+
+#[derive(QueryData)]
+/// The components deciding which entities a monitor watches.
+pub(crate) struct MonitorTargets {
+    pub(crate) entity: Entity,
+    monitor: Option<&'static Monitor>,
+    monitor_self: Has<MonitorSelf>,
+    many: Option<&'static MonitorMany>,
+    children: Option<&'static MonitorChildren>,
+    named: Has<MonitorNamed>,
+}
+impl MonitorTargetsItem<'_, '_> {
+    /// Whether the monitor watches all entities.
+    pub(crate) fn is_global(&self) -> bool {
+        self.monitor.is_none()
+            && !self.monitor_self
+            && self.many.is_none()
+            && self.children.is_none()
+            && !self.named
+    }
+    /// Whether the monitor watches `target`.
+    ///
+    /// Children are matched by their [`ChildOf`], which is still there while a child is being
+    /// despawned, unlike its entry in the [`Children`] of its parent.
+    pub(crate) fn watches(&self, target: Entity, hierarchy: &Hierarchy) -> bool {
+        self.is_global()
+            || self
+                .monitor
+                .is_some_and(|&Monitor(monitored)| monitored == target)
+            || self.monitor_self && self.entity == target
+            || self
+                .many
+                .is_some_and(|MonitorMany(targets)| targets.contains(&target))
+            || self.children.is_some_and(|&MonitorChildren(parent)| {
+                hierarchy
+                    .parents
+                    .get(target)
+                    .is_ok_and(|child_of| child_of.parent() == parent)
+            })
+    }
+    /// The entities the monitor watches, or `None` if it watches all of them.
+    pub(crate) fn resolve(&self, hierarchy: &Hierarchy) -> Option<Vec<Entity>> {
+        if self.is_global() {
+            return None;
+        }
+
+        let mut targets = self
+            .monitor
+            .map(|&Monitor(target)| target)
+            .into_iter()
+            .chain(self.monitor_self.then_some(self.entity))
+            .chain(
+                self.many
+                    .into_iter()
+                    .flat_map(|MonitorMany(targets)| targets.iter().copied()),
+            )
+            .chain(
+                self.children
+                    .and_then(|&MonitorChildren(parent)| hierarchy.children.get(parent).ok())
+                    .into_iter()
+                    .flat_map(|children| children.iter()),
+            )
+            .collect::<Vec<_>>();
+
+        targets.sort_unstable();
+        targets.dedup();
+        Some(targets)
+    }
+}
+
+/// Resolves the entities watched by `monitor`, or `None` if it watches all of them.
+///
+/// A despawned `monitor` watches nothing.
+pub(crate) fn resolve_targets(world: &mut World, monitor: Entity) -> Option<Vec<Entity>> {
+    let mut state = SystemState::<(Query<MonitorTargets>, Hierarchy)>::new(world);
+    let (monitors, hierarchy) = state.get(world);
+
+    monitors
+        .get(monitor)
+        .map_or(Some(Vec::new()), |targets| targets.resolve(&hierarchy))
+}
+
 #[cfg(test)]
 mod test {
     /// TODO: Test all types of reactivity.
@@ -173,6 +319,72 @@ mod test {
         assert_eq!(world.resource::<TesterAdded>().0, 2);
     }
 
+    #[test]
+    fn test_many_monitor() {
+        let mut world = World::new();
+
+        world.init_resource::<TesterAdded>();
+
+        let unrelated = world.spawn_empty().id();
+
+        let party = [world.spawn_empty().id(), world.spawn_empty().id()];
+
+        world
+            .spawn((
+                MonitorMany(party.to_vec()),
+                NotifyAdded::<Tester>::default(),
+            ))
+            .observe(
+                |_: On<Addition<Tester>>, mut tester_added: ResMut<TesterAdded>| {
+                    tester_added.0 += 1;
+                },
+            );
+
+        world.entity_mut(unrelated).insert(Tester);
+
+        assert_eq!(world.resource::<TesterAdded>().0, 0);
+
+        world.entity_mut(party[0]).insert(Tester);
+        world.entity_mut(party[1]).insert(Tester);
+
+        assert_eq!(world.resource::<TesterAdded>().0, 2);
+    }
+
+    #[test]
+    fn test_children_monitor() {
+        let mut world = World::new();
+
+        world.init_resource::<TesterAdded>();
+
+        let inventory = world.spawn_empty().id();
+
+        let sword = world.spawn(ChildOf(inventory)).id();
+
+        world
+            .spawn((MonitorChildren(inventory), NotifyAdded::<Tester>::default()))
+            .observe(
+                |_: On<Addition<Tester>>, mut tester_added: ResMut<TesterAdded>| {
+                    tester_added.0 += 1;
+                },
+            );
+
+        world.entity_mut(inventory).insert(Tester);
+
+        assert_eq!(world.resource::<TesterAdded>().0, 0);
+
+        world.entity_mut(sword).insert(Tester);
+
+        assert_eq!(world.resource::<TesterAdded>().0, 1);
+
+        // Children added later on are watched too, those taken out no longer are.
+        let shield = world.spawn(ChildOf(inventory)).id();
+        world.entity_mut(sword).remove::<(Tester, ChildOf)>();
+        world.entity_mut(shield).insert(Tester);
+        world.entity_mut(sword).insert(Tester);
+
+        assert_eq!(world.resource::<TesterAdded>().0, 2);
+    }
+
     #[test]
     fn test_global_monitor() {
         let mut world = World::new();
@@ -204,4 +416,42 @@ mod test {
 
         assert_eq!(world.resource::<TesterAdded>().0, 3);
     }
+
+    #[test]
+    fn test_cloned_monitor() {
+        let mut world = World::new();
+
+        world.init_resource::<TesterAdded>();
+
+        let empty = world.spawn_empty().id();
+
+        let monitor = world
+            .spawn((Monitor(empty), NotifyAdded::<Tester>::default()))
+            .observe(
+                |_: On<Addition<Tester>>, mut tester_added: ResMut<TesterAdded>| {
+                    tester_added.0 += 1;
+                },
+            )
+            .id();
+
+        let clone = world
+            .entity_mut(monitor)
+            .clone_and_spawn_with_opt_out(|builder| {
+                builder.add_observers(true);
+            });
+
+        assert_eq!(world.get::<Monitor>(clone), Some(&Monitor(empty)));
+        assert!(world.entity(clone).contains::<NotifyAdded<Tester>>());
+
+        world.entity_mut(empty).insert(Tester);
+
+        assert_eq!(world.resource::<TesterAdded>().0, 2);
+
+        // The clone keeps the reactivity alive once the original is gone.
+
+        world.entity_mut(monitor).despawn();
+        world.entity_mut(empty).remove::<Tester>().insert(Tester);
+
+        assert_eq!(world.resource::<TesterAdded>().0, 3);
+    }
 }
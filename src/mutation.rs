@@ -1,65 +1,298 @@
-use crate::prelude::*;
-use bevy_app::Update;
+use crate::{
+    catch_up::register_catch_up,
+    change_count::ChangeCounts,
+    change_id::ChangeIds,
+    defaults::{ConfiguredDefaults, apply_defaults},
+    dispatch::{Notifier, NotifyGate, component_ticks},
+    erased::spawn_forwarder,
+    fixed::{FixedSteps, count_fixed_steps},
+    flush::DetectionTicks,
+    monitors::{Hierarchy, MonitorTargets},
+    ordering::add_ordered_delivery,
+    payload::NotificationPayload,
+    prelude::*,
+    provenance::AuthoritativeTicks,
+    reflection::ReflectedPatches,
+    registry::{add_systems, register_implicitly, register_teardown, unregister},
+    settings::detection_schedule,
+    topology::register_watched,
+};
+use bevy_app::FixedPostUpdate;
 use bevy_ecs::{
-    lifecycle::HookContext, prelude::*, schedule::ScheduleCleanupPolicy, world::DeferredWorld,
+    change_detection::{ComponentTicks, Tick},
+    component::Mutable,
+    entity::EntityHashMap,
+    lifecycle::HookContext,
+    prelude::*,
+    query::QueryFilter,
+    schedule::{InternedScheduleLabel, ScheduleCleanupPolicy, ScheduleLabel},
+    system::{SystemChangeTick, SystemParam},
+    world::DeferredWorld,
 };
 use bevy_reflect::Reflect;
-use std::marker::PhantomData;
+use std::{collections::HashSet, marker::PhantomData, panic::Location};
 
 #[derive(SystemSet, Hash, PartialEq, Eq, Clone, Debug, Default)]
 /// The set that triggers reactivity for [`Mutation`]
 pub struct MutationSet;
 
-#[derive(Resource, Reflect, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
-/// Used to indicate that the component [`C`] is being watched by a system to prevent systems from
-/// being added multiple times.
-struct DetectingChanges<C>(PhantomData<C>);
-impl<C> Default for DetectingChanges<C> {
-    fn default() -> Self {
-        Self(PhantomData)
-    }
+#[derive(SystemSet, Hash, PartialEq, Eq, Clone, Copy, Debug)]
+/// The sets the systems of this crate are placed in, allowing systems to be ordered against them.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # fn apply_damage() {}
+/// # fn update_scoreboard() {}
+/// # let mut app = App::new();
+/// app.add_systems(Update, apply_damage.before(NotifySet::Detect))
+///     .add_systems(Update, update_scoreboard.after(NotifySet::Dispatch));
+/// ```
+///
+/// Notifications are delivered through commands, so systems ordered after either set will see
+/// the effects of observers reacting to them.
+pub enum NotifySet {
+    /// Detects changes to watched components, this contains [`MutationSet`].
+    Detect,
+    /// Derives notifications from detected changes, such as [`Accumulated<C>`] and
+    /// [`CompactedChanges`]. This always runs after [`NotifySet::Detect`].
+    ///
+    /// [`Accumulated<C>`]: crate::prelude::Accumulated
+    /// [`CompactedChanges`]: crate::prelude::CompactedChanges
+    Dispatch,
+}
+
+/// Orders [`NotifySet`] in `schedule`.
+pub(crate) fn configure_notify_sets(world: &mut World, schedule: impl ScheduleLabel) {
+    world
+        .get_resource_or_init::<Schedules>()
+        .entry(schedule)
+        .configure_sets((
+            MutationSet.in_set(NotifySet::Detect),
+            NotifySet::Detect.before(NotifySet::Dispatch),
+        ));
+}
+
+#[derive(Resource)]
+/// Contains the schedules changes to [`C`] are detected in.
+struct DetectingChanges<C: Component> {
+    schedules: Vec<InternedScheduleLabel>,
+    /// The observer triggering an [`AnyMutation`] for every [`Mutation<C>`].
+    forwarder: Entity,
+    _phantom: PhantomData<C>,
 }
 
-#[derive(EntityEvent)]
+#[derive(EntityEvent, Message, Reflect, Clone, Debug)]
 /// Indicates that the component [`C`] has been changed on an entity watched by a monitor.
 ///
 /// See [`NotifyChanged<C>`] for more information on how this is triggered.
+///
+/// Opt-in payloads such as [`Mutation::value`] and the [`MonitorData`] aren't visible through
+/// reflection, see [`AppNotifyExt::register_notification_types`] for registering this type.
 pub struct Mutation<C: Component> {
     pub entity: Entity,
     /// The [`Entity`] that [`C`] belongs to.
     pub mutated: Entity,
-    _phantom: PhantomData<C>,
+    /// The change tick at which this was triggered.
+    pub tick: Tick,
+    /// The change this was caused by, shared by every monitor notified about it.
+    pub change: ChangeId,
+    /// The ticks at which [`C`] was added to, and last changed on, the watched entity.
+    ///
+    /// Comparing these with [`Mutation::tick`] allows notifications buffered across frames to be
+    /// deduplicated, ordered, or dropped once stale.
+    pub ticks: ComponentTicks,
+    /// Whether [`C`] was added to the watched entity rather than changed on it.
+    ///
+    /// Adding a component also counts as changing it, so this allows observers to skip or
+    /// special-case the initial value. See [`NotifySettings::added_as_changed`] for not being
+    /// notified about additions at all.
+    pub is_added: bool,
+    /// Everything the monitor opted into, which is boxed to keep this event small.
+    #[reflect(ignore)]
+    pub(crate) payload: Option<Box<NotificationPayload<C>>>,
+    #[reflect(ignore)]
+    pub(crate) _phantom: PhantomData<C>,
+}
+impl<C: Component> Mutation<C> {
+    /// Where the change was made, such as the system that mutated [`C`].
+    ///
+    /// This is only recorded when the `track_location` feature, or Bevy's own, is enabled,
+    /// otherwise it is always `None`.
+    pub fn changed_by(&self) -> Option<&'static Location<'static>> {
+        self.payload.as_ref()?.changed_by
+    }
+    /// Whether the change was predicted or authoritative, see [`AuthoritativeScope`].
+    pub fn provenance(&self) -> Provenance {
+        self.payload
+            .as_ref()
+            .and_then(|payload| payload.provenance)
+            .unwrap_or_default()
+    }
+    /// Whether the change was made through [`ApplyAndNotify::apply_and_notify`].
+    pub fn via_reflection(&self) -> bool {
+        self.payload
+            .as_ref()
+            .is_some_and(|payload| payload.via_reflection)
+    }
+    /// The [`MonitorData`] of the monitor, if it has any.
+    pub fn data(&self) -> Option<&MonitorData> {
+        self.payload.as_ref()?.data.as_ref()
+    }
+    /// The fields of [`C`] that changed, this is `None` unless the monitor has [`DiffFields<C>`].
+    pub fn diff(&self) -> Option<&FieldDiff> {
+        self.payload.as_ref()?.diff.as_ref()
+    }
+    /// A snapshot of [`C`] after this change, this is `None` unless the monitor has
+    /// [`NotifyWithValue<C>`].
+    pub fn value(&self) -> Option<&C> {
+        self.payload.as_ref()?.value.as_ref()
+    }
+    /// A snapshot of [`C`] before this change, this is `None` unless the monitor has
+    /// [`PreviousValue<C>`].
+    pub fn previous(&self) -> Option<&C> {
+        self.payload.as_ref()?.previous.as_ref()
+    }
+    /// The version of [`C`] after this change, if the entity it belongs to has [`Versioned<C>`].
+    pub fn version(&self) -> Option<u64> {
+        self.payload.as_ref()?.version
+    }
+    /// The position of this notification among those about [`C`], this is `None` unless the
+    /// [`SequencedNotifications<C>`] resource exists.
+    pub fn sequence(&self) -> Option<u64> {
+        self.payload.as_ref()?.sequence
+    }
+    /// When this was triggered, this is `None` unless the [`TimestampedNotifications`] resource
+    /// exists.
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.payload.as_ref()?.timestamp
+    }
+    /// How many times [`C`] changed since changes were last detected, this is always one unless
+    /// changes to [`C`] are counted, see [`CountChanges`].
+    pub fn times_changed(&self) -> u32 {
+        self.payload
+            .as_ref()
+            .and_then(|payload| payload.times_changed)
+            .unwrap_or(1)
+    }
+    /// Reads [`C`] from the entity it changed on through `query`.
+    ///
+    /// This fails if [`C`] has been removed since, or the entity despawned.
+    pub fn component<'a, F: QueryFilter>(
+        &self,
+        query: &'a Query<'_, '_, &C, F>,
+    ) -> Result<&'a C, BevyError> {
+        Ok(query.get(self.mutated)?)
+    }
+    /// Mutably accesses [`C`] on the entity it changed on through `query`.
+    ///
+    /// This fails if [`C`] has been removed since, or the entity despawned.
+    pub fn component_mut<'a, F: QueryFilter>(
+        &self,
+        query: &'a mut Query<'_, '_, &mut C, F>,
+    ) -> Result<Mut<'a, C>, BevyError>
+    where
+        C: Component<Mutability = Mutable>,
+    {
+        Ok(query.get_mut(self.mutated)?)
+    }
 }
 
 #[derive(Component, Reflect, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 #[component(
     on_add = NotifyChanged::<C>::register_component_change_system,
-    on_remove = NotifyChanged::<C>::remove_component_change_system
+    on_remove = NotifyChanged::<C>::remove_component_change_system,
+    clone_behavior = clone::<Self>()
 )]
 /// Adding this component to a entity will cause it to react to changes on component [`C`] with
 /// [`Mutation<C>`]
 ///
 /// By default this will react to changes on **all** entities. See [`Monitor`], and [`MonitorSelf`]
 /// for restricting this.
+///
+/// [`C`] must be mutable, using this with an immutable component fails to compile.
 pub struct NotifyChanged<C: Component>(PhantomData<C>);
-impl<C: Component> Default for NotifyChanged<C> {
+impl<C: Component<Mutability = Mutable>> Default for NotifyChanged<C> {
     fn default() -> Self {
         Self(PhantomData)
     }
 }
+impl<C: Component> Clone for NotifyChanged<C> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
 impl<C: Component> NotifyChanged<C> {
-    fn register_component_change_system(mut world: DeferredWorld, _: HookContext) {
-        if world.contains_resource::<DetectingChanges<C>>() {
+    /// Creates the component without requiring [`C`] to be mutable, for monitor components whose
+    /// own constructors already do.
+    pub(crate) fn required() -> Self {
+        Self(PhantomData)
+    }
+    fn register_component_change_system(
+        mut world: DeferredWorld,
+        HookContext { entity, .. }: HookContext,
+    ) {
+        if world.contains_resource::<ConfiguredDefaults<C>>() {
+            world
+                .commands()
+                .queue(move |world: &mut World| apply_defaults::<C>(world, entity));
+        }
+        world
+            .commands()
+            .queue(|world: &mut World| register_implicitly::<Self>(world, Self::register));
+    }
+    /// Registers the system detecting changes to [`C`], this is done by the first
+    /// monitor or by [`NotifyPlugin`].
+    ///
+    /// [`NotifyPlugin`]: crate::NotifyPlugin
+    pub(crate) fn register(world: &mut World) {
+        let schedule = world
+            .get_resource::<ConfiguredDefaults<C>>()
+            .and_then(|defaults| defaults.config.schedule)
+            .unwrap_or_else(|| detection_schedule(world));
+
+        Self::register_in(world, schedule);
+    }
+    /// Registers the system detecting changes to [`C`] in `schedule` rather than [`Update`].
+    pub(crate) fn register_in(world: &mut World, schedule: impl ScheduleLabel) {
+        world.init_resource::<FixedSteps<C>>();
+        world.init_resource::<DetectionTicks<C>>();
+        world.init_resource::<ChangeIds>();
+        let forwarder = spawn_forwarder::<C>(world);
+        world.insert_resource(DetectingChanges::<C> {
+            schedules: Vec::new(),
+            forwarder,
+            _phantom: PhantomData,
+        });
+        Self::detect_in(world, schedule);
+        add_systems(
+            world,
+            FixedPostUpdate,
+            count_fixed_steps::<C>
+                .before(MutationSet)
+                .run_if(any_with_component::<FixedGranularity>),
+        );
+        register_catch_up::<Self>(world, catch_up_changed::<C>);
+        register_teardown::<Self>(world, Self::teardown);
+        register_watched::<Self, C>(world, NotificationKind::Changed);
+    }
+    /// Detects changes to [`C`] in `schedule` as well as any schedule it is already detected in.
+    ///
+    /// Each schedule runs its own system, so changes are tracked independently of the others.
+    pub(crate) fn detect_in(world: &mut World, schedule: impl ScheduleLabel) {
+        let schedule = schedule.intern();
+        // # Safety
+        // Registering `NotifyChanged::<C>` adds the `DetectingChanges` resource.
+        let mut detecting = world.resource_mut::<DetectingChanges<C>>();
+
+        if detecting.schedules.contains(&schedule) {
             return;
         }
+        detecting.schedules.push(schedule);
 
-        world.commands().queue(|world: &mut World| {
-            world.schedule_scope(Update, |_, schedule| {
-                schedule.configure_sets(MutationSet);
-                schedule.add_systems(watch_for_change::<C>.in_set(MutationSet));
-            });
-            world.insert_resource(DetectingChanges::<C>::default());
-        });
+        configure_notify_sets(world, schedule);
+        add_systems(world, schedule, watch_for_change::<C>.in_set(MutationSet));
+        add_ordered_delivery(world, schedule);
     }
     fn remove_component_change_system(mut world: DeferredWorld, _: HookContext) {
         // # Safety
@@ -73,72 +306,388 @@ impl<C: Component> NotifyChanged<C> {
 
         if total_reactive == 0 {
             world.commands().queue(|world: &mut World| {
-                world.schedule_scope(Update, |world, schedule| {
-                    // # Safety
-                    // This hook can only run when `NotifyChanged::<C>` has been removed which
-                    // ensures this sytem must exist in the `Update` schedule.
-                    schedule
-                        .remove_systems_in_set(
-                            watch_for_change::<C>,
-                            world,
-                            ScheduleCleanupPolicy::RemoveSystemsOnly,
-                        )
-                        .unwrap();
-                });
-                world.remove_resource::<DetectingChanges<C>>();
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    /// Removes everything added by registering [`Self`].
+    pub(crate) fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `NotifyChanged::<C>` adds the `DetectingChanges` resource.
+        let DetectingChanges {
+            schedules,
+            forwarder,
+            ..
+        } = world.remove_resource::<DetectingChanges<C>>().unwrap();
+
+        world.entity_mut(forwarder).despawn();
+
+        schedules.into_iter().for_each(|schedule| {
+            world.schedule_scope(schedule, |world, schedule| {
+                // # Safety
+                // `NotifyChanged::<C>` being registered ensures this system exists in every
+                // schedule changes are detected in.
+                schedule
+                    .remove_systems_in_set(
+                        watch_for_change::<C>,
+                        world,
+                        ScheduleCleanupPolicy::RemoveSystemsOnly,
+                    )
+                    .unwrap();
             });
+        });
+        world.schedule_scope(FixedPostUpdate, |world, schedule| {
+            // # Safety
+            // `NotifyChanged::<C>` being registered ensures this system exists in the
+            // `FixedPostUpdate` schedule.
+            schedule
+                .remove_systems_in_set(
+                    count_fixed_steps::<C>,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+        world.remove_resource::<FixedSteps<C>>();
+        world.remove_resource::<DetectionTicks<C>>();
+        // Only exists if changes were flushed.
+        let _ = world.unregister_system_cached(watch_for_change::<C>);
+    }
+}
+
+#[derive(SystemParam)]
+/// Decides whether a monitor is notified about a change, or has the [`Mutation<C>`] held back or
+/// folded into another notification instead.
+pub(crate) struct MutationRouting<'w, 's, C: Component> {
+    gate: NotifyGate<'w, 's, C>,
+    target_budgets: Query<'w, 's, &'static mut TargetBudget>,
+    /// Mutations held back by a [`TargetBudget`], to be retried before any new ones.
+    coalesced: Local<'s, Vec<(Entity, Entity, Option<ChangeId>)>>,
+    comparators: Query<'w, 's, &'static mut NotifyWithComparator<C>>,
+    filters: Query<'w, 's, &'static FilterProvenance>,
+    compact: Query<'w, 's, &'static mut CompactChanges>,
+    batches: Query<'w, 's, &'static mut BatchMutations<C>>,
+}
+impl<C: Component> MutationRouting<'_, '_, C> {
+    /// Whether `monitor` receives a [`Mutation<C>`] for `value` changing on `mutated`, letting
+    /// `notifier` know when it doesn't.
+    fn delivers(
+        &mut self,
+        notifier: &mut Notifier,
+        (monitor, mutated, change): (Entity, Entity, ChangeId),
+        value: &C,
+        provenance: Provenance,
+    ) -> bool {
+        if let Ok(mut budget) = self.target_budgets.get_mut(mutated)
+            && !budget.spend()
+        {
+            notifier.coalesce(monitor);
+            self.coalesced.push((monitor, mutated, Some(change)));
+            return false;
+        }
+        if !self.gate.allows(monitor, mutated) {
+            notifier.drop(monitor);
+            return false;
+        }
+        if let Ok(mut comparator) = self.comparators.get_mut(monitor)
+            && !comparator.differs(mutated, value)
+        {
+            notifier.drop(monitor);
+            return false;
+        }
+        if let Ok(&FilterProvenance(filter)) = self.filters.get(monitor)
+            && filter != provenance
+        {
+            notifier.drop(monitor);
+            return false;
+        }
+        if let Ok(mut compact) = self.compact.get_mut(monitor)
+            && compact.compact::<C>()
+        {
+            notifier.coalesce(monitor);
+            return false;
+        }
+        if let Ok(mut batch) = self.batches.get_mut(monitor) {
+            batch.push(mutated);
+            notifier.coalesce(monitor);
+            return false;
+        }
+
+        true
+    }
+}
+
+#[derive(SystemParam)]
+/// Fills in the [`NotificationPayload`] of a [`Mutation<C>`] with everything the monitor opted
+/// into.
+pub(crate) struct MutationPayloads<'w, 's, C: Component> {
+    diffs: Query<'w, 's, &'static mut DiffFields<C>>,
+    snapshots: Query<'w, 's, &'static NotifyWithValue<C>>,
+    previous: Query<'w, 's, &'static mut PreviousValue<C>>,
+    versions: Query<'w, 's, &'static mut Versioned<C>>,
+    sequences: Option<ResMut<'w, SequencedNotifications<C>>>,
+    authoritative: Option<Res<'w, AuthoritativeTicks>>,
+    patches: Option<Res<'w, ReflectedPatches>>,
+}
+impl<C: Component> MutationPayloads<'_, '_, C> {
+    /// Where the latest change to `value` came from, if changes are being told apart.
+    fn provenance(&self, value: &Ref<C>) -> Option<Provenance> {
+        self.authoritative
+            .as_deref()
+            .map(|authoritative| authoritative.provenance(value.last_changed()))
+    }
+    /// The fields of [`C`] that changed since `monitor` last saw `mutated`, if it diffs them.
+    fn diff(&mut self, monitor: Entity, mutated: Entity, value: &C) -> Option<FieldDiff> {
+        let mut diffs = self.diffs.get_mut(monitor).ok()?;
+
+        Some(diffs.diff(mutated, value))
+    }
+    /// The value of [`C`] `monitor` last saw on `mutated`, if it remembers them.
+    fn previous(&mut self, monitor: Entity, mutated: Entity, value: &C) -> Option<C> {
+        self.previous.get_mut(monitor).ok()?.replace(mutated, value)
+    }
+    /// Counts a notified change to `mutated`, returning its new version if it's versioned.
+    fn bump(&mut self, mutated: Entity) -> Option<u64> {
+        Some(self.versions.get_mut(mutated).ok()?.bump())
+    }
+    /// The current version of `mutated`, if it's versioned.
+    fn version(&self, mutated: Entity) -> Option<u64> {
+        self.versions
+            .get(mutated)
+            .ok()
+            .map(|versioned| versioned.version())
+    }
+    /// The parts of the payload that every notification about `value` changing on `mutated`
+    /// carries. Diffs and previous values are filled in by the caller.
+    fn payload(
+        &mut self,
+        notifier: &Notifier,
+        monitor: Entity,
+        mutated: Entity,
+        value: &Ref<C>,
+    ) -> NotificationPayload<C> {
+        NotificationPayload {
+            value: self
+                .snapshots
+                .get(monitor)
+                .ok()
+                .map(|snapshot| snapshot.snapshot(value)),
+            sequence: self
+                .sequences
+                .as_deref_mut()
+                .map(|sequences| sequences.next().get()),
+            timestamp: notifier.timestamp(),
+            data: notifier.data(monitor),
+            changed_by: value.changed_by().into_option(),
+            provenance: self.provenance(value),
+            via_reflection: self
+                .patches
+                .as_deref()
+                .is_some_and(|patches| patches.contains::<C>(mutated, value.last_changed())),
+            ..Default::default()
         }
     }
 }
 
-fn watch_for_change<C: Component>(
-    mut commands: Commands,
-    changed: Populated<Entity, Changed<C>>,
-    local_monitors: Query<Entity, (With<NotifyChanged<C>>, With<MonitorSelf>)>,
-    monitors: Query<(Entity, &Monitor), With<NotifyChanged<C>>>,
-    global_monitors: Query<
-        Entity,
-        (
-            With<NotifyChanged<C>>,
-            Without<MonitorSelf>,
-            Without<Monitor>,
-        ),
-    >,
+pub(crate) fn watch_for_change<C: Component>(
+    mut notifier: Notifier,
+    mut routing: MutationRouting<C>,
+    mut payloads: MutationPayloads<C>,
+    (suppressed, deterministic, settings): (
+        Option<Res<SuppressedNotifications<C>>>,
+        Option<Res<DeterministicNotifications>>,
+        Option<Res<NotifySettings>>,
+    ),
+    (changed, values, watching, monitors, hierarchy): (
+        Query<Entity, Changed<C>>,
+        Query<Ref<C>>,
+        Query<(), With<NotifyChanged<C>>>,
+        Query<MonitorTargets, With<NotifyChanged<C>>>,
+        Hierarchy,
+    ),
+    (mut fixed_steps, granularity): (ResMut<FixedSteps<C>>, Query<&FixedGranularity>),
+    (mut detection, ticks, mut counting, mut window): (
+        ResMut<DetectionTicks<C>>,
+        SystemChangeTick,
+        Option<ResMut<ChangeCounts<C>>>,
+        Local<Option<usize>>,
+    ),
 ) {
-    local_monitors.iter_many(changed.iter()).for_each(|entity| {
-        commands.trigger(Mutation::<C> {
-            entity,
-            mutated: entity,
-            _phantom: PhantomData,
-        });
-    });
+    let fixed_steps = std::mem::take(&mut fixed_steps.steps);
+    // Changes already reported by a flush, or by a schedule while flushing, are skipped.
+    let reported = detection.advance(ticks.this_run());
+    // Every schedule detecting changes counts them on its own, as each resets its counts.
+    let counting = counting
+        .as_deref_mut()
+        .map(|counting| counting.window(&mut window));
+
+    if suppressed.is_some() || changed.is_empty() && routing.coalesced.is_empty() {
+        return;
+    }
 
-    monitors
+    // Mutations held back by a `TargetBudget` are retried before any new ones.
+    let mut mutations = std::mem::take(&mut *routing.coalesced)
+        .into_iter()
+        .filter(|&(entity, mutated, _)| watching.contains(entity) && values.contains(mutated))
+        .collect::<Vec<_>>();
+    let added_as_changed = settings.is_none_or(|settings| settings.added_as_changed);
+    let retried = mutations
         .iter()
-        .filter(|(_, Monitor(entity))| changed.contains(*entity))
-        .for_each(|(entity, &Monitor(mutated))| {
-            commands.trigger(Mutation::<C> {
-                entity,
-                mutated,
-                _phantom: PhantomData,
-            });
+        .map(|&(entity, mutated, _)| (entity, mutated))
+        .collect::<HashSet<_>>();
+
+    mutations.extend(
+        monitors
+            .iter()
+            .flat_map(|targets| {
+                let entity = targets.entity;
+
+                match targets.resolve(&hierarchy) {
+                    Some(targets) => targets
+                        .into_iter()
+                        .filter(|&target| changed.contains(target))
+                        .collect::<Vec<_>>(),
+                    None => changed.iter().collect(),
+                }
+                .into_iter()
+                .map(move |mutated| (entity, mutated))
+            })
+            .filter(|mutation| !retried.contains(mutation))
+            .filter(|&(_, mutated)| {
+                values.get(mutated).is_ok_and(|value| {
+                    value
+                        .last_changed()
+                        .is_newer_than(reported, ticks.this_run())
+                        && (added_as_changed || !value.is_added())
+                })
+            })
+            .map(|(entity, mutated)| (entity, mutated, None)),
+    );
+
+    if deterministic.is_some() {
+        mutations.sort_unstable();
+    }
+
+    // Every monitor notified about the same change shares its id, and version.
+    let mut changes = EntityHashMap::<ChangeId>::default();
+    let mut bumped = EntityHashMap::<Option<u64>>::default();
+
+    mutations.into_iter().for_each(|(entity, mutated, change)| {
+        let Ok(value) = values.get(mutated) else {
+            return;
+        };
+        let change = change.unwrap_or_else(|| {
+            *changes
+                .entry(mutated)
+                .or_insert_with(|| notifier.next_change())
         });
+        let provenance = payloads.provenance(&value).unwrap_or_default();
+
+        if !routing.delivers(&mut notifier, (entity, mutated, change), &value, provenance) {
+            return;
+        }
 
-    global_monitors.iter().for_each(|global_monitor| {
-        changed.iter().for_each(|mutated| {
-            commands.trigger(Mutation::<C> {
-                entity: global_monitor,
+        let times_changed = counting
+            .as_deref()
+            .map(|counting| counting.times_changed(mutated, value.last_changed(), ticks.this_run()))
+            .filter(|&times_changed| times_changed > 1);
+        let mut diff = payloads.diff(entity, mutated, &value);
+        let mut previous = payloads.previous(entity, mutated, &value);
+        let version = *bumped
+            .entry(mutated)
+            .or_insert_with(|| payloads.bump(mutated));
+
+        let steps = match granularity.get(entity) {
+            Ok(FixedGranularity::PerStep) => fixed_steps.get(&mutated).copied().unwrap_or(1),
+            _ => 1,
+        };
+
+        (1..=steps).for_each(|step| {
+            let payload = NotificationPayload {
+                // Only the first step knows the previous value, only the last one the full diff.
+                previous: if step == 1 { previous.take() } else { None },
+                diff: if step == steps { diff.take() } else { None },
+                times_changed: if step == steps { times_changed } else { None },
+                version,
+                ..payloads.payload(&notifier, entity, mutated, &value)
+            };
+
+            notifier.trigger_in_order(
+                Mutation::<C> {
+                    entity,
+                    mutated,
+                    tick: notifier.tick(),
+                    change,
+                    ticks: component_ticks(&value),
+                    is_added: value.is_added(),
+                    payload: payload.boxed(),
+                    _phantom: PhantomData,
+                },
                 mutated,
-                _phantom: PhantomData,
-            });
+                NotificationKind::Changed,
+            );
         });
     });
+
+    if let Some(counting) = counting {
+        counting.reset(ticks.this_run());
+    }
+}
+
+/// Triggers [`Mutation<C>`] on `monitor` if `target` has [`C`].
+fn catch_up_changed<C: Component>(world: &mut World, monitor: Entity, target: Entity) {
+    // The monitor may have been despawned in the meantime, in which case there is nothing to do.
+    let _ = world.run_system_cached_with(trigger_catch_up_mutation::<C>, (monitor, target));
+}
+
+fn trigger_catch_up_mutation<C: Component>(
+    In((monitor, target)): In<(Entity, Entity)>,
+    mut notifier: Notifier,
+    mut gate: NotifyGate<C>,
+    mut payloads: MutationPayloads<C>,
+    filters: Query<&FilterProvenance>,
+    values: Query<Ref<C>>,
+) {
+    let Ok(value) = values.get(target) else {
+        return;
+    };
+    if !gate.allows(monitor, target) {
+        notifier.drop(monitor);
+        return;
+    }
+    if let Ok(&FilterProvenance(filter)) = filters.get(monitor)
+        && filter != payloads.provenance(&value).unwrap_or_default()
+    {
+        notifier.drop(monitor);
+        return;
+    }
+
+    let change = notifier.next_change();
+    let payload = NotificationPayload {
+        diff: payloads.diff(monitor, target, &value),
+        previous: payloads.previous(monitor, target, &value),
+        version: payloads.version(target),
+        // Catching up isn't a change, let alone one made through reflection.
+        via_reflection: false,
+        ..payloads.payload(&notifier, monitor, target, &value)
+    };
+
+    notifier.trigger(Mutation::<C> {
+        entity: monitor,
+        mutated: target,
+        tick: notifier.tick(),
+        change,
+        ticks: component_ticks(&value),
+        is_added: false,
+        payload: payload.boxed(),
+        _phantom: PhantomData,
+    });
 }
 
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
-    use bevy::prelude::*;
+    use bevy::{ecs::change_detection::Tick, prelude::*};
 
     #[derive(Component)]
     pub struct Player;
@@ -227,4 +776,170 @@ mod tests {
 
         assert_eq!(world.resource::<TimesMoved>().0, 3);
     }
+
+    #[test]
+    fn order_against_notify_sets() {
+        #[derive(Resource, Default)]
+        pub struct Order(Vec<&'static str>);
+
+        let mut app = App::new();
+
+        app.init_resource::<Order>()
+            .add_notify_changed::<Player>()
+            .add_systems(
+                Update,
+                (
+                    (|mut order: ResMut<Order>| order.0.push("before")).before(NotifySet::Detect),
+                    (|mut order: ResMut<Order>| order.0.push("after")).after(NotifySet::Dispatch),
+                ),
+            );
+
+        let player = app.world_mut().spawn(Player).id();
+        app.world_mut()
+            .spawn((Monitor(player), NotifyChanged::<Player>::default()))
+            .observe(|_: On<Mutation<Player>>, mut order: ResMut<Order>| {
+                order.0.push("mutation");
+            });
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Order>().0,
+            vec!["before", "mutation", "after"]
+        );
+    }
+
+    #[test]
+    fn carry_component_ticks() {
+        #[derive(Component)]
+        pub struct Health(u8);
+
+        #[derive(Resource, Default)]
+        pub struct Ticks(Vec<(Tick, Tick, Tick)>);
+
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Ticks>();
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(|mutation: On<Mutation<Health>>, mut ticks: ResMut<Ticks>| {
+                ticks
+                    .0
+                    .push((mutation.ticks.added, mutation.ticks.changed, mutation.tick));
+            });
+        world.run_schedule(Update);
+
+        world.increment_change_tick();
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        let [(added, changed, first), (still_added, last_changed, second)] =
+            world.resource::<Ticks>().0[..]
+        else {
+            panic!("expected two mutations");
+        };
+
+        assert_eq!(added, changed);
+        assert_eq!(added, still_added);
+        assert!(last_changed.is_newer_than(changed, second));
+        assert!(second.is_newer_than(first, second));
+    }
+
+    #[test]
+    #[cfg(feature = "track_location")]
+    fn carry_change_location() {
+        #[derive(Component)]
+        pub struct Health(u8);
+
+        #[derive(Resource, Default)]
+        pub struct Locations(Vec<Option<(&'static str, u32)>>);
+
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Locations>();
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(
+                |mutation: On<Mutation<Health>>, mut locations: ResMut<Locations>| {
+                    locations.0.push(
+                        mutation
+                            .changed_by()
+                            .map(|location| (location.file(), location.line())),
+                    );
+                },
+            );
+        world.run_schedule(Update);
+        world.resource_mut::<Locations>().0.clear();
+
+        let line = line!() + 1;
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Locations>().0, vec![Some((file!(), line))]);
+    }
+
+    #[test]
+    fn flag_added_values() {
+        #[derive(Component)]
+        pub struct Health(u8);
+
+        #[derive(Resource, Default)]
+        pub struct Added(Vec<bool>);
+
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Added>();
+
+        let player = world.spawn_empty().id();
+        world
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(|mutation: On<Mutation<Health>>, mut added: ResMut<Added>| {
+                added.0.push(mutation.is_added);
+            });
+        world.run_schedule(Update);
+
+        world.entity_mut(player).insert(Health(100));
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Added>().0, vec![true, false]);
+    }
+
+    #[test]
+    fn access_mutated_component() {
+        #[derive(Component)]
+        pub struct Health(u8);
+
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(
+                |mutation: On<Mutation<Health>>,
+                 mut health: Query<&mut Health>|
+                 -> Result<(), BevyError> {
+                    if mutation.component(&health.as_readonly())?.0 < 20 {
+                        mutation.component_mut(&mut health)?.0 = 20;
+                    }
+                    Ok(())
+                },
+            );
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(player).unwrap().0 = 5;
+        world.run_schedule(Update);
+
+        assert_eq!(world.get::<Health>(player).unwrap().0, 20);
+    }
 }
@@ -0,0 +1,189 @@
+use crate::{prelude::*, registry::add_systems, settings::detection_schedule};
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+};
+use std::collections::HashSet;
+
+/// Delivers a notification that was held back.
+type Delivery = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+#[derive(Resource, Default)]
+/// Inserting this resource funnels [`Addition<C>`], [`Mutation<C>`], and [`Removal<C>`] through a
+/// single ordered pipeline, so the notifications about an entity within a frame are always
+/// delivered together, in the order they were caught in.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # let mut app = App::new();
+/// app.init_resource::<OrderedNotifications>();
+/// ```
+///
+/// Without this additions and removals are delivered as soon as they happen, while changes are
+/// only detected once [`MutationSet`] runs. So a component that is changed and then removed again
+/// within a frame may be reported as removed before it is reported as changed.
+///
+/// # Guarantees
+///
+/// While this resource exists:
+///
+/// - Additions and removals are held back until after [`MutationSet`] in the schedule changes are
+///   detected in, and delivered together with the changes.
+/// - The notifications about an entity are delivered one after the other, in the order they were
+///   caught in. So the last notification delivered about an entity always matches whether it still
+///   has the component, for example a component removed and inserted again is reported as
+///   removed, then added, then changed.
+///
+/// Catching a monitor up with [`Monitor`] being retargeted, or with a monitor being spawned, is
+/// still delivered immediately.
+pub struct OrderedNotifications {
+    pending: Vec<(Entity, NotificationKind, Delivery)>,
+}
+impl OrderedNotifications {
+    /// Holds back the delivery of a notification about `watched`.
+    pub(crate) fn push(
+        &mut self,
+        watched: Entity,
+        kind: NotificationKind,
+        deliver: impl FnOnce(&mut World) + Send + Sync + 'static,
+    ) {
+        self.pending.push((watched, kind, Box::new(deliver)));
+    }
+}
+
+#[derive(Resource, Default)]
+/// The schedules [`deliver_in_order`] has been added to.
+struct OrderedDelivery {
+    schedules: HashSet<InternedScheduleLabel>,
+}
+
+/// Delivers held back notifications in `schedule`, after changes have been detected.
+///
+/// Like the [`NotifySet`] configuration this is shared by every component, so it's never torn
+/// down. It does nothing unless [`OrderedNotifications`] exists.
+pub(crate) fn add_ordered_delivery(world: &mut World, schedule: impl ScheduleLabel) {
+    let schedule = schedule.intern();
+
+    if !world
+        .get_resource_or_init::<OrderedDelivery>()
+        .schedules
+        .insert(schedule)
+    {
+        return;
+    }
+
+    add_systems(
+        world,
+        schedule,
+        deliver_in_order
+            .in_set(NotifySet::Detect)
+            // Changes are held back in the resource rather than through commands, so there is
+            // no need to wait for them to be applied.
+            .after_ignore_deferred(MutationSet)
+            .run_if(resource_exists::<OrderedNotifications>),
+    );
+}
+
+/// Makes sure held back notifications are delivered, even in worlds that don't detect changes to
+/// any component.
+pub(crate) fn ensure_ordered_delivery(world: &mut World) {
+    let schedule = detection_schedule(world);
+
+    add_ordered_delivery(world, schedule);
+}
+
+fn deliver_in_order(mut commands: Commands, mut ordered: ResMut<OrderedNotifications>) {
+    let mut pending = std::mem::take(&mut ordered.pending);
+
+    // Stable, so the notifications about an entity keep the order they were caught in.
+    pending.sort_by_key(|&(watched, _, _)| watched);
+    commands.queue(move |world: &mut World| {
+        pending
+            .into_iter()
+            .for_each(|(_, _, deliver)| deliver(world));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Burning;
+
+    #[derive(Resource, Default)]
+    pub struct Notified(Vec<NotificationKind>);
+
+    #[test]
+    fn add_change_remove() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Notified>();
+        world.init_resource::<OrderedNotifications>();
+
+        let player = world.spawn_empty().id();
+        world
+            .spawn((
+                Monitor(player),
+                NotifyAdded::<Burning>::default(),
+                NotifyChanged::<Burning>::default(),
+                NotifyRemoved::<Burning>::default(),
+            ))
+            .observe(|_: On<Addition<Burning>>, mut notified: ResMut<Notified>| {
+                notified.0.push(NotificationKind::Added);
+            })
+            .observe(|_: On<Mutation<Burning>>, mut notified: ResMut<Notified>| {
+                notified.0.push(NotificationKind::Changed);
+            })
+            .observe(|_: On<Removal<Burning>>, mut notified: ResMut<Notified>| {
+                notified.0.push(NotificationKind::Removed);
+            });
+        world.flush();
+
+        world.entity_mut(player).insert(Burning);
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<Notified>().0,
+            vec![NotificationKind::Added, NotificationKind::Changed]
+        );
+
+        // Replacing the component within a frame is caught as a removal, then an addition, then a
+        // change.
+        world.entity_mut(player).remove::<Burning>().insert(Burning);
+
+        assert_eq!(world.resource::<Notified>().0.len(), 2);
+
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<Notified>().0[2..],
+            [
+                NotificationKind::Removed,
+                NotificationKind::Added,
+                NotificationKind::Changed,
+            ]
+        );
+        // The last notification matches what the world looks like.
+        assert_eq!(
+            world.resource::<Notified>().0.last() != Some(&NotificationKind::Removed),
+            world.get::<Burning>(player).is_some()
+        );
+
+        // Overwriting the component is caught as its removal, as is removing it afterwards.
+        world.entity_mut(player).insert(Burning).remove::<Burning>();
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<Notified>().0[5..],
+            [NotificationKind::Removed, NotificationKind::Removed]
+        );
+        assert_eq!(
+            world.resource::<Notified>().0.last() != Some(&NotificationKind::Removed),
+            world.get::<Burning>(player).is_some()
+        );
+    }
+}
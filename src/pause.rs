@@ -0,0 +1,136 @@
+use crate::{catch_up::catch_up, monitors::resolve_targets};
+use bevy_ecs::{lifecycle::HookContext, prelude::*, world::DeferredWorld};
+use bevy_reflect::Reflect;
+
+#[derive(Component, Reflect, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+#[component(on_remove = MonitorPaused::resume)]
+/// Pauses a monitor, any notifications it would receive while this component exists are dropped.
+///
+/// When this component is removed the monitor is caught up with the current state of the entities
+/// it watches, receiving [`Mutation<C>`] for each [`NotifyChanged<C>`] and [`Addition<C>`] for
+/// each [`NotifyAdded<C>`] where the entity has [`C`]. Global monitors are not caught up.
+///
+/// [`Mutation<C>`]: crate::prelude::Mutation
+/// [`NotifyChanged<C>`]: crate::prelude::NotifyChanged
+/// [`Addition<C>`]: crate::prelude::Addition
+/// [`NotifyAdded<C>`]: crate::prelude::NotifyAdded
+pub struct MonitorPaused;
+impl MonitorPaused {
+    fn resume(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        world.commands().queue(move |world: &mut World| {
+            // Global monitors aren't caught up.
+            let Some(targets) = resolve_targets(world, entity) else {
+                return;
+            };
+
+            targets
+                .into_iter()
+                .for_each(|target| catch_up(world, entity, target));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Mutations(usize);
+
+    #[test]
+    fn resume_with_catch_up() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Mutations>();
+
+        let player = world.spawn(Health(100)).id();
+        let monitor = world
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                MonitorPaused,
+            ))
+            .observe(
+                |_: On<Mutation<Health>>, mut mutations: ResMut<Mutations>| {
+                    mutations.0 += 1;
+                },
+            )
+            .id();
+
+        world.run_schedule(Update);
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Mutations>().0, 0);
+
+        world.entity_mut(monitor).remove::<MonitorPaused>();
+
+        assert_eq!(world.resource::<Mutations>().0, 1);
+    }
+
+    #[test]
+    fn resume_many_with_catch_up() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Mutations>();
+
+        let party = [world.spawn(Health(100)).id(), world.spawn(Health(80)).id()];
+        let monitor = world
+            .spawn((
+                MonitorMany(party.to_vec()),
+                NotifyChanged::<Health>::default(),
+                MonitorPaused,
+            ))
+            .observe(
+                |_: On<Mutation<Health>>, mut mutations: ResMut<Mutations>| {
+                    mutations.0 += 1;
+                },
+            )
+            .id();
+
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Mutations>().0, 0);
+
+        world.entity_mut(monitor).remove::<MonitorPaused>();
+
+        assert_eq!(world.resource::<Mutations>().0, 2);
+    }
+
+    #[test]
+    fn resume_children_with_catch_up() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Mutations>();
+
+        let party = world.spawn_empty().id();
+        world.spawn((Health(100), ChildOf(party)));
+        world.spawn((Health(80), ChildOf(party)));
+        world.spawn(Health(50));
+
+        let monitor = world
+            .spawn((
+                MonitorChildren(party),
+                NotifyChanged::<Health>::default(),
+                MonitorPaused,
+            ))
+            .observe(
+                |_: On<Mutation<Health>>, mut mutations: ResMut<Mutations>| {
+                    mutations.0 += 1;
+                },
+            )
+            .id();
+
+        world.run_schedule(Update);
+        world.entity_mut(monitor).remove::<MonitorPaused>();
+
+        assert_eq!(world.resource::<Mutations>().0, 2);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::prelude::*;
+use bevy_ecs::prelude::*;
+use std::panic::Location;
+
+/// The parts of a notification that monitors opt into, such as snapshots of [`C`] or a
+/// [`FieldDiff`], along with those only present while a feature is in use, such as the
+/// [`Provenance`] of a change.
+///
+/// Notifications are triggered once per monitor, so their size is paid for on every delivery.
+/// Anything only some monitors receive is stored behind this single [`Box`], so that monitors that
+/// don't opt in never allocate and the size of [`C`] never affects the size of the notification.
+///
+/// [`Mutation<C>`], [`Addition<C>`], and [`Removal<C>`] are kept within 72 bytes, whatever [`C`]
+/// is. New fields that every monitor receives should fit within this budget, new fields that only
+/// some do belong in a payload.
+#[derive(Debug)]
+pub(crate) struct NotificationPayload<C: Component> {
+    pub(crate) diff: Option<FieldDiff>,
+    pub(crate) value: Option<C>,
+    pub(crate) previous: Option<C>,
+    pub(crate) version: Option<u64>,
+    pub(crate) sequence: Option<u64>,
+    pub(crate) timestamp: Option<Timestamp>,
+    /// Only set when more than one change was counted.
+    pub(crate) times_changed: Option<u32>,
+    pub(crate) data: Option<MonitorData>,
+    pub(crate) changed_by: Option<&'static Location<'static>>,
+    pub(crate) provenance: Option<Provenance>,
+    pub(crate) via_reflection: bool,
+}
+impl<C: Component> Default for NotificationPayload<C> {
+    fn default() -> Self {
+        Self {
+            diff: None,
+            value: None,
+            previous: None,
+            version: None,
+            sequence: None,
+            timestamp: None,
+            times_changed: None,
+            data: None,
+            changed_by: None,
+            provenance: None,
+            via_reflection: false,
+        }
+    }
+}
+impl<C: Component + Clone> Clone for NotificationPayload<C> {
+    fn clone(&self) -> Self {
+        Self {
+            // Reflected values can't be cloned directly, so they're copied as dynamic values.
+            diff: self.diff.as_ref().map(|diff| {
+                diff.iter()
+                    .map(|(path, value)| (path.clone(), value.to_dynamic()))
+                    .collect()
+            }),
+            value: self.value.clone(),
+            previous: self.previous.clone(),
+            version: self.version,
+            sequence: self.sequence,
+            timestamp: self.timestamp,
+            times_changed: self.times_changed,
+            data: self.data.clone(),
+            changed_by: self.changed_by,
+            provenance: self.provenance,
+            via_reflection: self.via_reflection,
+        }
+    }
+}
+impl<C: Component> NotificationPayload<C> {
+    /// Boxes the payload, unless there's nothing in it.
+    pub(crate) fn boxed(self) -> Option<Box<Self>> {
+        (self.diff.is_some()
+            || self.value.is_some()
+            || self.previous.is_some()
+            || self.version.is_some()
+            || self.sequence.is_some()
+            || self.timestamp.is_some()
+            || self.times_changed.is_some()
+            || self.data.is_some()
+            || self.changed_by.is_some()
+            || self.provenance.is_some()
+            || self.via_reflection)
+            .then(|| Box::new(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+    use std::mem::{size_of, size_of_val};
+
+    #[derive(Component)]
+    pub struct Small;
+
+    #[derive(Component)]
+    pub struct Large([u8; 1024]);
+
+    /// See [`NotificationPayload`](super::NotificationPayload).
+    const NOTIFICATION_SIZE_BUDGET: usize = 72;
+
+    #[test]
+    fn notifications_within_budget() {
+        assert!(size_of::<Mutation<Small>>() <= NOTIFICATION_SIZE_BUDGET);
+        assert!(size_of::<Addition<Small>>() <= NOTIFICATION_SIZE_BUDGET);
+        assert!(size_of::<Removal<Small>>() <= NOTIFICATION_SIZE_BUDGET);
+
+        // The component alone is over budget, the notifications carrying it aren't.
+        assert!(size_of_val(&Large([0; 1024]).0) > NOTIFICATION_SIZE_BUDGET);
+        assert_eq!(size_of::<Mutation<Large>>(), size_of::<Mutation<Small>>());
+        assert_eq!(size_of::<Addition<Large>>(), size_of::<Addition<Small>>());
+        assert_eq!(size_of::<Removal<Large>>(), size_of::<Removal<Small>>());
+    }
+}
@@ -0,0 +1,461 @@
+use crate::prelude::*;
+use bevy_app::{App, AppExit, Last, Plugin, Startup};
+use bevy_ecs::{prelude::*, reflect::AppTypeRegistry};
+use bevy_log::warn;
+use bevy_reflect::{
+    GetTypeRegistration, Reflect, TypePath,
+    serde::{ReflectDeserializer, ReflectSerializer},
+};
+use ron::value::RawValue;
+use serde::{Deserialize, Serialize, de::DeserializeSeed};
+use std::{
+    any::{TypeId, type_name},
+    collections::HashMap,
+    error::Error,
+    fmt, fs, io,
+    path::PathBuf,
+};
+
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[reflect(Component)]
+/// Marks a monitor as one to be saved by [`PersistMonitors::save_monitors`], every monitor
+/// restored by [`PersistMonitors::restore_monitors`] has this as well.
+///
+/// Observers can't be saved, so restored monitors should be given them again, such as by observing
+/// [`On<Add, Persistent>`] and matching on the monitor's [`Name`].
+pub struct Persistent;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// The reasons monitors can fail to be saved or restored.
+pub enum PersistError {
+    Io(io::ErrorKind),
+    /// The saved monitors couldn't be written or read, such as if the file was edited by hand.
+    Format(String),
+    /// The saved monitors use a type that wasn't registered through [`PersistMonitors`].
+    Unregistered(String),
+}
+impl fmt::Display for PersistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(kind) => write!(f, "couldn't access the saved monitors: {kind}"),
+            Self::Format(error) => write!(f, "the saved monitors are malformed: {error}"),
+            Self::Unregistered(path) => write!(f, "{path} isn't registered for persistence"),
+        }
+    }
+}
+impl Error for PersistError {}
+
+/// How to find the [`NotifyAdded<C>`], [`NotifyChanged<C>`], and [`NotifyRemoved<C>`] of a
+/// monitor, and how to give them back to it.
+struct PersistedWatch {
+    save: fn(EntityRef) -> SavedWatch,
+    restore: fn(&mut EntityWorldMut, &SavedWatch),
+}
+
+#[derive(Resource, Default)]
+/// Contains every type registered through [`PersistMonitors`].
+struct PersistedTypes {
+    /// Keyed by the type name of the watched component.
+    watches: HashMap<&'static str, PersistedWatch>,
+    components: Vec<TypeId>,
+}
+
+#[derive(Serialize, Deserialize)]
+/// Stands in for the target of a monitor, as entities aren't stable across runs.
+enum SavedTarget {
+    Everything,
+    Itself,
+    Named(String),
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct SavedWatch {
+    component: String,
+    added: bool,
+    changed: bool,
+    removed: bool,
+}
+
+#[derive(Serialize)]
+struct SavingMonitor<'a> {
+    target: SavedTarget,
+    watches: Vec<SavedWatch>,
+    components: Vec<ReflectSerializer<'a>>,
+}
+
+#[derive(Deserialize)]
+struct SavedMonitor {
+    target: SavedTarget,
+    watches: Vec<SavedWatch>,
+    components: Vec<Box<RawValue>>,
+}
+
+/// Saves monitors marked with [`Persistent`] and restores them, so that monitors created at
+/// runtime, such as by a player setting up alerts, survive the app restarting.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(world: &mut World, player: Entity) -> Result<(), BevyError> {
+/// world
+///     .persist_watches::<Health>()
+///     .persist_component::<Name>();
+///
+/// world.spawn((
+///     Persistent,
+///     Name::new("Low health alert"),
+///     Monitor(player),
+///     NotifyChanged::<Health>::default(),
+/// ));
+/// let saved = world.save_monitors()?;
+///
+/// // Later, in a fresh world with the same types registered.
+/// world.restore_monitors(&saved)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// A monitor's target is saved by its [`Name`] and restored as [`MonitorNamed`], monitors
+/// targeting an entity without one can't be saved and are skipped. Only the watches and
+/// components registered through [`PersistMonitors::persist_watches`] and
+/// [`PersistMonitors::persist_component`] are saved.
+///
+/// See [`PersistencePlugin`] for saving to a file on exit and restoring from it on startup.
+pub trait PersistMonitors {
+    /// Saves the [`NotifyAdded<C>`], [`NotifyChanged<C>`], and [`NotifyRemoved<C>`] of monitors.
+    ///
+    /// These are saved by the type name of [`C`], which changes if [`C`] is renamed or moved.
+    fn persist_watches<C: Component>(&mut self) -> &mut Self;
+    /// Saves [`T`] through reflection, for monitor configuration such as [`Name`] or
+    /// [`MonitorPaused`].
+    fn persist_component<T: Component + Reflect + TypePath + GetTypeRegistration>(
+        &mut self,
+    ) -> &mut Self;
+    /// Writes every [`Persistent`] monitor to a string.
+    fn save_monitors(&mut self) -> Result<String, PersistError>;
+    /// Spawns the monitors written by [`PersistMonitors::save_monitors`], returning them.
+    fn restore_monitors(&mut self, saved: &str) -> Result<Vec<Entity>, PersistError>;
+}
+
+impl PersistMonitors for World {
+    fn persist_watches<C: Component>(&mut self) -> &mut Self {
+        self.get_resource_or_init::<PersistedTypes>()
+            .watches
+            .insert(
+                type_name::<C>(),
+                PersistedWatch {
+                    save: save_watch::<C>,
+                    restore: restore_watch::<C>,
+                },
+            );
+        self
+    }
+    fn persist_component<T: Component + Reflect + TypePath + GetTypeRegistration>(
+        &mut self,
+    ) -> &mut Self {
+        let registry = self.get_resource_or_init::<AppTypeRegistry>().clone();
+        let mut registry = registry.write();
+
+        registry.register::<T>();
+        registry.register_type_data::<T, ReflectComponent>();
+        self.get_resource_or_init::<PersistedTypes>()
+            .components
+            .push(TypeId::of::<T>());
+        self
+    }
+    fn save_monitors(&mut self) -> Result<String, PersistError> {
+        let registry = self.get_resource_or_init::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        self.init_resource::<PersistedTypes>();
+        self.register_component::<Persistent>();
+
+        let types = self.resource::<PersistedTypes>();
+        let monitors = self
+            .try_query_filtered::<EntityRef, With<Persistent>>()
+            .unwrap()
+            .iter(self)
+            .filter_map(|monitor| {
+                let target = if monitor.contains::<MonitorSelf>() {
+                    SavedTarget::Itself
+                } else if let Some(MonitorNamed(name)) = monitor.get::<MonitorNamed>() {
+                    SavedTarget::Named(name.to_string())
+                } else if let Some(&Monitor(target)) = monitor.get::<Monitor>() {
+                    let Some(name) = self.get::<Name>(target) else {
+                        warn!(
+                            "{} targets {target}, which has no Name, so it won't be saved",
+                            monitor.id()
+                        );
+                        return None;
+                    };
+                    SavedTarget::Named(name.to_string())
+                } else {
+                    SavedTarget::Everything
+                };
+                let watches = types
+                    .watches
+                    .values()
+                    .map(|watch| (watch.save)(monitor))
+                    .filter(|watch| watch.added || watch.changed || watch.removed)
+                    .collect();
+                let components = types
+                    .components
+                    .iter()
+                    .filter_map(|&type_id| {
+                        registry
+                            .get_type_data::<ReflectComponent>(type_id)?
+                            .reflect(monitor)
+                    })
+                    .map(|component| {
+                        ReflectSerializer::new(component.as_partial_reflect(), &registry)
+                    })
+                    .collect();
+
+                Some(SavingMonitor {
+                    target,
+                    watches,
+                    components,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        ron::ser::to_string_pretty(&monitors, Default::default())
+            .map_err(|error| PersistError::Format(error.to_string()))
+    }
+    fn restore_monitors(&mut self, saved: &str) -> Result<Vec<Entity>, PersistError> {
+        let saved = ron::from_str::<Vec<SavedMonitor>>(saved)
+            .map_err(|error| PersistError::Format(error.to_string()))?;
+        let registry = self.get_resource_or_init::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let types = self.remove_resource::<PersistedTypes>().unwrap_or_default();
+
+        // Everything is resolved up front, so that nothing is spawned unless it can all be restored.
+        let restored = saved
+            .into_iter()
+            .map(|monitor| {
+                let watches = monitor
+                    .watches
+                    .into_iter()
+                    .map(|saved| {
+                        let watch = types
+                            .watches
+                            .get(saved.component.as_str())
+                            .ok_or_else(|| PersistError::Unregistered(saved.component.clone()))?;
+
+                        Ok((watch.restore, saved))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let components = monitor
+                    .components
+                    .iter()
+                    .map(|raw| {
+                        let mut deserializer = ron::Deserializer::from_str(raw.get_ron())
+                            .map_err(|error| PersistError::Format(error.to_string()))?;
+                        let component = ReflectDeserializer::new(&registry)
+                            .deserialize(&mut deserializer)
+                            .map_err(|error| PersistError::Format(error.to_string()))?;
+                        let info = component
+                            .get_represented_type_info()
+                            .ok_or_else(|| PersistError::Format(raw.get_ron().to_string()))?;
+                        let reflect_component = registry
+                            .get_type_data::<ReflectComponent>(info.type_id())
+                            .ok_or_else(|| PersistError::Unregistered(info.type_path().into()))?;
+
+                        Ok((reflect_component.clone(), component))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok((monitor.target, watches, components))
+            })
+            .collect::<Result<Vec<_>, PersistError>>();
+
+        self.insert_resource(types);
+
+        Ok(restored?
+            .into_iter()
+            .map(|(target, watches, components)| {
+                let mut monitor = self.spawn(Persistent);
+
+                match target {
+                    SavedTarget::Everything => {}
+                    SavedTarget::Itself => {
+                        monitor.insert(MonitorSelf);
+                    }
+                    SavedTarget::Named(name) => {
+                        monitor.insert(MonitorNamed::new(name));
+                    }
+                }
+                watches
+                    .iter()
+                    .for_each(|(restore, saved)| restore(&mut monitor, saved));
+                components
+                    .iter()
+                    .for_each(|(reflect_component, component)| {
+                        reflect_component.insert(&mut monitor, component.as_ref(), &registry);
+                    });
+                monitor.id()
+            })
+            .collect())
+    }
+}
+
+impl PersistMonitors for App {
+    fn persist_watches<C: Component>(&mut self) -> &mut Self {
+        self.world_mut().persist_watches::<C>();
+        self
+    }
+    fn persist_component<T: Component + Reflect + TypePath + GetTypeRegistration>(
+        &mut self,
+    ) -> &mut Self {
+        self.world_mut().persist_component::<T>();
+        self
+    }
+    fn save_monitors(&mut self) -> Result<String, PersistError> {
+        self.world_mut().save_monitors()
+    }
+    fn restore_monitors(&mut self, saved: &str) -> Result<Vec<Entity>, PersistError> {
+        self.world_mut().restore_monitors(saved)
+    }
+}
+
+fn save_watch<C: Component>(monitor: EntityRef) -> SavedWatch {
+    SavedWatch {
+        component: type_name::<C>().to_string(),
+        added: monitor.contains::<NotifyAdded<C>>(),
+        changed: monitor.contains::<NotifyChanged<C>>(),
+        removed: monitor.contains::<NotifyRemoved<C>>(),
+    }
+}
+
+fn restore_watch<C: Component>(monitor: &mut EntityWorldMut, saved: &SavedWatch) {
+    if saved.added {
+        monitor.insert(NotifyAdded::<C>::default());
+    }
+    if saved.changed {
+        monitor.insert(NotifyChanged::<C>::required());
+    }
+    if saved.removed {
+        monitor.insert(NotifyRemoved::<C>::default());
+    }
+}
+
+/// Restores the monitors saved to a file on startup, and saves them to it again on exit.
+///
+/// ```rust,no_run
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # let mut app = App::new();
+/// app.add_plugins(PersistencePlugin::new("monitors.ron"))
+///     .persist_watches::<Health>()
+///     .persist_component::<Name>();
+/// ```
+///
+/// A missing file is treated as there being no saved monitors, other errors are logged.
+pub struct PersistencePlugin {
+    path: PathBuf,
+}
+impl PersistencePlugin {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Plugin for PersistencePlugin {
+    fn build(&self, app: &mut App) {
+        let restore_from = self.path.clone();
+        let save_to = self.path.clone();
+
+        app.register_type::<Persistent>()
+            .add_systems(Startup, move |world: &mut World| {
+                let restored = match fs::read_to_string(&restore_from) {
+                    Ok(saved) => world.restore_monitors(&saved).map(|_| ()),
+                    Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+                    Err(error) => Err(PersistError::Io(error.kind())),
+                };
+
+                if let Err(error) = restored {
+                    warn!("{error}, no monitors were restored");
+                }
+            })
+            .add_systems(Last, move |world: &mut World| {
+                if world.resource::<Messages<AppExit>>().is_empty() {
+                    return;
+                }
+
+                let saved = world.save_monitors().and_then(|saved| {
+                    fs::write(&save_to, saved).map_err(|error| PersistError::Io(error.kind()))
+                });
+
+                if let Err(error) = saved {
+                    warn!("{error}, no monitors were saved");
+                }
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Alerts(u32);
+
+    fn persisting_world() -> World {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Alerts>();
+        world
+            .persist_watches::<Health>()
+            .persist_component::<Name>()
+            .persist_component::<MonitorPaused>();
+        world
+    }
+
+    #[test]
+    fn restore_saved_monitors() {
+        let mut world = persisting_world();
+        let player = world.spawn((Name::new("Player"), Health(100))).id();
+
+        world.spawn((
+            Persistent,
+            Name::new("Low health alert"),
+            Monitor(player),
+            NotifyChanged::<Health>::default(),
+        ));
+        // Not marked as persistent, so isn't saved.
+        world.spawn((Monitor(player), NotifyAdded::<Health>::default()));
+
+        let saved = world.save_monitors().unwrap();
+
+        let mut world = persisting_world();
+        let player = world.spawn((Name::new("Player"), Health(100))).id();
+        let restored = world.restore_monitors(&saved).unwrap();
+
+        assert_eq!(restored.len(), 1);
+        assert_eq!(
+            world.get::<Name>(restored[0]),
+            Some(&Name::new("Low health alert"))
+        );
+
+        world.entity_mut(restored[0]).observe(
+            |_: On<Mutation<Health>>, mut alerts: ResMut<Alerts>| {
+                alerts.0 += 1;
+            },
+        );
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 90;
+        world.run_schedule(Update);
+
+        assert_eq!(world.get::<Monitor>(restored[0]), Some(&Monitor(player)));
+        assert_eq!(world.resource::<Alerts>().0, 2);
+    }
+}
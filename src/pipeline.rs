@@ -0,0 +1,245 @@
+use bevy_ecs::{entity::EntityHashMap, prelude::*};
+use std::time::Duration;
+
+enum Stage<C> {
+    Predicate(Box<dyn Fn(&C) -> bool + Send + Sync>),
+    Threshold {
+        project: Box<dyn Fn(&C) -> f32 + Send + Sync>,
+        amount: f32,
+        /// The projection last delivered, per watched entity.
+        last: EntityHashMap<f32>,
+    },
+    Debounce {
+        duration: Duration,
+        /// When a notification was last delivered, per watched entity.
+        last: EntityHashMap<Duration>,
+    },
+}
+
+#[derive(Component)]
+/// Adding this component to a monitor will filter the notifications it receives for component
+/// [`C`] through a series of stages, executed in the order they were declared.
+///
+/// A notification is only delivered when it passes every stage.
+///
+/// - [`NotifyPipeline::predicate`] passes when the watched value satisfies a condition.
+/// - [`NotifyPipeline::threshold`] passes when a projection of the watched value has moved at least
+///   a given amount since the last delivered notification.
+/// - [`NotifyPipeline::debounce`] passes when at least a given duration has passed since the last
+///   delivered notification.
+///
+/// Stateful stages only update when the notification is delivered, so a threshold always compares
+/// against the value the monitor last saw. They keep their state per watched entity, so a monitor
+/// watching several entities through [`MonitorMany`] or [`MonitorChildren`] filters each of them
+/// on its own.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::time::Duration;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands.spawn((
+///     Monitor(player),
+///     NotifyChanged::<Health>::default(),
+///     NotifyPipeline::<Health>::default()
+///         .predicate(|health| health.0 < 50)
+///         .threshold(|health| health.0 as f32, 5.)
+///         .debounce(Duration::from_millis(250)),
+/// ));
+/// # }
+/// ```
+///
+/// Notifications that don't make it through the pipeline are counted as dropped by
+/// [`MonitorStats`].
+///
+/// [`MonitorStats`]: crate::prelude::MonitorStats
+/// [`MonitorMany`]: crate::prelude::MonitorMany
+/// [`MonitorChildren`]: crate::prelude::MonitorChildren
+pub struct NotifyPipeline<C: Component> {
+    stages: Vec<Stage<C>>,
+}
+impl<C: Component> Default for NotifyPipeline<C> {
+    fn default() -> Self {
+        Self { stages: Vec::new() }
+    }
+}
+impl<C: Component> NotifyPipeline<C> {
+    /// Only deliver notifications when the watched value satisfies `predicate`.
+    pub fn predicate(mut self, predicate: impl Fn(&C) -> bool + Send + Sync + 'static) -> Self {
+        self.stages.push(Stage::Predicate(Box::new(predicate)));
+        self
+    }
+    /// Only deliver notifications when `project` of the watched value differs by at least `amount`
+    /// from the last delivered notification.
+    pub fn threshold(
+        mut self,
+        project: impl Fn(&C) -> f32 + Send + Sync + 'static,
+        amount: f32,
+    ) -> Self {
+        self.stages.push(Stage::Threshold {
+            project: Box::new(project),
+            amount,
+            last: EntityHashMap::default(),
+        });
+        self
+    }
+    /// Only deliver notifications when at least `duration` has passed since the last delivered
+    /// notification.
+    ///
+    /// This relies on the `Time` resource and the `time` feature, without them this stage always
+    /// passes.
+    pub fn debounce(mut self, duration: Duration) -> Self {
+        self.stages.push(Stage::Debounce {
+            duration,
+            last: EntityHashMap::default(),
+        });
+        self
+    }
+    /// Runs every stage against `value` of `watched`, returning whether the notification should be
+    /// delivered.
+    pub(crate) fn check(
+        &mut self,
+        watched: Entity,
+        value: Option<&C>,
+        now: Option<Duration>,
+    ) -> bool {
+        let passes = self.stages.iter().all(|stage| match stage {
+            Stage::Predicate(predicate) => value.is_some_and(predicate),
+            Stage::Threshold {
+                project,
+                amount,
+                last,
+            } => value.is_some_and(|value| {
+                last.get(&watched)
+                    .is_none_or(|last| (project(value) - last).abs() >= *amount)
+            }),
+            Stage::Debounce { duration, last } => match (now, last.get(&watched)) {
+                (Some(now), Some(last)) => now.saturating_sub(*last) >= *duration,
+                _ => true,
+            },
+        });
+
+        if passes {
+            self.stages.iter_mut().for_each(|stage| match stage {
+                Stage::Predicate(_) => {}
+                Stage::Threshold { project, last, .. } => match value.map(&**project) {
+                    Some(projected) => {
+                        last.insert(watched, projected);
+                    }
+                    None => {
+                        last.remove(&watched);
+                    }
+                },
+                Stage::Debounce { last, .. } => {
+                    if let Some(now) = now {
+                        last.insert(watched, now);
+                    }
+                }
+            });
+        }
+
+        passes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+    #[cfg(feature = "time")]
+    use std::time::Duration;
+
+    #[derive(Component)]
+    pub struct Health(i32);
+
+    #[derive(Resource, Default)]
+    pub struct Seen(Vec<i32>);
+
+    #[test]
+    #[cfg(feature = "time")]
+    fn stacked_filters() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+
+        world.init_resource::<Seen>();
+        world.init_resource::<Time>();
+
+        let player = world.spawn(Health(100)).id();
+
+        world
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                NotifyPipeline::<Health>::default()
+                    .predicate(|health| health.0 < 90)
+                    .threshold(|health| health.0 as f32, 10.)
+                    .debounce(Duration::from_secs(1)),
+            ))
+            .observe(
+                |mutation: On<Mutation<Health>>,
+                 health: Query<&Health>,
+                 mut seen: ResMut<Seen>|
+                 -> Result<(), BevyError> {
+                    seen.0.push(health.get(mutation.mutated)?.0);
+                    Ok(())
+                },
+            );
+
+        world.flush();
+
+        // Fails the predicate, then passes everything, then fails the threshold, then fails the
+        // debounce, then passes everything.
+        for (health, wait) in [(95, 2), (85, 2), (80, 0), (70, 0), (60, 2)] {
+            world.get_mut::<Health>(player).unwrap().0 = health;
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(wait));
+            world.run_schedule(Update);
+        }
+
+        assert_eq!(world.resource::<Seen>().0, vec![85, 60]);
+    }
+
+    #[test]
+    fn threshold_per_watched_entity() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+
+        world.init_resource::<Seen>();
+
+        let player = world.spawn(Health(100)).id();
+        let enemy = world.spawn(Health(100)).id();
+
+        world
+            .spawn((
+                MonitorMany(vec![player, enemy]),
+                NotifyChanged::<Health>::default(),
+                NotifyPipeline::<Health>::default().threshold(|health| health.0 as f32, 10.),
+            ))
+            .observe(
+                |mutation: On<Mutation<Health>>,
+                 health: Query<&Health>,
+                 mut seen: ResMut<Seen>|
+                 -> Result<(), BevyError> {
+                    seen.0.push(health.get(mutation.mutated)?.0);
+                    Ok(())
+                },
+            );
+
+        world.run_schedule(Update);
+        world.resource_mut::<Seen>().0.clear();
+
+        // Moving the player doesn't count towards the threshold of the enemy, and the other way
+        // around.
+        for (entity, health) in [(player, 85), (enemy, 95), (player, 80), (enemy, 85)] {
+            world.get_mut::<Health>(entity).unwrap().0 = health;
+            world.run_schedule(Update);
+        }
+
+        assert_eq!(world.resource::<Seen>().0, vec![85, 85]);
+    }
+}
@@ -1,6 +1,92 @@
 pub use crate::{
+    AppNotifyExt, ExplicitRegistration, NotifyPlugin, UnregisteredNotify,
+    adapt::AdaptedObserver,
     addition::{Addition, NotifyAdded},
-    monitors::{Monitor, MonitorSelf, MonitoredBy},
-    mutation::{Mutation, NotifyChanged},
-    removal::{NotifyRemoved, Removal},
+    aggregate::{Aggregate, Summary},
+    backend::{CustomDispatch, NotificationDispatcher},
+    batch::{BatchMutations, MutationBatch},
+    broadcast::SubscribeTo,
+    bulk::{MonitorAll, MonitorSet},
+    callback::{CallbackNotification, NotifyCallback, NotifyCallbackSystem},
+    change_count::CountChanges,
+    change_id::ChangeId,
+    compact::{ChangedTypes, CompactChanges, CompactedChanges},
+    comparator::NotifyWithComparator,
+    conditions::{on_addition, on_mutation, on_removal},
+    data::MonitorData,
+    decimation::EveryN,
+    defaults::{NotifyConfig, NotifyDefaults},
+    delivery::DeliverIn,
+    determinism::DeterministicNotifications,
+    diffable::Diffable,
+    dynamic::{DynamicNotification, DynamicWatchError, WatchDynamic},
+    erased::AnyMutation,
+    field_diff::{DiffFields, FieldDiff},
+    fixed::FixedGranularity,
+    flush::FlushNotifications,
+    forward::{ForwardNotifications, ForwardedNotification},
+    global::{GlobalMutation, ObserveChanged},
+    group::{InternedMonitorGroupLabel, MonitorGroup, MonitorGroupLabel, RetargetGroup},
+    history::{PreviousFrameNotifications, RetainPreviousFrame, RetainedNotification},
+    lifetime::{DespawnTargetWithMonitor, DespawnWithTarget},
+    messages::NotificationMessages,
+    mirror::{MirrorNotifications, MirrorOf, Mirrored, NotificationKind, NotificationMirror},
+    monitors::{Monitor, MonitorChildren, MonitorMany, MonitorSelf, MonitoredBy},
+    mutation::{Mutation, MutationSet, NotifyChanged, NotifySet},
+    ordering::OrderedNotifications,
+    pause::MonitorPaused,
+    pipeline::NotifyPipeline,
+    previous::PreviousValue,
+    priority::{NotificationBudget, NotifyPriority, ShedNotifications},
+    projection::ProjectToResource,
+    provenance::{AuthoritativeScope, FilterProvenance, Provenance},
+    reactions::NotifyReactions,
+    reattach::MonitorNamed,
+    reflection::{ApplyAndNotify, PatchError},
+    relation::{NotifyRelationChanged, RelationChanged},
+    removal::{NotifyRemoved, Removal, RemovalCause},
+    required::RequireNotify,
+    rollback::{CheckpointId, Rollback, RollbackHistory},
+    sequence::SequencedNotifications,
+    settings::{NotifySchedule, NotifySettings, run_notify_systems},
+    settled::{NotifySettled, Settled},
+    slicing::{NotificationSlice, SliceNotifications},
+    slot::{Slot, SlotOf, TargetSlots},
+    slow::{ObserverBudget, SlowMonitor},
+    snapshot::NotifyWithValue,
+    sorted_view::{ItemEntered, ItemLeft, ItemMoved, SortedView},
+    stats::MonitorStats,
+    suppress::SuppressedNotifications,
+    swap::{NotifySwapped, Swapped},
+    target_budget::TargetBudget,
+    timestamp::{Timestamp, TimestampedNotifications},
+    topology::{MonitorGraph, MonitorNode, TrackMonitorGraph, WatchedComponent},
+    versioned::Versioned,
 };
+
+#[cfg(feature = "time")]
+pub use crate::accumulate::{AccumulateChanges, Accumulated};
+#[cfg(feature = "animation")]
+pub use crate::animation::AnimateOnNotify;
+#[cfg(feature = "bench")]
+pub use crate::bench::StressHarness;
+#[cfg(feature = "dev-tools")]
+pub use crate::capture::{CaptureOnNotify, NotifyCapture, NotifyCaptures};
+#[cfg(feature = "expressions")]
+pub use crate::expression::{
+    ExpressionChanged, ExpressionError, NotifyExpression, WatchExpression,
+};
+#[cfg(feature = "ffi")]
+pub use crate::ffi::{FfiCallback, FfiNotification, FfiNotifications};
+#[cfg(feature = "dev-tools")]
+pub use crate::heatmap::{ChangeHeat, ChangeHeatmap};
+#[cfg(feature = "log")]
+pub use crate::inspect::LogChanges;
+#[cfg(feature = "persistence")]
+pub use crate::persistence::{PersistError, PersistMonitors, PersistencePlugin, Persistent};
+#[cfg(feature = "proximity")]
+pub use crate::proximity::ActivateWithinRange;
+#[cfg(feature = "scripting")]
+pub use crate::scripting::{ScriptCallback, ScriptNotification, ScriptNotifications};
+#[cfg(feature = "state")]
+pub use crate::suppress::SuppressNotify;
@@ -0,0 +1,110 @@
+use crate::prelude::*;
+use bevy_ecs::{
+    component::Mutable, entity::EntityHashMap, lifecycle::HookContext, prelude::*,
+    world::DeferredWorld,
+};
+
+#[derive(Component)]
+#[require(NotifyChanged<C> = NotifyChanged::required())]
+#[component(on_add = PreviousValue::<C>::capture_new_values)]
+/// Adding this component to a monitor will cause the [`Mutation<C>`] it receives to carry the
+/// value [`C`] had before the change, see [`Mutation::previous`], as well as the value after it,
+/// see [`Mutation::value`].
+///
+/// This is useful for anything that needs the delta rather than just knowing something changed,
+/// such as animating a health bar from the old value to the new one, or recording an undo step.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component, Clone)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands
+///     .spawn((Monitor(player), PreviousValue::<Health>::default()))
+///     .observe(|mutation: On<Mutation<Health>>| {
+///         if let (Some(Health(from)), Some(Health(to))) = (mutation.previous(), mutation.value()) {
+///             println!("Health went from {from} to {to}");
+///         }
+///     });
+/// # }
+/// ```
+///
+/// The previous value is the one the monitor was last notified about, so the first mutation on
+/// each watched entity has none. Values are remembered per monitor, keeping a copy of [`C`] for
+/// every entity it watches.
+pub struct PreviousValue<C: Component> {
+    snapshot: fn(&C) -> C,
+    last: EntityHashMap<C>,
+}
+impl<C: Component<Mutability = Mutable> + Clone> Default for PreviousValue<C> {
+    fn default() -> Self {
+        Self {
+            snapshot: C::clone,
+            last: EntityHashMap::default(),
+        }
+    }
+}
+impl<C: Component> PreviousValue<C> {
+    fn capture_new_values(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let Some(&PreviousValue { snapshot, .. }) = world.get::<Self>(entity) else {
+            return;
+        };
+
+        world
+            .commands()
+            .entity(entity)
+            .insert_if_new(NotifyWithValue { snapshot });
+    }
+    /// Remembers `value` as the last one seen on `watched`, returning the one before it.
+    pub(crate) fn replace(&mut self, watched: Entity, value: &C) -> Option<C> {
+        self.last.insert(watched, (self.snapshot)(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Deltas(Vec<(Option<Health>, Option<Health>)>);
+
+    #[test]
+    fn carry_previous_value() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Deltas>();
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((Monitor(player), PreviousValue::<Health>::default()))
+            .observe(
+                |mutation: On<Mutation<Health>>, mut deltas: ResMut<Deltas>| {
+                    deltas
+                        .0
+                        .push((mutation.previous().cloned(), mutation.value().cloned()));
+                },
+            );
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 30;
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<Deltas>().0,
+            vec![
+                (None, Some(Health(100))),
+                (Some(Health(100)), Some(Health(90))),
+                (Some(Health(90)), Some(Health(60))),
+            ]
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use crate::registry::{add_systems, register_once};
+use bevy_app::{App, First};
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+
+#[derive(Component, Reflect, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+/// How important the notifications a monitor receives are, deciding which are shed first when the
+/// [`NotificationBudget`] is exceeded.
+///
+/// Monitors without this component are [`NotifyPriority::Normal`].
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands.spawn((
+///     Monitor(player),
+///     NotifyChanged::<Health>::default(),
+///     NotifyPriority::Cosmetic,
+/// ));
+/// # }
+/// ```
+pub enum NotifyPriority {
+    /// Gameplay critical notifications, these are never shed.
+    Critical,
+    /// Shed once the budget has been exceeded twice over.
+    #[default]
+    Normal,
+    /// Visual polish such as particles and tweens, shed as soon as the budget has been exceeded.
+    Cosmetic,
+}
+
+#[derive(Resource, Reflect, Clone, Hash, PartialEq, Eq, Debug)]
+/// The number of notifications that can be delivered each frame before they start being shed,
+/// inserted by [`ShedNotifications::shed_notifications_over`].
+///
+/// Shed notifications are counted as dropped by [`MonitorStats`].
+///
+/// [`MonitorStats`]: crate::prelude::MonitorStats
+pub struct NotificationBudget {
+    pub per_frame: usize,
+    delivered: usize,
+}
+impl NotificationBudget {
+    /// The number of notifications delivered so far this frame.
+    pub fn delivered(&self) -> usize {
+        self.delivered
+    }
+    /// Counts a notification with the given priority, returning whether it should be delivered.
+    pub(crate) fn spend(&mut self, priority: NotifyPriority) -> bool {
+        let allowed = match priority {
+            NotifyPriority::Critical => true,
+            NotifyPriority::Normal => self.delivered < self.per_frame.saturating_mul(2),
+            NotifyPriority::Cosmetic => self.delivered < self.per_frame,
+        };
+
+        if allowed {
+            self.delivered += 1;
+        }
+        allowed
+    }
+}
+
+/// Allows shedding less important notifications during spikes, keeping gameplay critical reactions
+/// timely while degrading visual polish gracefully.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # let mut app = App::new();
+/// app.shed_notifications_over(500);
+/// ```
+///
+/// # Technical info
+///
+/// This adds a system to the [`First`] schedule that resets the [`NotificationBudget`], this is
+/// only done once.
+pub trait ShedNotifications {
+    /// Starts shedding notifications by [`NotifyPriority`] once more than `per_frame` have been
+    /// delivered in a frame.
+    fn shed_notifications_over(&mut self, per_frame: usize) -> &mut Self;
+}
+
+impl ShedNotifications for World {
+    fn shed_notifications_over(&mut self, per_frame: usize) -> &mut Self {
+        register_once::<NotificationBudget>(self, |world| {
+            add_systems(world, First, reset_notification_budget);
+        });
+
+        self.insert_resource(NotificationBudget {
+            per_frame,
+            delivered: 0,
+        });
+        self
+    }
+}
+
+impl ShedNotifications for App {
+    fn shed_notifications_over(&mut self, per_frame: usize) -> &mut Self {
+        self.world_mut().shed_notifications_over(per_frame);
+        self
+    }
+}
+
+fn reset_notification_budget(budget: Option<ResMut<NotificationBudget>>) {
+    if let Some(mut budget) = budget {
+        budget.delivered = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health;
+
+    #[test]
+    fn shed_cosmetic_first() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(First));
+        world.add_schedule(Schedule::new(Update));
+        world.shed_notifications_over(2);
+
+        let player = world.spawn(Health).id();
+        let [critical, normal, cosmetic] = [
+            NotifyPriority::Critical,
+            NotifyPriority::Normal,
+            NotifyPriority::Cosmetic,
+        ]
+        .map(|priority| {
+            world
+                .spawn((
+                    Monitor(player),
+                    NotifyChanged::<Health>::default(),
+                    priority,
+                    MonitorStats::default(),
+                ))
+                .id()
+        });
+
+        world.run_schedule(First);
+        // Something else used up the budget.
+        world.resource_mut::<NotificationBudget>().delivered = 2;
+        world.run_schedule(Update);
+
+        let received =
+            |world: &World, monitor| world.get::<MonitorStats>(monitor).unwrap().received;
+
+        assert_eq!(received(&world, critical), 1);
+        assert_eq!(received(&world, normal), 1);
+        assert_eq!(received(&world, cosmetic), 0);
+
+        world.run_schedule(First);
+
+        assert_eq!(world.resource::<NotificationBudget>().delivered(), 0);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    prelude::*,
+    registry::{register_once, register_teardown, unregister},
+};
+use bevy_ecs::{component::Mutable, lifecycle::HookContext, prelude::*, world::DeferredWorld};
+use std::marker::PhantomData;
+
+#[derive(Resource)]
+/// Contains the observer writing projections of [`C`] into [`R`].
+struct DetectingProjections<C: Component, R: Resource> {
+    observer: Entity,
+    _phantom: PhantomData<(C, R)>,
+}
+
+#[derive(Component)]
+#[component(
+    on_add = ProjectToResource::<C, R>::register_projection_observer,
+    on_remove = ProjectToResource::<C, R>::remove_projection_observer
+)]
+#[require(NotifyChanged<C> = NotifyChanged::required())]
+/// Adding this component to a monitor will write a projection of [`C`] into the resource [`R`]
+/// whenever the monitor receives [`Mutation<C>`], without any observers of your own.
+///
+/// This bridges monitors to systems that read resources, such as many systems reading the health
+/// of the currently selected unit.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// #[derive(Resource)]
+/// pub struct SelectedUnitHealth(pub u8);
+///
+/// # fn showcase(mut commands: Commands, selected: Entity) {
+/// commands.spawn((
+///     Monitor(selected),
+///     ProjectToResource::<Health, _>::by(|health| SelectedUnitHealth(health.0)),
+/// ));
+/// # }
+/// ```
+///
+/// The resource is left as it is when the monitor is despawned.
+///
+/// # Technical info
+///
+/// Adding this component to an entity will spawn an [`Observer`] for event [`Mutation<C>`], this is
+/// only done once.
+///
+/// When all instances of this component in the world are removed the observer will be despawned.
+pub struct ProjectToResource<C: Component, R: Resource> {
+    projection: fn(&C) -> R,
+}
+impl<C: Component, R: Resource> ProjectToResource<C, R> {
+    /// Writes the value returned by `projection` into [`R`].
+    pub fn by(projection: fn(&C) -> R) -> Self
+    where
+        C: Component<Mutability = Mutable>,
+    {
+        Self { projection }
+    }
+    fn register_projection_observer(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                let observer = world.add_observer(project::<C, R>).id();
+                world.insert_resource(DetectingProjections::<C, R> {
+                    observer,
+                    _phantom: PhantomData,
+                });
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_projection_observer(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_projections = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_projections == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `ProjectToResource::<C, R>` adds the `DetectingProjections`
+        // resource.
+        let DetectingProjections { observer, .. } = world
+            .remove_resource::<DetectingProjections<C, R>>()
+            .unwrap();
+        world.entity_mut(observer).despawn();
+    }
+}
+
+fn project<C: Component, R: Resource>(
+    mutation: On<Mutation<C>>,
+    mut commands: Commands,
+    projections: Query<&ProjectToResource<C, R>>,
+    values: Query<&C>,
+) {
+    let Ok(ProjectToResource { projection }) = projections.get(mutation.entity) else {
+        return;
+    };
+    let Ok(value) = values.get(mutation.mutated) else {
+        return;
+    };
+
+    commands.insert_resource(projection(value));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, PartialEq, Debug)]
+    pub struct SelectedUnitHealth(u8);
+
+    #[test]
+    fn project_selected_unit() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+
+        let knight = world.spawn(Health(100)).id();
+        let archer = world.spawn(Health(60)).id();
+        let selection = world
+            .spawn((
+                Monitor(knight),
+                ProjectToResource::<Health, _>::by(|health| SelectedUnitHealth(health.0)),
+            ))
+            .id();
+
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<SelectedUnitHealth>(),
+            &SelectedUnitHealth(100)
+        );
+
+        world.get_mut::<Health>(knight).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<SelectedUnitHealth>(),
+            &SelectedUnitHealth(90)
+        );
+
+        world.entity_mut(selection).insert(Monitor(archer));
+        world.get_mut::<Health>(knight).unwrap().0 -= 10;
+        world.get_mut::<Health>(archer).unwrap().0 -= 0;
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<SelectedUnitHealth>(),
+            &SelectedUnitHealth(60)
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{
+    mutation::MutationSet,
+    registry::{add_systems, register_once},
+};
+use bevy_app::Update;
+use bevy_ecs::{change_detection::Tick, prelude::*};
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+/// Where the latest change to a component came from.
+pub enum Provenance {
+    /// The change was made locally, such as by client-side prediction, and may later be rolled
+    /// back. This is the case for any change made outside of
+    /// [`AuthoritativeScope::authoritative_scope`].
+    #[default]
+    Predicted,
+    /// The change was made inside [`AuthoritativeScope::authoritative_scope`], such as when applying
+    /// state received from a server.
+    Authoritative,
+}
+
+#[derive(Component, Reflect, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+/// Adding this component to a monitor will cause it to only receive [`Mutation<C>`] for changes
+/// with the given [`Provenance`], any others are dropped.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands.spawn((
+///     Monitor(player),
+///     NotifyChanged::<Health>::default(),
+///     FilterProvenance(Provenance::Authoritative),
+/// ));
+/// # }
+/// ```
+///
+/// [`Mutation<C>`]: crate::prelude::Mutation
+pub struct FilterProvenance(pub Provenance);
+
+#[derive(Resource, Default)]
+/// Contains the ranges of change ticks used inside of [`AuthoritativeScope::authoritative_scope`]
+/// that haven't been checked by [`MutationSet`] yet.
+pub(crate) struct AuthoritativeTicks(Vec<(Tick, Tick)>);
+impl AuthoritativeTicks {
+    /// The [`Provenance`] of a change made at `tick`.
+    pub(crate) fn provenance(&self, tick: Tick) -> Provenance {
+        if self
+            .0
+            .iter()
+            .any(|&(start, end)| tick == start || tick.is_newer_than(start, end))
+        {
+            Provenance::Authoritative
+        } else {
+            Provenance::Predicted
+        }
+    }
+}
+
+/// Allows marking changes as [`Provenance::Authoritative`].
+pub trait AuthoritativeScope {
+    /// Runs `scope`, marking every change it makes as [`Provenance::Authoritative`].
+    ///
+    /// ```rust
+    /// # use bevy_notify::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # #[derive(Component)]
+    /// # pub struct Health(pub u8);
+    /// # fn showcase(mut commands: Commands, player: Entity) {
+    /// commands.authoritative_scope(move |world: &mut World| {
+    ///     world.get_mut::<Health>(player).unwrap().0 = 90;
+    /// });
+    /// # }
+    /// ```
+    fn authoritative_scope(&mut self, scope: impl FnOnce(&mut World) + Send + 'static);
+}
+
+impl AuthoritativeScope for World {
+    fn authoritative_scope(&mut self, scope: impl FnOnce(&mut World) + Send + 'static) {
+        register_once::<AuthoritativeTicks>(self, |world| {
+            world.init_resource::<AuthoritativeTicks>();
+            add_systems(world, Update, forget_authoritative_ticks.after(MutationSet));
+        });
+
+        // Changes made before and after the scope must not share a tick with the changes made
+        // inside of it.
+        self.increment_change_tick();
+        let start = self.change_tick();
+
+        scope(self);
+
+        let end = self.change_tick();
+        self.increment_change_tick();
+
+        self.get_resource_or_init::<AuthoritativeTicks>()
+            .0
+            .push((start, end));
+    }
+}
+
+impl AuthoritativeScope for Commands<'_, '_> {
+    fn authoritative_scope(&mut self, scope: impl FnOnce(&mut World) + Send + 'static) {
+        self.queue(move |world: &mut World| world.authoritative_scope(scope));
+    }
+}
+
+fn forget_authoritative_ticks(mut ticks: ResMut<AuthoritativeTicks>) {
+    ticks.0.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Received(Vec<Provenance>);
+
+    #[test]
+    fn filter_predicted_changes() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Received>();
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                FilterProvenance(Provenance::Authoritative),
+            ))
+            .observe(
+                |mutation: On<Mutation<Health>>, mut received: ResMut<Received>| {
+                    received.0.push(mutation.provenance());
+                },
+            );
+
+        world.run_schedule(Update);
+        world.resource_mut::<Received>().0.clear();
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert!(world.resource::<Received>().0.is_empty());
+
+        world.authoritative_scope(move |world| {
+            world.get_mut::<Health>(player).unwrap().0 = 95;
+        });
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<Received>().0,
+            vec![Provenance::Authoritative]
+        );
+
+        // A prediction made after the authoritative change takes precedence.
+
+        world.authoritative_scope(move |world| {
+            world.get_mut::<Health>(player).unwrap().0 = 90;
+        });
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<Received>().0,
+            vec![Provenance::Authoritative]
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{
+    monitors::{Hierarchy, MonitorTargets},
+    mutation::MutationSet,
+    prelude::*,
+    registry::{add_systems, register_once, register_teardown, unregister},
+};
+use bevy_app::Update;
+use bevy_camera::Camera;
+use bevy_ecs::{
+    lifecycle::HookContext, prelude::*, schedule::ScheduleCleanupPolicy, world::DeferredWorld,
+};
+use bevy_reflect::Reflect;
+use bevy_transform::components::GlobalTransform;
+
+#[derive(Component, Reflect, Clone, Copy, PartialEq, PartialOrd, Default, Debug)]
+#[component(
+    on_add = ActivateWithinRange::register_activation_system,
+    on_remove = ActivateWithinRange::remove_activation_system
+)]
+/// Pauses a monitor with [`MonitorPaused`] while the entity it watches is further than this
+/// distance from the active [`Camera`], resuming it once it comes back into range.
+///
+/// This is useful for large worlds with many monitors for entities that are off-screen, such as
+/// nameplates and health bars.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, enemy: Entity) {
+/// commands.spawn((
+///     Monitor(enemy),
+///     NotifyChanged::<Health>::default(),
+///     ActivateWithinRange(50.),
+/// ));
+/// # }
+/// ```
+///
+/// Monitors watching several entities, such as with both [`Monitor`] and [`MonitorSelf`] or with
+/// [`MonitorMany`] or [`MonitorChildren`], stay active while any of them is in range. Monitors watching nothing with a
+/// [`GlobalTransform`] are left as they are, as are global monitors and all monitors while there is
+/// no active [`Camera`].
+///
+/// Since resuming a monitor catches it up, it will be notified about anything that changed while
+/// it was out of range.
+///
+/// # Technical info
+///
+/// Adding this component to an entity will add a system to the [`Update`] schedule that runs before
+/// [`MutationSet`], this is only done once.
+///
+/// When all instances of this component in the world are removed the system will be removed.
+pub struct ActivateWithinRange(pub f32);
+impl ActivateWithinRange {
+    fn register_activation_system(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                add_systems(world, Update, activate_within_range.before(MutationSet));
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_activation_system(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_activated = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_activated == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        world.schedule_scope(Update, |world, schedule| {
+            // # Safety
+            // `ActivateWithinRange` being registered ensures this system exists in the
+            // `Update` schedule.
+            schedule
+                .remove_systems_in_set(
+                    activate_within_range,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+    }
+}
+
+fn activate_within_range(
+    mut commands: Commands,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    transforms: Query<&GlobalTransform>,
+    monitors: Query<(MonitorTargets, &ActivateWithinRange, Has<MonitorPaused>)>,
+    hierarchy: Hierarchy,
+) {
+    let Some(camera) = cameras
+        .iter()
+        .find(|(camera, _)| camera.is_active)
+        .map(|(_, transform)| transform.translation())
+    else {
+        return;
+    };
+
+    monitors
+        .iter()
+        .for_each(|(targets, &ActivateWithinRange(range), paused)| {
+            let entity = targets.entity;
+            let Some(targets) = targets.resolve(&hierarchy) else {
+                return;
+            };
+            let mut distances = transforms
+                .iter_many(targets)
+                .map(|target| target.translation().distance(camera))
+                .peekable();
+
+            if distances.peek().is_none() {
+                return;
+            }
+
+            let in_range = distances.any(|distance| distance <= range);
+
+            match (in_range, paused) {
+                (true, true) => {
+                    commands.entity(entity).remove::<MonitorPaused>();
+                }
+                (false, false) => {
+                    commands.entity(entity).insert(MonitorPaused);
+                }
+                _ => {}
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[test]
+    fn pause_when_far_away() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+
+        world.spawn((Camera::default(), GlobalTransform::default()));
+
+        let enemy = world.spawn(GlobalTransform::from_xyz(100., 0., 0.)).id();
+        let nameplate = world.spawn((Monitor(enemy), ActivateWithinRange(50.))).id();
+
+        world.run_schedule(Update);
+
+        assert!(world.entity(nameplate).contains::<MonitorPaused>());
+
+        world
+            .entity_mut(enemy)
+            .insert(GlobalTransform::from_xyz(10., 0., 0.));
+        world.run_schedule(Update);
+
+        assert!(!world.entity(nameplate).contains::<MonitorPaused>());
+    }
+
+    #[test]
+    fn stay_active_while_any_in_range() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+
+        world.spawn((Camera::default(), GlobalTransform::default()));
+
+        let near = world.spawn(GlobalTransform::from_xyz(10., 0., 0.)).id();
+        let far = world.spawn(GlobalTransform::from_xyz(100., 0., 0.)).id();
+        let hud = world
+            .spawn((MonitorMany(vec![near, far]), ActivateWithinRange(50.)))
+            .id();
+
+        world.run_schedule(Update);
+
+        assert!(!world.entity(hud).contains::<MonitorPaused>());
+
+        world
+            .entity_mut(near)
+            .insert(GlobalTransform::from_xyz(100., 0., 0.));
+        world.run_schedule(Update);
+
+        assert!(world.entity(hud).contains::<MonitorPaused>());
+    }
+}
@@ -0,0 +1,206 @@
+use crate::registry::{register_once, register_teardown, unregister};
+use bevy_ecs::{lifecycle::HookContext, prelude::*, system::SystemId, world::DeferredWorld};
+use std::marker::PhantomData;
+
+/// A single step of [`NotifyReactions<E>`], given the monitor that received the notification.
+type Reaction = Box<dyn Fn(&mut Commands, Entity) + Send + Sync>;
+
+#[derive(Resource)]
+/// Contains the observer running [`NotifyReactions<E>`].
+struct DetectingReactions<E: EntityEvent> {
+    observer: Entity,
+    _phantom: PhantomData<E>,
+}
+
+#[derive(Component)]
+#[component(
+    on_add = NotifyReactions::<E>::register_reaction_observer,
+    on_remove = NotifyReactions::<E>::remove_reaction_observer
+)]
+/// Adding this component to a monitor will run a series of reactions, in the order they were
+/// declared, whenever it receives [`E`].
+///
+/// Many reactions are simple enough that writing an observer for them is pure ceremony, such as
+/// highlighting a widget when the value it shows changes.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # #[derive(Component, Clone)]
+/// # pub struct Flash;
+/// # #[derive(Component)]
+/// # pub struct Idle;
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands.spawn((
+///     Monitor(player),
+///     NotifyChanged::<Health>::default(),
+///     NotifyReactions::<Mutation<Health>>::default()
+///         .then_insert(Flash)
+///         .then_remove::<Idle>(),
+/// ));
+/// # }
+/// ```
+///
+/// Inserting and removing components is done on the monitor that received the notification.
+///
+/// # Technical info
+///
+/// Adding this component to an entity will spawn an [`Observer`] for event [`E`], this is only
+/// done once.
+///
+/// When all instances of this component in the world are removed the observer will be despawned.
+pub struct NotifyReactions<E: EntityEvent> {
+    reactions: Vec<Reaction>,
+    _phantom: PhantomData<E>,
+}
+impl<E: EntityEvent> Default for NotifyReactions<E> {
+    fn default() -> Self {
+        Self {
+            reactions: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<E: EntityEvent> NotifyReactions<E> {
+    /// Inserts `bundle` into the monitor.
+    pub fn then_insert(mut self, bundle: impl Bundle + Clone) -> Self {
+        self.reactions.push(Box::new(move |commands, monitor| {
+            if let Ok(mut monitor) = commands.get_entity(monitor) {
+                monitor.insert(bundle.clone());
+            }
+        }));
+        self
+    }
+    /// Removes the component [`C`] from the monitor.
+    pub fn then_remove<C: Component>(mut self) -> Self {
+        self.reactions.push(Box::new(|commands, monitor| {
+            if let Ok(mut monitor) = commands.get_entity(monitor) {
+                monitor.try_remove::<C>();
+            }
+        }));
+        self
+    }
+    /// Triggers `event`.
+    pub fn then_trigger<'t, T: Event<Trigger<'t>: Default> + Clone>(mut self, event: T) -> Self {
+        self.reactions.push(Box::new(move |commands, _| {
+            commands.trigger(event.clone());
+        }));
+        self
+    }
+    /// Runs the one-shot system `system`.
+    pub fn then_run(mut self, system: SystemId) -> Self {
+        self.reactions.push(Box::new(move |commands, _| {
+            commands.run_system(system);
+        }));
+        self
+    }
+    fn register_reaction_observer(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                let observer = world.add_observer(react::<E>).id();
+                world.insert_resource(DetectingReactions::<E> {
+                    observer,
+                    _phantom: PhantomData,
+                });
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_reaction_observer(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_reactions = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_reactions == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `NotifyReactions::<E>` adds the `DetectingReactions` resource.
+        let DetectingReactions { observer, .. } =
+            world.remove_resource::<DetectingReactions<E>>().unwrap();
+        world.entity_mut(observer).despawn();
+    }
+}
+
+fn react<E: EntityEvent>(
+    event: On<E>,
+    mut commands: Commands,
+    reactions: Query<&NotifyReactions<E>>,
+) {
+    let monitor = event.event_target();
+    let Ok(NotifyReactions { reactions, .. }) = reactions.get(monitor) else {
+        return;
+    };
+
+    reactions
+        .iter()
+        .for_each(|reaction| reaction(&mut commands, monitor));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Component, Clone)]
+    pub struct Flash;
+
+    #[derive(Component)]
+    pub struct Idle;
+
+    #[derive(Event, Clone)]
+    pub struct Redraw;
+
+    #[derive(Resource, Default)]
+    pub struct Steps(Vec<&'static str>);
+
+    #[test]
+    fn chain_reactions() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Steps>();
+        world.add_observer(|_: On<Redraw>, mut steps: ResMut<Steps>| {
+            steps.0.push("trigger");
+        });
+
+        let run = world.register_system(|mut steps: ResMut<Steps>| {
+            steps.0.push("run");
+        });
+        let player = world.spawn(Health(100)).id();
+        let monitor = world
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                Idle,
+                NotifyReactions::<Mutation<Health>>::default()
+                    .then_insert(Flash)
+                    .then_remove::<Idle>()
+                    .then_trigger(Redraw)
+                    .then_run(run),
+            ))
+            .id();
+
+        world.flush();
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert!(world.entity(monitor).contains::<Flash>());
+        assert!(!world.entity(monitor).contains::<Idle>());
+        assert_eq!(world.resource::<Steps>().0, vec!["trigger", "run"]);
+    }
+}
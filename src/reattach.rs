@@ -0,0 +1,187 @@
+use crate::{
+    prelude::*,
+    registry::{register_once, register_teardown, unregister},
+};
+use bevy_ecs::{lifecycle::HookContext, prelude::*, world::DeferredWorld};
+use bevy_reflect::Reflect;
+use std::cmp::Ordering;
+
+#[derive(Resource, Reflect, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+/// Contains the observer re-attaching [`MonitorNamed`] monitors.
+struct DetectingNames {
+    observer: Entity,
+}
+
+#[derive(Component, Reflect, Clone, PartialEq, Eq, Debug)]
+#[component(
+    on_add = MonitorNamed::resolve_target,
+    on_remove = MonitorNamed::remove_name_observer
+)]
+/// Keeps a monitor's [`Monitor`] pointed at whichever entity currently has the given [`Name`].
+///
+/// This is useful for entities that are despawned and respawned, such as those belonging to a
+/// scene that gets hot-reloaded. When the original target is despawned the [`Monitor`] is removed,
+/// once an entity with a matching [`Name`] appears the monitor is re-attached to it.
+///
+/// While no entity with a matching [`Name`] exists the monitor will not react to anything, rather
+/// than falling back to reacting to all entities.
+///
+/// If multiple entities share the same [`Name`] the most recently named one is used, that is the
+/// one whose [`Name`] was last inserted or changed.
+///
+/// # Technical info
+///
+/// Adding this component to an entity will spawn an [`Observer`] for event [`On<Insert, Name>`],
+/// this is only done once.
+///
+/// When all instances of this component in the world are removed the observer will be despawned.
+pub struct MonitorNamed(pub Name);
+impl MonitorNamed {
+    pub fn new(name: impl Into<Name>) -> Self {
+        Self(name.into())
+    }
+    fn resolve_target(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                let observer = world.add_observer(reattach_on_name).id();
+                world.insert_resource(DetectingNames { observer });
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+
+        world.commands().queue(move |world: &mut World| {
+            let Some(MonitorNamed(name)) = world.get::<MonitorNamed>(entity).cloned() else {
+                return;
+            };
+            let this_run = world.change_tick();
+            let target = world
+                .query::<(Entity, Ref<Name>)>()
+                .iter(world)
+                .filter(|(target, target_name)| *target != entity && **target_name == name)
+                .max_by(|(_, a), (_, b)| {
+                    if a.last_changed().is_newer_than(b.last_changed(), this_run) {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Less
+                    }
+                })
+                .map(|(target, _)| target);
+
+            if let Some(target) = target {
+                world.entity_mut(entity).insert(Monitor(target));
+            }
+        });
+    }
+    fn remove_name_observer(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_named = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_named == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `MonitorNamed` adds the `DetectingNames` resource.
+        let DetectingNames { observer } = world.remove_resource::<DetectingNames>().unwrap();
+        world.entity_mut(observer).despawn();
+    }
+}
+
+pub(crate) fn reattach_on_name(
+    insert: On<Insert, Name>,
+    mut commands: Commands,
+    names: Query<&Name>,
+    monitors: Query<(Entity, &MonitorNamed)>,
+) {
+    let Ok(name) = names.get(insert.entity) else {
+        return;
+    };
+
+    monitors
+        .iter()
+        .filter(|(monitor, MonitorNamed(wanted))| *monitor != insert.entity && wanted == name)
+        .for_each(|(monitor, _)| {
+            commands.entity(monitor).insert(Monitor(insert.entity));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Poisoned;
+
+    #[derive(Resource, Default)]
+    pub struct TimesPoisoned(usize);
+
+    #[test]
+    fn reattach_after_respawn() {
+        let mut world = World::new();
+
+        world.init_resource::<TimesPoisoned>();
+
+        let player = world.spawn(Name::new("Player")).id();
+
+        let monitor = world
+            .spawn((
+                MonitorNamed::new("Player"),
+                NotifyAdded::<Poisoned>::default(),
+            ))
+            .observe(
+                |_: On<Addition<Poisoned>>, mut times_poisoned: ResMut<TimesPoisoned>| {
+                    times_poisoned.0 += 1;
+                },
+            )
+            .id();
+
+        assert_eq!(world.get::<Monitor>(monitor), Some(&Monitor(player)));
+
+        world.entity_mut(player).insert(Poisoned);
+
+        assert_eq!(world.resource::<TimesPoisoned>().0, 1);
+
+        // Simulate a scene reload.
+
+        world.entity_mut(player).despawn();
+
+        let bystander = world.spawn(Name::new("Bystander")).id();
+        world.entity_mut(bystander).insert(Poisoned);
+
+        assert_eq!(world.resource::<TimesPoisoned>().0, 1);
+
+        let player = world.spawn(Name::new("Player")).id();
+
+        assert_eq!(world.get::<Monitor>(monitor), Some(&Monitor(player)));
+
+        world.entity_mut(player).insert(Poisoned);
+
+        assert_eq!(world.resource::<TimesPoisoned>().0, 2);
+    }
+
+    #[test]
+    fn attach_to_most_recently_named() {
+        let mut world = World::new();
+
+        let original = world.spawn(Name::new("Player")).id();
+        world.spawn(Name::new("Player"));
+
+        world.increment_change_tick();
+        world.entity_mut(original).insert(Name::new("Player"));
+
+        let monitor = world.spawn(MonitorNamed::new("Player")).id();
+        world.flush();
+
+        assert_eq!(world.get::<Monitor>(monitor), Some(&Monitor(original)));
+    }
+}
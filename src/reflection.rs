@@ -0,0 +1,168 @@
+use bevy_app::App;
+use bevy_ecs::{change_detection::Tick, prelude::*, reflect::AppTypeRegistry};
+use bevy_reflect::PartialReflect;
+use std::{any::TypeId, collections::HashMap, error::Error, fmt};
+
+#[derive(Resource, Default)]
+/// The change tick at which each component was last patched by [`ApplyAndNotify`].
+///
+/// A mutation is only considered to come from reflection if the component hasn't been changed
+/// since, so entries never need to be cleared.
+pub(crate) struct ReflectedPatches {
+    patched: HashMap<(Entity, TypeId), Tick>,
+}
+impl ReflectedPatches {
+    /// Whether the change to [`C`] on `entity` at `changed` was made by [`ApplyAndNotify`].
+    pub(crate) fn contains<C: Component>(&self, entity: Entity, changed: Tick) -> bool {
+        self.patched
+            .get(&(entity, TypeId::of::<C>()))
+            .is_some_and(|&patched| patched == changed)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// The reasons [`ApplyAndNotify::apply_and_notify`] can fail.
+pub enum PatchError {
+    /// The patch doesn't represent a concrete type, such as a dynamic struct without one set.
+    UnknownType,
+    /// The type isn't registered in the [`AppTypeRegistry`] with `#[reflect(Component)]`.
+    Unregistered(&'static str),
+    NoEntity(Entity),
+    /// The entity doesn't have the component being patched.
+    NoComponent(Entity, &'static str),
+}
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownType => write!(f, "the patch doesn't represent a concrete type"),
+            Self::Unregistered(path) => write!(f, "{path} isn't registered as a component"),
+            Self::NoEntity(entity) => write!(f, "{entity} doesn't exist"),
+            Self::NoComponent(entity, path) => write!(f, "{entity} doesn't have {path}"),
+        }
+    }
+}
+impl Error for PatchError {}
+
+/// Allows tooling such as editors to patch components through reflection, marking the resulting
+/// [`Mutation<C>`] with [`Mutation::via_reflection`].
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component, Reflect)]
+/// # #[reflect(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(world: &mut World, player: Entity) -> Result<(), BevyError> {
+/// world.apply_and_notify(player, &Health(50))?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Components changed through [`ReflectComponent::apply`] directly, such as by remote protocol
+/// writes, are still detected like any other change but can't be told apart from them.
+///
+/// [`Mutation<C>`]: crate::prelude::Mutation
+/// [`Mutation::via_reflection`]: crate::prelude::Mutation::via_reflection
+pub trait ApplyAndNotify {
+    /// Applies `patch` to the component it represents on `entity`.
+    fn apply_and_notify(
+        &mut self,
+        entity: Entity,
+        patch: &dyn PartialReflect,
+    ) -> Result<(), PatchError>;
+}
+
+impl ApplyAndNotify for World {
+    fn apply_and_notify(
+        &mut self,
+        entity: Entity,
+        patch: &dyn PartialReflect,
+    ) -> Result<(), PatchError> {
+        let info = patch
+            .get_represented_type_info()
+            .ok_or(PatchError::UnknownType)?;
+        let path = info.type_path();
+        let registry = self.get_resource_or_init::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let reflect_component = registry
+            .get_type_data::<ReflectComponent>(info.type_id())
+            .ok_or(PatchError::Unregistered(path))?;
+        let component = self
+            .components()
+            .get_id(info.type_id())
+            .ok_or(PatchError::Unregistered(path))?;
+        let mut target = self
+            .get_entity_mut(entity)
+            .map_err(|_| PatchError::NoEntity(entity))?;
+
+        if !target.contains_id(component) {
+            return Err(PatchError::NoComponent(entity, path));
+        }
+
+        reflect_component.apply(&mut target, patch);
+
+        // # Safety
+        // The entity was checked to have the component above.
+        let changed = target.get_change_ticks_by_id(component).unwrap().changed;
+
+        self.get_resource_or_init::<ReflectedPatches>()
+            .patched
+            .insert((entity, info.type_id()), changed);
+        Ok(())
+    }
+}
+
+impl ApplyAndNotify for App {
+    fn apply_and_notify(
+        &mut self,
+        entity: Entity,
+        patch: &dyn PartialReflect,
+    ) -> Result<(), PatchError> {
+        self.world_mut().apply_and_notify(entity, patch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Patched(Vec<bool>);
+
+    #[test]
+    fn tag_reflected_patches() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Patched>();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(
+                |mutation: On<Mutation<Health>>, mut patched: ResMut<Patched>| {
+                    patched.0.push(mutation.via_reflection());
+                },
+            );
+        world.run_schedule(Update);
+
+        world.apply_and_notify(player, &Health(50)).unwrap();
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Patched>().0, vec![false, true, false]);
+        assert_eq!(world.get::<Health>(player).unwrap().0, 40);
+    }
+}
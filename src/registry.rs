@@ -0,0 +1,274 @@
+use crate::{ExplicitRegistration, UnregisteredNotify};
+use bevy_ecs::{
+    prelude::*,
+    schedule::{IntoScheduleConfigs, ScheduleLabel},
+    system::ScheduleSystem,
+};
+use std::{
+    any::{TypeId, type_name},
+    collections::{HashMap, HashSet},
+};
+
+#[derive(Resource, Default)]
+/// Keeps track of which components have had their systems and observers registered, keyed by the
+/// [`TypeId`] of the component that registered them.
+///
+/// Registration is checked when the queued command runs rather than in the hook that queued it, so
+/// inserting the same component many times before commands are applied (such as from multiple
+/// plugins, or multiple spawns in a single system) still only registers once.
+///
+/// Everything registered lives in the world it was registered in, as does this registry. Worlds
+/// watching the same components, such as several match worlds on a server, never share state and
+/// may run on different threads. Nothing in this crate should be cached in a `static`, apart from
+/// interning labels, which like Bevy's own labels doesn't depend on any world.
+pub(crate) struct NotifyRegistry {
+    registered: HashSet<TypeId>,
+    /// Registrations that were made up front, which are only torn down by [`unregister_pinned`]
+    /// or [`unregister_all`].
+    pinned: HashSet<TypeId>,
+    /// How to tear down each registration that can be torn down.
+    teardowns: HashMap<TypeId, fn(&mut World)>,
+    /// Registrations that component hooks may not make, as if [`ExplicitRegistration`] existed.
+    explicit: HashSet<TypeId>,
+}
+
+/// Runs `init` if [`T`] hasn't been registered yet.
+pub(crate) fn register_once<T: 'static>(world: &mut World, init: impl FnOnce(&mut World)) {
+    if world
+        .get_resource_or_init::<NotifyRegistry>()
+        .registered
+        .insert(TypeId::of::<T>())
+    {
+        init(world);
+    }
+}
+
+/// Runs `init` if [`T`] hasn't been registered yet, as done by component hooks.
+///
+/// While [`ExplicitRegistration`] exists, or if [`T`] was passed to [`require_explicit`], nothing
+/// is registered, instead an error is returned if [`T`] wasn't registered up front.
+pub(crate) fn register_implicitly<T: 'static>(
+    world: &mut World,
+    init: impl FnOnce(&mut World),
+) -> Result<(), UnregisteredNotify> {
+    if !world.contains_resource::<ExplicitRegistration>()
+        && world
+            .get_resource::<NotifyRegistry>()
+            .is_none_or(|registry| !registry.explicit.contains(&TypeId::of::<T>()))
+    {
+        register_once::<T>(world, init);
+        return Ok(());
+    }
+
+    if world
+        .get_resource::<NotifyRegistry>()
+        .is_some_and(|registry| registry.registered.contains(&TypeId::of::<T>()))
+    {
+        Ok(())
+    } else {
+        Err(UnregisteredNotify {
+            component: type_name::<T>(),
+        })
+    }
+}
+
+/// Prevents component hooks from registering [`T`], requiring it to be registered up front.
+pub(crate) fn require_explicit<T: 'static>(world: &mut World) {
+    world
+        .get_resource_or_init::<NotifyRegistry>()
+        .explicit
+        .insert(TypeId::of::<T>());
+}
+
+/// Runs `init` if [`T`] hasn't been registered yet, keeping it registered even once every
+/// component that would otherwise tear it down has been removed.
+pub(crate) fn register_pinned<T: 'static>(world: &mut World, init: impl FnOnce(&mut World)) {
+    register_once::<T>(world, init);
+    world
+        .resource_mut::<NotifyRegistry>()
+        .pinned
+        .insert(TypeId::of::<T>());
+}
+
+/// Records how to tear down [`T`], allowing [`unregister_all`] to tear it down.
+pub(crate) fn register_teardown<T: 'static>(world: &mut World, teardown: fn(&mut World)) {
+    world
+        .get_resource_or_init::<NotifyRegistry>()
+        .teardowns
+        .insert(TypeId::of::<T>(), teardown);
+}
+
+/// Runs `teardown` if [`T`] has been registered and isn't pinned, allowing it to be registered
+/// again afterwards.
+pub(crate) fn unregister<T: 'static>(world: &mut World, teardown: fn(&mut World)) {
+    if world
+        .get_resource_mut::<NotifyRegistry>()
+        .is_some_and(|mut registry| {
+            !registry.pinned.contains(&TypeId::of::<T>())
+                && registry.registered.remove(&TypeId::of::<T>())
+        })
+    {
+        world
+            .resource_mut::<NotifyRegistry>()
+            .teardowns
+            .remove(&TypeId::of::<T>());
+        teardown(world);
+    }
+}
+
+/// Runs `teardown` if [`T`] has been registered, even if it's pinned.
+pub(crate) fn unregister_pinned<T: 'static>(world: &mut World, teardown: fn(&mut World)) {
+    if let Some(mut registry) = world.get_resource_mut::<NotifyRegistry>() {
+        registry.pinned.remove(&TypeId::of::<T>());
+    }
+    unregister::<T>(world, teardown);
+}
+
+/// Tears down every registration that can be torn down, pinned or not.
+///
+/// Registrations shared by every component, such as the [`NotifySet`] configuration, are left in
+/// place as they do nothing on their own.
+///
+/// [`NotifySet`]: crate::prelude::NotifySet
+pub(crate) fn unregister_all(world: &mut World) {
+    let Some(mut registry) = world.get_resource_mut::<NotifyRegistry>() else {
+        return;
+    };
+    let teardowns = std::mem::take(&mut registry.teardowns);
+
+    teardowns.into_iter().for_each(|(id, teardown)| {
+        let mut registry = world.resource_mut::<NotifyRegistry>();
+
+        registry.registered.remove(&id);
+        registry.pinned.remove(&id);
+        teardown(world);
+    });
+}
+
+/// Adds `systems` to `schedule`, creating it if it doesn't exist yet.
+///
+/// Schedules in an [`App`](bevy_app::App) are only created once something is added to them, so
+/// they may not exist yet when a component is first inserted.
+pub(crate) fn add_systems<M>(
+    world: &mut World,
+    schedule: impl ScheduleLabel,
+    systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+) {
+    world
+        .get_resource_or_init::<Schedules>()
+        .entry(schedule)
+        .add_systems(systems);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Mutations(usize);
+
+    fn count_mutation(_: On<Mutation<Health>>, mut mutations: ResMut<Mutations>) {
+        mutations.0 += 1;
+    }
+
+    fn damage_everyone(app: &mut App) -> usize {
+        app.world_mut().resource_mut::<Mutations>().0 = 0;
+        app.world_mut()
+            .query::<&mut Health>()
+            .iter_mut(app.world_mut())
+            .for_each(|mut health| health.0 -= 1);
+        app.update();
+        app.world().resource::<Mutations>().0
+    }
+
+    #[test]
+    fn register_from_plugins() {
+        pub struct HudPlugin;
+        impl Plugin for HudPlugin {
+            fn build(&self, app: &mut App) {
+                app.world_mut()
+                    .spawn((Health(100), MonitorSelf, NotifyChanged::<Health>::default()))
+                    .observe(count_mutation);
+            }
+        }
+
+        pub struct MinimapPlugin;
+        impl Plugin for MinimapPlugin {
+            fn build(&self, app: &mut App) {
+                app.world_mut()
+                    .spawn((Health(100), MonitorSelf, NotifyChanged::<Health>::default()))
+                    .observe(count_mutation);
+            }
+        }
+
+        let mut app = App::new();
+
+        app.init_resource::<Mutations>()
+            .add_plugins((HudPlugin, MinimapPlugin));
+        app.update();
+
+        assert_eq!(damage_everyone(&mut app), 2);
+    }
+
+    #[test]
+    fn register_per_world() {
+        let mutations = std::thread::scope(|scope| {
+            let matches = (0..4)
+                .map(|match_index| {
+                    scope.spawn(move || {
+                        let mut app = App::new();
+
+                        app.init_resource::<Mutations>();
+                        let monitor = app
+                            .world_mut()
+                            .spawn((Health(100), MonitorSelf, NotifyChanged::<Health>::default()))
+                            .observe(count_mutation)
+                            .id();
+                        app.update();
+
+                        // Tearing down half of the worlds doesn't affect the others.
+                        if match_index % 2 == 0 {
+                            app.world_mut().entity_mut(monitor).despawn();
+                            app.update();
+                        }
+
+                        app.world_mut()
+                            .spawn((Health(100), MonitorSelf, NotifyChanged::<Health>::default()))
+                            .observe(count_mutation);
+                        app.update();
+
+                        damage_everyone(&mut app)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            matches
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(mutations, vec![1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn register_at_runtime() {
+        let mut app = App::new();
+
+        app.init_resource::<Mutations>()
+            .add_systems(Startup, |mut commands: Commands| {
+                for _ in 0..2 {
+                    commands
+                        .spawn((Health(100), MonitorSelf, NotifyChanged::<Health>::default()))
+                        .observe(count_mutation);
+                }
+            });
+        app.update();
+
+        assert_eq!(damage_everyone(&mut app), 2);
+    }
+}
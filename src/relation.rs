@@ -0,0 +1,336 @@
+use crate::{
+    dispatch::Notifier,
+    monitors::{Hierarchy, MonitorTargets},
+    mutation::configure_notify_sets,
+    prelude::*,
+    registry::{add_systems, register_once, register_teardown, unregister},
+};
+use bevy_app::Update;
+use bevy_ecs::{
+    entity::EntityHashMap, lifecycle::HookContext, prelude::*, schedule::ScheduleCleanupPolicy,
+    world::DeferredWorld,
+};
+use std::marker::PhantomData;
+
+#[derive(EntityEvent)]
+/// Indicates that the relation derived from [`A`] and [`B`] by a [`NotifyRelationChanged<A, B, R>`]
+/// has changed on an entity watched by a monitor.
+pub struct RelationChanged<A: Component, B: Component, R: PartialEq + Clone + Send + Sync + 'static>
+{
+    pub entity: Entity,
+    /// The [`Entity`] that [`A`] and [`B`] belong to.
+    pub changed: Entity,
+    /// The previous relation, this is `None` the first time the relation is derived.
+    pub previous: Option<R>,
+    pub current: R,
+    _phantom: PhantomData<(A, B)>,
+}
+
+#[derive(Component)]
+#[component(
+    on_add = NotifyRelationChanged::<A, B, R>::register_relation_system,
+    on_remove = NotifyRelationChanged::<A, B, R>::remove_relation_system
+)]
+/// Adding this component to a monitor will cause it to derive a relation [`R`] from [`A`] and [`B`]
+/// on the same entity, reacting with [`RelationChanged<A, B, R>`] only when the relation changes.
+///
+/// This is useful for things like health bars that only care which band the ratio of health to max
+/// health is in, rather than recomputing the ratio on every change to either.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u32);
+/// # #[derive(Component)]
+/// # pub struct MaxHealth(pub u32);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands
+///     .spawn((
+///         Monitor(player),
+///         NotifyRelationChanged::<Health, MaxHealth, _>::by(|health, max| {
+///             health.0 * 4 / max.0.max(1)
+///         }),
+///     ))
+///     .observe(|changed: On<RelationChanged<Health, MaxHealth, u32>>| {
+///         // Recolor the health bar.
+///     });
+/// # }
+/// ```
+///
+/// Like [`NotifyChanged<C>`] this will watch **all** entities unless restricted with [`Monitor`],
+/// [`MonitorSelf`], [`MonitorMany`], or [`MonitorChildren`], entities without both [`A`] and [`B`]
+/// are ignored.
+///
+/// # Technical info
+///
+/// Adding this component to an entity will add a system to the [`Update`] schedule that runs in
+/// [`NotifySet::Dispatch`], this is only done once.
+///
+/// When all instances of this component in the world are removed the system will be removed.
+pub struct NotifyRelationChanged<
+    A: Component,
+    B: Component,
+    R: PartialEq + Clone + Send + Sync + 'static,
+> {
+    relation: fn(&A, &B) -> R,
+    previous: EntityHashMap<R>,
+}
+impl<A: Component, B: Component, R: PartialEq + Clone + Send + Sync + 'static>
+    NotifyRelationChanged<A, B, R>
+{
+    /// Derives the relation with `relation`.
+    pub fn by(relation: fn(&A, &B) -> R) -> Self {
+        Self {
+            relation,
+            previous: EntityHashMap::default(),
+        }
+    }
+    fn register_relation_system(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                configure_notify_sets(world, Update);
+                add_systems(
+                    world,
+                    Update,
+                    watch_for_relation_change::<A, B, R>.in_set(NotifySet::Dispatch),
+                );
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_relation_system(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_relations = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_relations == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        world.schedule_scope(Update, |world, schedule| {
+            // # Safety
+            // `NotifyRelationChanged::<A, B, R>` being registered ensures this system
+            // exists in the `Update` schedule.
+            schedule
+                .remove_systems_in_set(
+                    watch_for_relation_change::<A, B, R>,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+    }
+}
+
+fn watch_for_relation_change<
+    A: Component,
+    B: Component,
+    R: PartialEq + Clone + Send + Sync + 'static,
+>(
+    mut notifier: Notifier,
+    suppressed_a: Option<Res<SuppressedNotifications<A>>>,
+    suppressed_b: Option<Res<SuppressedNotifications<B>>>,
+    changed: Query<Entity, (Or<(Changed<A>, Changed<B>)>, With<A>, With<B>)>,
+    values: Query<(&A, &B)>,
+    mut monitors: Query<(MonitorTargets, &mut NotifyRelationChanged<A, B, R>)>,
+    hierarchy: Hierarchy,
+) {
+    if suppressed_a.is_some() || suppressed_b.is_some() || changed.is_empty() {
+        return;
+    }
+
+    monitors.iter_mut().for_each(|(targets, mut notify)| {
+        let entity = targets.entity;
+        let targets = targets
+            .resolve(&hierarchy)
+            .unwrap_or_else(|| changed.iter().collect());
+
+        targets
+            .into_iter()
+            .filter(|&target| changed.contains(target))
+            .for_each(|target| {
+                let Ok((a, b)) = values.get(target) else {
+                    return;
+                };
+
+                let current = (notify.relation)(a, b);
+                let previous = notify.previous.insert(target, current.clone());
+
+                if previous.as_ref() == Some(&current) {
+                    return;
+                }
+
+                notifier.trigger(RelationChanged::<A, B, R> {
+                    entity,
+                    changed: target,
+                    previous,
+                    current,
+                    _phantom: PhantomData,
+                });
+            });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u32);
+
+    #[derive(Component)]
+    pub struct MaxHealth(u32);
+
+    #[derive(Resource, Default)]
+    pub struct Bands(Vec<(Option<u32>, u32)>);
+
+    #[test]
+    fn react_to_band_changes() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Bands>();
+
+        let player = world.spawn((Health(60), MaxHealth(100))).id();
+        world
+            .spawn((
+                Monitor(player),
+                NotifyRelationChanged::<Health, MaxHealth, _>::by(|health, max| {
+                    health.0 * 4 / max.0
+                }),
+            ))
+            .observe(
+                |changed: On<RelationChanged<Health, MaxHealth, u32>>, mut bands: ResMut<Bands>| {
+                    bands.0.push((changed.previous, changed.current));
+                },
+            );
+
+        world.run_schedule(Update);
+
+        // Still in the same band.
+        world.get_mut::<Health>(player).unwrap().0 = 55;
+        world.run_schedule(Update);
+
+        world.get_mut::<MaxHealth>(player).unwrap().0 = 200;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Bands>().0, vec![(None, 2), (Some(2), 1)]);
+    }
+
+    #[test]
+    fn react_to_target_and_self() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Bands>();
+
+        let player = world.spawn((Health(60), MaxHealth(100))).id();
+        let monitor = world
+            .spawn((
+                Monitor(player),
+                MonitorSelf,
+                Health(100),
+                MaxHealth(100),
+                NotifyRelationChanged::<Health, MaxHealth, _>::by(|health, max| {
+                    health.0 * 4 / max.0
+                }),
+            ))
+            .observe(
+                |changed: On<RelationChanged<Health, MaxHealth, u32>>, mut bands: ResMut<Bands>| {
+                    bands.0.push((changed.previous, changed.current));
+                },
+            )
+            .id();
+
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(monitor).unwrap().0 = 10;
+        world.run_schedule(Update);
+
+        let mut bands = world.resource::<Bands>().0.clone();
+        bands.sort();
+
+        assert_eq!(bands, vec![(None, 2), (None, 4), (Some(4), 0)]);
+    }
+
+    #[test]
+    fn react_to_many_targets() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Bands>();
+
+        let party = [
+            world.spawn((Health(60), MaxHealth(100))).id(),
+            world.spawn((Health(100), MaxHealth(100))).id(),
+        ];
+        let unrelated = world.spawn((Health(100), MaxHealth(100))).id();
+        world
+            .spawn((
+                MonitorMany(party.to_vec()),
+                NotifyRelationChanged::<Health, MaxHealth, _>::by(|health, max| {
+                    health.0 * 4 / max.0
+                }),
+            ))
+            .observe(
+                |changed: On<RelationChanged<Health, MaxHealth, u32>>, mut bands: ResMut<Bands>| {
+                    bands.0.push((changed.previous, changed.current));
+                },
+            );
+
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(party[1]).unwrap().0 = 10;
+        world.get_mut::<Health>(unrelated).unwrap().0 = 10;
+        world.run_schedule(Update);
+
+        let mut bands = world.resource::<Bands>().0.clone();
+        bands.sort();
+
+        assert_eq!(bands, vec![(None, 2), (None, 4), (Some(4), 0)]);
+    }
+
+    #[test]
+    fn react_to_children() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Bands>();
+
+        let party = world.spawn_empty().id();
+        let member = world
+            .spawn((Health(60), MaxHealth(100), ChildOf(party)))
+            .id();
+        let unrelated = world.spawn((Health(100), MaxHealth(100))).id();
+        world
+            .spawn((
+                MonitorChildren(party),
+                NotifyRelationChanged::<Health, MaxHealth, _>::by(|health, max| {
+                    health.0 * 4 / max.0
+                }),
+            ))
+            .observe(
+                |changed: On<RelationChanged<Health, MaxHealth, u32>>, mut bands: ResMut<Bands>| {
+                    bands.0.push((changed.previous, changed.current));
+                },
+            );
+
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(member).unwrap().0 = 10;
+        world.get_mut::<Health>(unrelated).unwrap().0 = 10;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Bands>().0, vec![(None, 2), (Some(2), 0)]);
+    }
+}
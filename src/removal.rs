@@ -1,53 +1,217 @@
-use crate::prelude::*;
-use bevy_ecs::{lifecycle::HookContext, prelude::*, world::DeferredWorld};
+use crate::{
+    change_id::ChangeIds,
+    dispatch::{Notifier, NotifyGate, component_ticks},
+    monitors::{Hierarchy, MonitorTargets},
+    payload::NotificationPayload,
+    prelude::*,
+    registry::{register_implicitly, register_teardown, unregister},
+    topology::register_watched,
+};
+use bevy_ecs::{
+    change_detection::{ComponentTicks, Tick},
+    entity::{EntityHashMap, EntityHashSet},
+    lifecycle::HookContext,
+    prelude::*,
+    system::SystemParam,
+    world::DeferredWorld,
+};
 use bevy_reflect::Reflect;
-use std::marker::PhantomData;
+use std::{marker::PhantomData, num::NonZeroU64};
 
-#[derive(Resource, Reflect, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Resource)]
+/// Contains the observers detecting removals of [`C`], and keeps track of the entities that are
+/// between the lifecycle events that tell the cause of a removal apart.
 struct DetectingRemoved<C: Component> {
-    observer: Entity,
-    _phantom: PhantomData<C>,
+    observers: [Entity; 4],
+    /// Entities being despawned, which [`C`] is about to be removed from.
+    despawned: EntityHashSet,
+    /// Entities [`C`] is about to be removed from or replaced on, along with its ticks and, if any
+    /// monitor captures it, its value.
+    replaced: EntityHashMap<(ComponentTicks, Option<C>)>,
+}
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+/// Why a component was removed from an entity, see [`Removal::cause`].
+pub enum RemovalCause {
+    /// The component was removed, the entity it belonged to still exists.
+    Removed,
+    /// The entity the component belonged to was despawned, so it can no longer be accessed.
+    Despawned,
+    /// The component was overwritten by inserting a new value, which the entity now has.
+    Replaced,
 }
 
-#[derive(EntityEvent)]
+#[derive(EntityEvent, Message, Reflect, Clone, Debug)]
 /// Indicates that the component [`C`] has been removed from an entity watched by a monitor.
 ///
 /// See [`NotifyRemoved<C>`] for more information on how this is triggered.
+///
+/// [`Removal::value`] and the [`MonitorData`] aren't visible through reflection, see
+/// [`AppNotifyExt::register_notification_types`] for registering this type.
 pub struct Removal<C: Component> {
     pub entity: Entity,
     /// The [`Entity`] that [`C`] was removed from.
     pub removed: Entity,
+    /// The change tick at which this was triggered.
+    pub tick: Tick,
+    /// The change this was caused by, shared by every monitor notified about it.
+    pub change: ChangeId,
+    /// The ticks at which [`C`] was added to, and last changed on, the watched entity.
+    ///
+    /// Comparing these with [`Removal::tick`] allows notifications buffered across frames to be
+    /// deduplicated, ordered, or dropped once stale.
+    pub ticks: ComponentTicks,
+    /// Whether [`C`] was removed, replaced, or removed because the entity was despawned.
+    ///
+    /// Observers cleaning up after [`C`] should check for [`RemovalCause::Despawned`] before
+    /// touching [`Removal::removed`], as it no longer exists by the time commands are applied.
+    pub cause: RemovalCause,
+    /// Everything the monitor opted into, which is boxed to keep this event small.
+    #[reflect(ignore)]
+    payload: Option<Box<NotificationPayload<C>>>,
+    /// See [`Removal::sequence`].
+    sequence: Option<NonZeroU64>,
+    #[reflect(ignore)]
     _phantom: PhantomData<C>,
 }
+impl<C: Component> Removal<C> {
+    /// The value [`C`] had when it was removed, this is `None` unless the monitor was created with
+    /// [`NotifyRemoved::with_value`].
+    pub fn value(&self) -> Option<&C> {
+        self.payload.as_ref()?.value.as_ref()
+    }
+    /// The position of this notification among those about [`C`], this is `None` unless the
+    /// [`SequencedNotifications<C>`] resource exists.
+    pub fn sequence(&self) -> Option<u64> {
+        self.sequence.map(NonZeroU64::get)
+    }
+    /// When this was triggered, this is `None` unless the [`TimestampedNotifications`] resource
+    /// exists.
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.payload.as_ref()?.timestamp
+    }
+    /// The [`MonitorData`] of the monitor, if it has any.
+    pub fn data(&self) -> Option<&MonitorData> {
+        self.payload.as_ref()?.data.as_ref()
+    }
+}
+
+/// Clones the value of [`C`] as it is added or removed.
+///
+/// Two captures are considered equal if they both clone or both don't, function pointers can't be
+/// meaningfully compared.
+pub(crate) struct Capture<C>(pub(crate) Option<fn(&C) -> C>);
+impl<C> Clone for Capture<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<C> Copy for Capture<C> {}
+impl<C> std::fmt::Debug for Capture<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Capture").field(&self.0.is_some()).finish()
+    }
+}
+impl<C> Default for Capture<C> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+impl<C> PartialEq for Capture<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.is_some() == other.0.is_some()
+    }
+}
+impl<C> Eq for Capture<C> {}
+impl<C> PartialOrd for Capture<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<C> Ord for Capture<C> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.is_some().cmp(&other.0.is_some())
+    }
+}
+impl<C> std::hash::Hash for Capture<C> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.is_some().hash(state);
+    }
+}
 
 #[derive(Component, Reflect, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 #[component(
     on_add = NotifyRemoved::<C>::register_component_remove_observer,
-    on_remove = NotifyRemoved::<C>::remove_component_remove_observer
+    on_remove = NotifyRemoved::<C>::remove_component_remove_observer,
+    clone_behavior = clone::<Self>()
 )]
 /// Adding this component to a entity will cause it to react to component [`C`] being removed from
 /// an entity with [`Removal<C>`]
 ///
 /// By default this will react to changes on **all** entities. See [`Monitor`], and [`MonitorSelf`]
 /// for restricting this.
-pub struct NotifyRemoved<C: Component>(PhantomData<C>);
+///
+/// If the removed value is needed, such as to refund the cost of a removed buff, create this with
+/// [`NotifyRemoved::with_value`]. By the time [`Removal<C>`] is observed [`C`] can no longer be
+/// queried.
+///
+/// Overwriting [`C`] by inserting a new value also counts as removing the old one, see
+/// [`RemovalCause`].
+pub struct NotifyRemoved<C: Component> {
+    #[reflect(ignore)]
+    capture: Capture<C>,
+    _phantom: PhantomData<C>,
+}
 impl<C: Component> Default for NotifyRemoved<C> {
     fn default() -> Self {
-        Self(PhantomData)
+        Self {
+            capture: Capture(None),
+            _phantom: PhantomData,
+        }
     }
 }
-impl<C: Component> NotifyRemoved<C> {
-    fn register_component_remove_observer(mut world: DeferredWorld, _: HookContext) {
-        if world.contains_resource::<DetectingRemoved<C>>() {
-            return;
+impl<C: Component> Clone for NotifyRemoved<C> {
+    fn clone(&self) -> Self {
+        Self {
+            capture: self.capture,
+            _phantom: PhantomData,
         }
-
-        let mut commands = world.commands();
-        let observer = commands.add_observer(notify_on_remove::<C>).id();
-        commands.insert_resource(DetectingRemoved::<C> {
-            observer,
+    }
+}
+impl<C: Component + Clone> NotifyRemoved<C> {
+    /// Includes the value [`C`] had when it was removed in [`Removal::value`].
+    pub fn with_value() -> Self {
+        Self {
+            capture: Capture(Some(C::clone)),
             _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Component> NotifyRemoved<C> {
+    fn register_component_remove_observer(mut world: DeferredWorld, _: HookContext) {
+        world
+            .commands()
+            .queue(|world: &mut World| register_implicitly::<Self>(world, Self::register));
+    }
+    /// Registers the observer detecting removals of [`C`], this is done by the first
+    /// monitor or by [`NotifyPlugin`].
+    ///
+    /// [`NotifyPlugin`]: crate::NotifyPlugin
+    pub(crate) fn register(world: &mut World) {
+        let observers = [
+            world.add_observer(notify_on_remove::<C>).id(),
+            world.add_observer(mark_despawned::<C>).id(),
+            world.add_observer(stash_replaced::<C>).id(),
+            world.add_observer(notify_on_replace::<C>).id(),
+        ];
+        world.insert_resource(DetectingRemoved::<C> {
+            observers,
+            despawned: EntityHashSet::default(),
+            replaced: EntityHashMap::default(),
         });
+        world.init_resource::<ChangeIds>();
+        register_teardown::<Self>(world, Self::teardown);
+        register_watched::<Self, C>(world, NotificationKind::Removed);
     }
     fn remove_component_remove_observer(mut world: DeferredWorld, _: HookContext) {
         // # Safety
@@ -61,56 +225,163 @@ impl<C: Component> NotifyRemoved<C> {
 
         if total_reactive == 0 {
             world.commands().queue(|world: &mut World| {
-                // # Safety
-                // In order for this component to be removed `NotifyAdded::register_component_add_observer` must have run which adds the `DetectingAdded` resource.
-                let DetectingRemoved { observer, .. } =
-                    world.remove_resource::<DetectingRemoved<C>>().unwrap();
-                world.entity_mut(observer).despawn();
+                unregister::<Self>(world, Self::teardown);
             });
         }
     }
+    /// Removes everything added by registering [`Self`].
+    pub(crate) fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `NotifyRemoved::<C>` adds the `DetectingRemoved` resource.
+        let DetectingRemoved { observers, .. } =
+            world.remove_resource::<DetectingRemoved<C>>().unwrap();
+        observers.into_iter().for_each(|observer| {
+            world.entity_mut(observer).despawn();
+        });
+    }
 }
 
-pub(crate) fn notify_on_remove<C: Component>(
+fn notify_on_remove<C: Component>(
     remove: On<Remove, C>,
-    mut commands: Commands,
-    local_monitors: Query<Entity, (With<NotifyRemoved<C>>, With<MonitorSelf>)>,
-    monitors: Query<(Entity, &Monitor), With<NotifyRemoved<C>>>,
-    global_monitors: Query<
-        Entity,
-        (
-            With<NotifyRemoved<C>>,
-            Without<Monitor>,
-            Without<MonitorSelf>,
-        ),
-    >,
+    mut detecting: ResMut<DetectingRemoved<C>>,
+    mut removals: RemovalDispatch<C>,
+    values: Query<Ref<C>>,
 ) {
-    if local_monitors.contains(remove.entity) {
-        commands.trigger(Removal::<C> {
-            entity: remove.entity,
-            removed: remove.entity,
-            _phantom: PhantomData,
-        });
-    }
+    // Replacing `C` also runs this, it's only a replacement if it isn't followed by a removal.
+    detecting.replaced.remove(&remove.entity);
+    let cause = match detecting.despawned.remove(&remove.entity) {
+        true => RemovalCause::Despawned,
+        false => RemovalCause::Removed,
+    };
+
+    let Ok(value) = values.get(remove.entity) else {
+        return;
+    };
+
+    removals.dispatch(remove.entity, cause, component_ticks(&value), Some(&value));
+}
+
+/// Despawning an entity runs this before [`C`] is removed from it.
+fn mark_despawned<C: Component>(
+    despawn: On<Despawn, C>,
+    mut detecting: ResMut<DetectingRemoved<C>>,
+) {
+    detecting.despawned.insert(despawn.entity);
+}
+
+/// Keeps the value being replaced, as it's overwritten by the time [`notify_on_replace`] runs.
+fn stash_replaced<C: Component>(
+    replace: On<Replace, C>,
+    mut detecting: ResMut<DetectingRemoved<C>>,
+    captures: Query<&NotifyRemoved<C>>,
+    values: Query<Ref<C>>,
+) {
+    let Ok(value) = values.get(replace.entity) else {
+        return;
+    };
 
-    monitors
+    let captured = captures
         .iter()
-        .filter(|(_, Monitor(entity))| *entity == remove.entity)
-        .for_each(|(entity, &Monitor(removed))| {
-            commands.trigger(Removal::<C> {
-                entity,
-                removed,
-                _phantom: PhantomData,
-            });
-        });
+        .find_map(|notify| notify.capture.0)
+        .map(|capture| capture(&value));
 
-    global_monitors.iter().for_each(|entity| {
-        commands.trigger(Removal::<C> {
-            entity,
-            removed: remove.entity,
-            _phantom: PhantomData,
+    detecting
+        .replaced
+        .insert(replace.entity, (component_ticks(&value), captured));
+}
+
+fn notify_on_replace<C: Component>(
+    insert: On<Insert, C>,
+    mut detecting: ResMut<DetectingRemoved<C>>,
+    mut removals: RemovalDispatch<C>,
+) {
+    let Some((ticks, value)) = detecting.replaced.remove(&insert.entity) else {
+        return;
+    };
+
+    removals.dispatch(insert.entity, RemovalCause::Replaced, ticks, value.as_ref());
+}
+
+#[derive(SystemParam)]
+/// Triggers [`Removal<C>`] on every monitor watching an entity.
+struct RemovalDispatch<'w, 's, C: Component> {
+    notifier: Notifier<'w, 's>,
+    gate: NotifyGate<'w, 's, C>,
+    suppressed: Option<Res<'w, SuppressedNotifications<C>>>,
+    deterministic: Option<Res<'w, DeterministicNotifications>>,
+    sequences: Option<ResMut<'w, SequencedNotifications<C>>>,
+    captures: Query<'w, 's, &'static NotifyRemoved<C>>,
+    monitors: Query<'w, 's, MonitorTargets, With<NotifyRemoved<C>>>,
+    hierarchy: Hierarchy<'w, 's>,
+}
+impl<C: Component> RemovalDispatch<'_, '_, C> {
+    fn dispatch(
+        &mut self,
+        removed: Entity,
+        cause: RemovalCause,
+        ticks: ComponentTicks,
+        value: Option<&C>,
+    ) {
+        if self.suppressed.is_some() {
+            return;
+        }
+
+        let mut monitors = self
+            .monitors
+            .iter()
+            .filter(|targets| targets.watches(removed, &self.hierarchy))
+            .map(|targets| targets.entity)
+            .collect::<Vec<_>>();
+
+        if self.deterministic.is_some() {
+            monitors.sort_unstable();
+        }
+
+        let change = self.notifier.next_change();
+
+        monitors.into_iter().for_each(|entity| {
+            if !self.gate.allows(entity, removed) {
+                self.notifier.drop(entity);
+                return;
+            }
+
+            let value = self
+                .captures
+                .get(entity)
+                .ok()
+                .and_then(|notify| notify.capture.0)
+                .zip(value)
+                .map(|(capture, value)| capture(value));
+
+            let tick = self.notifier.tick();
+            let data = self.notifier.data(entity);
+            let sequence = self
+                .sequences
+                .as_deref_mut()
+                .map(SequencedNotifications::next);
+            self.notifier.trigger_in_order(
+                Removal::<C> {
+                    entity,
+                    removed,
+                    tick,
+                    change,
+                    ticks,
+                    cause,
+                    payload: NotificationPayload {
+                        value,
+                        timestamp: self.notifier.timestamp(),
+                        data,
+                        ..Default::default()
+                    }
+                    .boxed(),
+                    sequence,
+                    _phantom: PhantomData,
+                },
+                removed,
+                NotificationKind::Removed,
+            );
         });
-    });
+    }
 }
 
 #[cfg(test)]
@@ -163,4 +434,67 @@ mod tests {
 
         assert!(world.resource::<HasPurse>().0);
     }
+
+    #[test]
+    fn check_removed_value() {
+        #[derive(Component, Clone)]
+        pub struct Buff {
+            cost: u32,
+        }
+
+        #[derive(Resource, Debug)]
+        pub struct Gold(u32);
+
+        let mut world = World::new();
+
+        world.insert_resource(Gold(0));
+
+        let player = world
+            .spawn((
+                Buff { cost: 25 },
+                MonitorSelf,
+                NotifyRemoved::<Buff>::with_value(),
+            ))
+            .observe(|removal: On<Removal<Buff>>, mut gold: ResMut<Gold>| {
+                if let Some(buff) = removal.value() {
+                    gold.0 += buff.cost;
+                }
+            })
+            .id();
+
+        world.entity_mut(player).remove::<Buff>();
+
+        assert_eq!(world.resource::<Gold>().0, 25);
+    }
+
+    #[test]
+    fn tell_removal_causes() {
+        #[derive(Resource, Default)]
+        pub struct Causes(Vec<RemovalCause>);
+
+        let mut world = World::new();
+
+        world.init_resource::<Causes>();
+        world.spawn(NotifyRemoved::<Purse>::default()).observe(
+            |removal: On<Removal<Purse>>, mut causes: ResMut<Causes>| {
+                causes.0.push(removal.cause);
+            },
+        );
+
+        let player = world.spawn((Player, Purse)).id();
+
+        world.entity_mut(player).remove::<Purse>();
+        world.entity_mut(player).insert(Purse);
+        world.entity_mut(player).insert(Purse);
+        world.entity_mut(player).despawn();
+
+        assert_eq!(
+            world.resource::<Causes>().0,
+            vec![
+                RemovalCause::Removed,
+                RemovalCause::Replaced,
+                RemovalCause::Despawned
+            ]
+        );
+    }
 }
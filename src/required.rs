@@ -0,0 +1,117 @@
+use crate::prelude::*;
+use bevy_app::App;
+use bevy_ecs::{
+    component::{Mutable, RequiredComponentsError},
+    prelude::*,
+};
+
+/// Registers a component's own monitoring setup through Bevy's required components, giving it "this
+/// type is always reactive" semantics.
+///
+/// Each method makes [`C`] require [`MonitorSelf`] and the matching `Notify*` component, so every
+/// entity spawned with [`C`] reacts to changes on itself without any per-spawn code.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # let mut app = App::new();
+/// app.require_notify_changed::<Health>();
+/// ```
+///
+/// # Panics
+///
+/// Like [`World::register_required_components`] this must be called before any entity with [`C`]
+/// has been spawned, otherwise it will panic.
+pub trait RequireNotify {
+    /// Make [`C`] require [`MonitorSelf`] and [`NotifyChanged<C>`].
+    fn require_notify_changed<C: Component<Mutability = Mutable>>(&mut self) -> &mut Self;
+    /// Make [`C`] require [`MonitorSelf`] and [`NotifyAdded<C>`].
+    fn require_notify_added<C: Component>(&mut self) -> &mut Self;
+    /// Make [`C`] require [`MonitorSelf`] and [`NotifyRemoved<C>`].
+    fn require_notify_removed<C: Component>(&mut self) -> &mut Self;
+}
+
+impl RequireNotify for World {
+    fn require_notify_changed<C: Component<Mutability = Mutable>>(&mut self) -> &mut Self {
+        require_self_monitoring::<C, NotifyChanged<C>>(self);
+        self
+    }
+    fn require_notify_added<C: Component>(&mut self) -> &mut Self {
+        require_self_monitoring::<C, NotifyAdded<C>>(self);
+        self
+    }
+    fn require_notify_removed<C: Component>(&mut self) -> &mut Self {
+        require_self_monitoring::<C, NotifyRemoved<C>>(self);
+        self
+    }
+}
+
+impl RequireNotify for App {
+    fn require_notify_changed<C: Component<Mutability = Mutable>>(&mut self) -> &mut Self {
+        self.world_mut().require_notify_changed::<C>();
+        self
+    }
+    fn require_notify_added<C: Component>(&mut self) -> &mut Self {
+        self.world_mut().require_notify_added::<C>();
+        self
+    }
+    fn require_notify_removed<C: Component>(&mut self) -> &mut Self {
+        self.world_mut().require_notify_removed::<C>();
+        self
+    }
+}
+
+fn require_self_monitoring<C: Component, N: Component + Default>(world: &mut World) {
+    // Requiring multiple kinds of notification will register `MonitorSelf` more than once.
+    match world.try_register_required_components::<C, MonitorSelf>() {
+        Ok(()) | Err(RequiredComponentsError::DuplicateRegistration(..)) => {}
+        Err(error) => panic!("{error}"),
+    }
+
+    world.register_required_components::<C, N>();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Poisoned;
+
+    #[derive(Resource, Default)]
+    pub struct TimesPoisoned(usize);
+
+    #[test]
+    fn always_reactive() {
+        let mut world = World::new();
+
+        world.init_resource::<TimesPoisoned>();
+
+        world
+            .require_notify_added::<Poisoned>()
+            .require_notify_removed::<Poisoned>();
+
+        let player = world
+            .spawn(Poisoned)
+            .observe(
+                |_: On<Addition<Poisoned>>, mut times_poisoned: ResMut<TimesPoisoned>| {
+                    times_poisoned.0 += 1;
+                },
+            )
+            .id();
+
+        assert!(world.entity(player).contains::<MonitorSelf>());
+        assert!(world.entity(player).contains::<NotifyAdded<Poisoned>>());
+        assert!(world.entity(player).contains::<NotifyRemoved<Poisoned>>());
+
+        world
+            .entity_mut(player)
+            .remove::<Poisoned>()
+            .insert(Poisoned);
+
+        assert_eq!(world.resource::<TimesPoisoned>().0, 1);
+    }
+}
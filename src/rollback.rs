@@ -0,0 +1,325 @@
+use bevy_ecs::{
+    change_detection::Tick,
+    entity::{EntityHashMap, EntityHashSet},
+    prelude::*,
+};
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+/// Identifies a checkpoint made by [`Rollback::checkpoint`].
+///
+/// Ids increase with every checkpoint and are never reused within a world, even for checkpoints
+/// of different components or after [`Rollback::untrack_rollback`].
+pub struct CheckpointId(pub u64);
+
+#[derive(Resource, Default)]
+/// Hands out the next [`CheckpointId`].
+struct CheckpointIds {
+    next: u64,
+}
+
+/// The values of [`C`] as they were when a checkpoint was made, for entities that have changed
+/// since.
+struct Checkpoint<C> {
+    id: CheckpointId,
+    /// `None` means [`C`] didn't exist on the entity.
+    previous: EntityHashMap<Option<C>>,
+}
+
+#[derive(Resource)]
+/// Keeps track of the values of [`C`] needed to roll back to a checkpoint.
+///
+/// See [`Rollback`] for how this is used.
+pub struct RollbackHistory<C: Component + Clone> {
+    known: EntityHashMap<C>,
+    checkpoints: Vec<Checkpoint<C>>,
+    last_sync: Option<Tick>,
+}
+impl<C: Component + Clone> Default for RollbackHistory<C> {
+    fn default() -> Self {
+        Self {
+            known: EntityHashMap::default(),
+            checkpoints: Vec::new(),
+            last_sync: None,
+        }
+    }
+}
+impl<C: Component + Clone> RollbackHistory<C> {
+    /// The ids of all checkpoints that can still be rolled back to, oldest first.
+    pub fn checkpoints(&self) -> impl Iterator<Item = CheckpointId> + '_ {
+        self.checkpoints.iter().map(|checkpoint| checkpoint.id)
+    }
+    /// The position of the checkpoint `id` in the history, if it can still be rolled back to.
+    fn position(&self, id: CheckpointId) -> Option<usize> {
+        self.checkpoints
+            .iter()
+            .position(|checkpoint| checkpoint.id == id)
+    }
+    fn record(&mut self, entity: Entity, previous: Option<C>) {
+        if let Some(checkpoint) = self.checkpoints.last_mut() {
+            checkpoint.previous.entry(entity).or_insert(previous);
+        }
+    }
+}
+
+/// Allows snapshotting the values of [`C`] and later restoring them, for things like rollback
+/// netcode.
+///
+/// Only values that actually changed after a checkpoint are stored, these are found using Bevy's
+/// change detection.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component, Clone)]
+/// # pub struct Health(pub u8);
+/// # let mut world = World::new();
+/// world.track_rollback::<Health>();
+///
+/// let confirmed = world.checkpoint::<Health>();
+///
+/// // Predict ahead.
+///
+/// world.rollback::<Health>(confirmed);
+/// ```
+pub trait Rollback {
+    /// Starts keeping track of the values of [`C`].
+    fn track_rollback<C: Component + Clone>(&mut self) -> &mut Self;
+    /// Stops keeping track of the values of [`C`], discarding all checkpoints.
+    fn untrack_rollback<C: Component + Clone>(&mut self) -> &mut Self;
+    /// Records the current values of [`C`], returning the id of the checkpoint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Rollback::track_rollback`] has not been called for [`C`].
+    fn checkpoint<C: Component + Clone>(&mut self) -> CheckpointId;
+    /// The values [`C`] had at the checkpoint `id` for every entity where it has changed since,
+    /// `None` meaning [`C`] didn't exist on the entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Rollback::track_rollback`] has not been called for [`C`].
+    fn changed_since<C: Component + Clone>(&mut self, id: CheckpointId)
+    -> Vec<(Entity, Option<C>)>;
+    /// Restores the values of [`C`] to what they were at the checkpoint `id`, discarding it and all
+    /// later checkpoints.
+    ///
+    /// Returns `false` if there is no checkpoint `id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Rollback::track_rollback`] has not been called for [`C`].
+    fn rollback<C: Component + Clone>(&mut self, id: CheckpointId) -> bool;
+}
+
+impl Rollback for World {
+    fn track_rollback<C: Component + Clone>(&mut self) -> &mut Self {
+        if !self.contains_resource::<RollbackHistory<C>>() {
+            self.init_resource::<RollbackHistory<C>>();
+            sync_history::<C>(self);
+        }
+        self
+    }
+    fn untrack_rollback<C: Component + Clone>(&mut self) -> &mut Self {
+        self.remove_resource::<RollbackHistory<C>>();
+        self
+    }
+    fn checkpoint<C: Component + Clone>(&mut self) -> CheckpointId {
+        sync_history::<C>(self);
+
+        let mut ids = self.get_resource_or_init::<CheckpointIds>();
+        ids.next += 1;
+        let id = CheckpointId(ids.next);
+
+        self.resource_mut::<RollbackHistory<C>>()
+            .checkpoints
+            .push(Checkpoint {
+                id,
+                previous: EntityHashMap::default(),
+            });
+        id
+    }
+    fn changed_since<C: Component + Clone>(
+        &mut self,
+        id: CheckpointId,
+    ) -> Vec<(Entity, Option<C>)> {
+        sync_history::<C>(self);
+
+        let history = self.resource::<RollbackHistory<C>>();
+        let Some(index) = history.position(id) else {
+            return Vec::new();
+        };
+
+        let mut changed = EntityHashMap::default();
+        history.checkpoints[index..]
+            .iter()
+            .rev()
+            .flat_map(|checkpoint| checkpoint.previous.iter())
+            .for_each(|(&entity, previous)| {
+                changed.insert(entity, previous.clone());
+            });
+        changed.into_iter().collect()
+    }
+    fn rollback<C: Component + Clone>(&mut self, id: CheckpointId) -> bool {
+        sync_history::<C>(self);
+
+        let rolled_back = self.resource_scope(|world, mut history: Mut<RollbackHistory<C>>| {
+            let Some(index) = history.position(id) else {
+                return false;
+            };
+
+            let mut restored = EntityHashMap::default();
+            history
+                .checkpoints
+                .drain(index..)
+                .rev()
+                .flat_map(|checkpoint| checkpoint.previous)
+                .for_each(|(entity, previous)| {
+                    restored.insert(entity, previous);
+                });
+
+            restored.into_iter().for_each(|(entity, previous)| {
+                let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+                    history.known.remove(&entity);
+                    return;
+                };
+
+                match previous {
+                    Some(previous) => {
+                        entity_mut.insert(previous.clone());
+                        history.known.insert(entity, previous);
+                    }
+                    None => {
+                        entity_mut.remove::<C>();
+                        history.known.remove(&entity);
+                    }
+                }
+            });
+
+            // The restored values shouldn't count as changes.
+            history.last_sync = Some(world.change_tick());
+            true
+        });
+        self.increment_change_tick();
+
+        rolled_back
+    }
+}
+
+/// Brings [`RollbackHistory<C>`] up to date with all changes made to [`C`] since it was last synced.
+fn sync_history<C: Component + Clone>(world: &mut World) {
+    world.resource_scope(|world, mut history: Mut<RollbackHistory<C>>| {
+        let this_run = world.change_tick();
+        let last_sync = history.last_sync;
+        let mut seen = EntityHashSet::default();
+
+        world
+            .query::<(Entity, Ref<C>)>()
+            .iter(world)
+            .for_each(|(entity, value)| {
+                seen.insert(entity);
+
+                if last_sync.is_some_and(|last_sync| {
+                    !value.last_changed().is_newer_than(last_sync, this_run)
+                }) {
+                    return;
+                }
+
+                let previous = history.known.insert(entity, value.clone());
+                history.record(entity, previous);
+            });
+
+        let removed = history
+            .known
+            .keys()
+            .filter(|entity| !seen.contains(*entity))
+            .copied()
+            .collect::<Vec<_>>();
+
+        removed.into_iter().for_each(|entity| {
+            let previous = history.known.remove(&entity);
+            history.record(entity, previous);
+        });
+
+        history.last_sync = Some(this_run);
+    });
+    world.increment_change_tick();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    pub struct Health(u8);
+
+    #[test]
+    fn restore_checkpoint() {
+        let mut world = World::new();
+
+        let player = world.spawn(Health(100)).id();
+        let enemy = world.spawn(Health(50)).id();
+        let bystander = world.spawn(Health(10)).id();
+
+        world.track_rollback::<Health>();
+
+        let confirmed = world.checkpoint::<Health>();
+
+        world.get_mut::<Health>(player).unwrap().0 = 80;
+        world.entity_mut(enemy).remove::<Health>();
+        let ally = world.spawn(Health(30)).id();
+
+        let predicted = world.checkpoint::<Health>();
+
+        world.get_mut::<Health>(player).unwrap().0 = 60;
+
+        let mut changed = world.changed_since::<Health>(confirmed);
+        changed.sort_by_key(|(entity, _)| *entity);
+
+        let mut expected = vec![
+            (player, Some(Health(100))),
+            (enemy, Some(Health(50))),
+            (ally, None),
+        ];
+        expected.sort_by_key(|(entity, _)| *entity);
+
+        assert_eq!(changed, expected);
+
+        assert!(world.rollback::<Health>(confirmed));
+
+        assert_eq!(world.get::<Health>(player), Some(&Health(100)));
+        assert_eq!(world.get::<Health>(enemy), Some(&Health(50)));
+        assert_eq!(world.get::<Health>(bystander), Some(&Health(10)));
+        assert_eq!(world.get::<Health>(ally), None);
+
+        // Later checkpoints are discarded.
+
+        assert!(!world.rollback::<Health>(predicted));
+    }
+
+    #[test]
+    fn never_reuse_checkpoint_ids() {
+        let mut world = World::new();
+
+        let player = world.spawn(Health(100)).id();
+
+        world.track_rollback::<Health>();
+
+        let forgotten = world.checkpoint::<Health>();
+
+        world.untrack_rollback::<Health>();
+        world.track_rollback::<Health>();
+
+        let confirmed = world.checkpoint::<Health>();
+
+        world.get_mut::<Health>(player).unwrap().0 = 80;
+
+        assert_ne!(forgotten, confirmed);
+        assert!(!world.rollback::<Health>(forgotten));
+        assert_eq!(world.get::<Health>(player), Some(&Health(80)));
+
+        assert!(world.rollback::<Health>(confirmed));
+        assert_eq!(world.get::<Health>(player), Some(&Health(100)));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::prelude::*;
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypePath;
+use std::{collections::HashMap, sync::Arc};
+
+/// A callback registered by a scripting layer, see [`ScriptNotifications`].
+pub type ScriptCallback = Arc<dyn Fn(&mut World, &ScriptNotification) + Send + Sync>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// A notification forwarded to script callbacks.
+pub struct ScriptNotification {
+    pub kind: NotificationKind,
+    /// The monitor that received the notification.
+    pub monitor: Entity,
+    /// The entity the component belongs to.
+    pub watched: Entity,
+    /// The type path of the component.
+    pub component: &'static str,
+}
+
+#[derive(Resource, Default)]
+/// Contains the callbacks registered for each component, keyed by the type path of the component.
+struct ScriptCallbacks(HashMap<String, Vec<ScriptCallback>>);
+
+/// Allows scripting layers such as Lua or Rhai bindings to subscribe to notifications by the type
+/// path of a component, rather than through a parallel mechanism.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component, Reflect)]
+/// # pub struct Health(pub u8);
+/// # let mut app = App::new();
+/// app.forward_to_scripts::<Health>()
+///     .register_script_callback(Health::type_path(), |_world, notification| {
+///         // Call into the script engine.
+///         println!("{:?} on {}", notification.kind, notification.watched);
+///     });
+/// ```
+pub trait ScriptNotifications {
+    /// Forwards every [`Addition<C>`], [`Mutation<C>`], and [`Removal<C>`] triggered in this world
+    /// to the callbacks registered for the type path of [`C`].
+    ///
+    /// Only the notifications themselves are forwarded, monitors still need to be set up as usual.
+    fn forward_to_scripts<C: Component + TypePath>(&mut self) -> &mut Self;
+    /// Registers `callback` to be called for every forwarded notification about the component
+    /// with the type path `component`.
+    fn register_script_callback(
+        &mut self,
+        component: impl Into<String>,
+        callback: impl Fn(&mut World, &ScriptNotification) + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl ScriptNotifications for World {
+    fn forward_to_scripts<C: Component + TypePath>(&mut self) -> &mut Self {
+        self.init_resource::<ScriptCallbacks>();
+
+        self.add_observer(|addition: On<Addition<C>>, mut commands: Commands| {
+            forward::<C>(
+                &mut commands,
+                NotificationKind::Added,
+                addition.entity,
+                addition.added,
+            );
+        });
+        self.add_observer(|mutation: On<Mutation<C>>, mut commands: Commands| {
+            forward::<C>(
+                &mut commands,
+                NotificationKind::Changed,
+                mutation.entity,
+                mutation.mutated,
+            );
+        });
+        self.add_observer(|removal: On<Removal<C>>, mut commands: Commands| {
+            forward::<C>(
+                &mut commands,
+                NotificationKind::Removed,
+                removal.entity,
+                removal.removed,
+            );
+        });
+        self
+    }
+    fn register_script_callback(
+        &mut self,
+        component: impl Into<String>,
+        callback: impl Fn(&mut World, &ScriptNotification) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.get_resource_or_init::<ScriptCallbacks>()
+            .0
+            .entry(component.into())
+            .or_default()
+            .push(Arc::new(callback));
+        self
+    }
+}
+
+impl ScriptNotifications for App {
+    fn forward_to_scripts<C: Component + TypePath>(&mut self) -> &mut Self {
+        self.world_mut().forward_to_scripts::<C>();
+        self
+    }
+    fn register_script_callback(
+        &mut self,
+        component: impl Into<String>,
+        callback: impl Fn(&mut World, &ScriptNotification) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.world_mut()
+            .register_script_callback(component, callback);
+        self
+    }
+}
+
+fn forward<C: Component + TypePath>(
+    commands: &mut Commands,
+    kind: NotificationKind,
+    monitor: Entity,
+    watched: Entity,
+) {
+    let notification = ScriptNotification {
+        kind,
+        monitor,
+        watched,
+        component: C::type_path(),
+    };
+
+    commands.queue(move |world: &mut World| {
+        // Cloned out so callbacks are free to register more callbacks.
+        let callbacks = world
+            .get_resource::<ScriptCallbacks>()
+            .and_then(|callbacks| callbacks.0.get(notification.component))
+            .cloned()
+            .unwrap_or_default();
+
+        callbacks
+            .iter()
+            .for_each(|callback| callback(world, &notification));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Reflect)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct ScriptLog(Vec<(NotificationKind, Entity)>);
+
+    #[test]
+    fn forward_to_script() {
+        let mut world = World::new();
+
+        world.init_resource::<ScriptLog>();
+        world
+            .forward_to_scripts::<Health>()
+            .register_script_callback(Health::type_path(), |world, notification| {
+                world
+                    .resource_mut::<ScriptLog>()
+                    .0
+                    .push((notification.kind, notification.watched));
+            });
+
+        let player = world.spawn_empty().id();
+        world.spawn((
+            Monitor(player),
+            NotifyAdded::<Health>::default(),
+            NotifyRemoved::<Health>::default(),
+        ));
+
+        world.entity_mut(player).insert(Health(100));
+        world.entity_mut(player).remove::<Health>();
+
+        assert_eq!(
+            world.resource::<ScriptLog>().0,
+            vec![
+                (NotificationKind::Added, player),
+                (NotificationKind::Removed, player),
+            ]
+        );
+    }
+}
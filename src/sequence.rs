@@ -0,0 +1,116 @@
+use bevy_ecs::prelude::*;
+use std::{marker::PhantomData, num::NonZeroU64};
+
+#[derive(Resource, Debug)]
+/// While this resource exists every [`Mutation<C>`], [`Addition<C>`], and [`Removal<C>`] carries a
+/// sequence number, see [`Mutation::sequence`].
+///
+/// Numbers start at one and increase by one with every notification about [`C`], whichever
+/// monitor it is for. Consumers that buffer or forward notifications, such as over the network,
+/// can use them to restore the original order and to detect gaps.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # let mut app = App::new();
+/// app.init_resource::<SequencedNotifications<Health>>()
+///     .add_observer(|mutation: On<Mutation<Health>>| {
+///         println!("Mutation number {:?}", mutation.sequence());
+///     });
+/// ```
+///
+/// Notifications are numbered as they are created, so ones dropped before being delivered, such
+/// as for a [`MonitorPaused`] monitor, show up as gaps too.
+///
+/// [`Mutation<C>`]: crate::prelude::Mutation
+/// [`Mutation::sequence`]: crate::prelude::Mutation::sequence
+/// [`Addition<C>`]: crate::prelude::Addition
+/// [`Removal<C>`]: crate::prelude::Removal
+/// [`MonitorPaused`]: crate::prelude::MonitorPaused
+pub struct SequencedNotifications<C: Component> {
+    last: u64,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component> Default for SequencedNotifications<C> {
+    fn default() -> Self {
+        Self {
+            last: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Component> SequencedNotifications<C> {
+    /// The sequence number of the most recent notification, or zero if there hasn't been one.
+    pub fn last(&self) -> u64 {
+        self.last
+    }
+    /// Hands out the next sequence number.
+    pub(crate) fn next(&mut self) -> NonZeroU64 {
+        self.last += 1;
+        // # Safety
+        // `last` was just incremented from at least zero.
+        NonZeroU64::new(self.last).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Sequences(Vec<Option<u64>>);
+
+    #[test]
+    fn number_every_notification() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Sequences>();
+        world.init_resource::<SequencedNotifications<Health>>();
+
+        let player = world.spawn_empty().id();
+        world
+            .spawn((
+                Monitor(player),
+                NotifyAdded::<Health>::default(),
+                NotifyChanged::<Health>::default(),
+                NotifyRemoved::<Health>::default(),
+            ))
+            .observe(
+                |addition: On<Addition<Health>>, mut sequences: ResMut<Sequences>| {
+                    sequences.0.push(addition.sequence());
+                },
+            )
+            .observe(
+                |mutation: On<Mutation<Health>>, mut sequences: ResMut<Sequences>| {
+                    sequences.0.push(mutation.sequence());
+                },
+            )
+            .observe(
+                |removal: On<Removal<Health>>, mut sequences: ResMut<Sequences>| {
+                    sequences.0.push(removal.sequence());
+                },
+            );
+        world.run_schedule(Update);
+
+        world.entity_mut(player).insert(Health(100));
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        world.entity_mut(player).remove::<Health>();
+
+        assert_eq!(
+            world.resource::<Sequences>().0,
+            vec![Some(1), Some(2), Some(3), Some(4)]
+        );
+        assert_eq!(world.resource::<SequencedNotifications<Health>>().last(), 4);
+    }
+}
@@ -0,0 +1,208 @@
+use bevy_app::{First, FixedMainScheduleOrder, MainScheduleOrder, Update};
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+};
+
+#[derive(Resource, Clone, Debug)]
+/// Controls crate wide behaviour of monitors.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # let mut app = App::new();
+/// app.insert_resource(NotifySettings {
+///     added_as_changed: false,
+///     max_trigger_depth: Some(8),
+///     ..default()
+/// });
+/// ```
+///
+/// This should be inserted before any monitors are spawned, as components are only registered
+/// once.
+pub struct NotifySettings {
+    /// The schedule changes are detected in for components registered by their first monitor.
+    ///
+    /// See [`AppNotifyExt::add_notify_changed_in`] for choosing the schedule of a single
+    /// component.
+    ///
+    /// [`AppNotifyExt::add_notify_changed_in`]: crate::AppNotifyExt::add_notify_changed_in
+    pub schedule: InternedScheduleLabel,
+    /// Whether adding a component counts as changing it, triggering [`Mutation<C>`] as well as
+    /// [`Addition<C>`].
+    ///
+    /// [`Mutation<C>`]: crate::prelude::Mutation
+    /// [`Addition<C>`]: crate::prelude::Addition
+    pub added_as_changed: bool,
+    /// How deeply notifications may be nested, such as an observer changing a component which
+    /// triggers another notification while the first is being delivered. Notifications past this
+    /// depth are dropped.
+    ///
+    /// This protects against monitors that react to each other forever. While set, commands queued
+    /// by observers are applied as part of the notification that caused them, rather than after
+    /// every queued notification has been delivered.
+    pub max_trigger_depth: Option<usize>,
+    /// Whether to log every notification as it is delivered, requiring the `log` feature.
+    pub log_dispatches: bool,
+}
+impl Default for NotifySettings {
+    fn default() -> Self {
+        Self {
+            schedule: Update.intern(),
+            added_as_changed: true,
+            max_trigger_depth: None,
+            log_dispatches: false,
+        }
+    }
+}
+
+#[derive(ScheduleLabel, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+/// The schedule changes are detected in when the world has no [`Update`] schedule, such as a bare
+/// [`World`] used by headless tools or tests. This is run by [`run_notify_systems`].
+///
+/// Inserting [`NotifySettings`] always takes priority over this.
+pub struct NotifySchedule;
+
+/// The schedule changes to components are detected in when they are registered by their first
+/// monitor.
+pub(crate) fn detection_schedule(world: &World) -> InternedScheduleLabel {
+    if let Some(settings) = world.get_resource::<NotifySettings>() {
+        return settings.schedule;
+    }
+
+    // The resources and schedules of an `App` are taken out of the world while they run, so
+    // several are checked to tell whether this world belongs to one.
+    let has_update = world.contains_resource::<MainScheduleOrder>()
+        || world.contains_resource::<FixedMainScheduleOrder>()
+        || world
+            .get_resource::<Schedules>()
+            .is_some_and(|schedules| schedules.contains(Update) || schedules.contains(First));
+
+    if has_update {
+        Update.intern()
+    } else {
+        NotifySchedule.intern()
+    }
+}
+
+/// Detects changes to watched components in a world without an [`Update`] schedule, triggering
+/// any notifications caused by them.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// let mut world = World::new();
+/// let player = world.spawn(Health(100)).id();
+///
+/// world.spawn((Monitor(player), NotifyChanged::<Health>::default()));
+/// world.flush();
+///
+/// run_notify_systems(&mut world);
+/// ```
+///
+/// Worlds inside of an [`App`](bevy_app::App), including those using `MinimalPlugins`, detect
+/// changes in [`Update`] and don't need this.
+pub fn run_notify_systems(world: &mut World) {
+    // Nothing has been registered in the schedule yet.
+    let _ = world.try_run_schedule(NotifySchedule);
+}
+
+#[derive(Resource, Default)]
+/// How many notifications are currently being delivered within one another.
+pub(crate) struct TriggerDepth(pub(crate) usize);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Ping;
+
+    #[derive(Component)]
+    pub struct Pong;
+
+    #[derive(Resource, Default)]
+    pub struct Rallies(usize);
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[test]
+    fn limit_trigger_depth() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Rallies>();
+        world.insert_resource(NotifySettings {
+            added_as_changed: false,
+            max_trigger_depth: Some(3),
+            ..default()
+        });
+
+        let ball = world.spawn_empty().id();
+        // Each monitor re-adds the component the other removes, forever.
+        world
+            .spawn((Monitor(ball), NotifyRemoved::<Ping>::default()))
+            .observe(
+                move |_: On<Removal<Ping>>,
+                      mut commands: Commands,
+                      mut rallies: ResMut<Rallies>| {
+                    rallies.0 += 1;
+                    commands.entity(ball).insert(Pong).remove::<Pong>();
+                },
+            );
+        world
+            .spawn((Monitor(ball), NotifyRemoved::<Pong>::default()))
+            .observe(
+                move |_: On<Removal<Pong>>,
+                      mut commands: Commands,
+                      mut rallies: ResMut<Rallies>| {
+                    rallies.0 += 1;
+                    commands.entity(ball).insert(Ping).remove::<Ping>();
+                },
+            );
+        world
+            .spawn((Monitor(ball), NotifyChanged::<Ping>::default()))
+            .observe(|_: On<Mutation<Ping>>, mut rallies: ResMut<Rallies>| {
+                rallies.0 += 1;
+            });
+        world.flush();
+
+        world.entity_mut(ball).insert(Ping).remove::<Ping>();
+        world.flush();
+
+        assert_eq!(world.resource::<Rallies>().0, 3);
+
+        // Adding `Ping` doesn't count as changing it.
+        world.entity_mut(ball).insert(Ping);
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Rallies>().0, 3);
+    }
+
+    #[test]
+    fn detect_without_schedules() {
+        let mut world = World::new();
+
+        world.init_resource::<Rallies>();
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(|_: On<Mutation<Health>>, mut rallies: ResMut<Rallies>| {
+                rallies.0 += 1;
+            });
+        world.flush();
+
+        assert!(!world.resource::<Schedules>().contains(Update));
+
+        run_notify_systems(&mut world);
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        run_notify_systems(&mut world);
+
+        assert_eq!(world.resource::<Rallies>().0, 2);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::registry::{add_systems, register_once, register_teardown, unregister};
+use bevy_app::Last;
+use bevy_ecs::{
+    lifecycle::HookContext, prelude::*, schedule::ScheduleCleanupPolicy, world::DeferredWorld,
+};
+use bevy_reflect::Reflect;
+
+#[derive(EntityEvent, Clone, Copy, PartialEq, Eq, Debug)]
+/// Indicates that a monitor with [`NotifySettled`] received notifications this frame, and that
+/// nothing else will change before the frame ends.
+pub struct Settled {
+    pub entity: Entity,
+}
+
+#[derive(Component, Reflect, Clone, Hash, PartialEq, Eq, Default, Debug)]
+#[component(
+    on_add = NotifySettled::register_settle_system,
+    on_remove = NotifySettled::remove_settle_system
+)]
+/// Adding this component to a monitor will trigger [`Settled`] on it at the end of every frame in
+/// which it received any notifications.
+///
+/// This is useful for once-per-burst logic such as a final recompute, which would otherwise run on
+/// every intermediate notification of a cascade of changes.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # #[derive(Component)]
+/// # pub struct Armor(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands
+///     .spawn((
+///         Monitor(player),
+///         NotifyChanged::<Health>::default(),
+///         NotifyChanged::<Armor>::default(),
+///         NotifySettled::default(),
+///     ))
+///     .observe(|_: On<Settled>| {
+///         // Recompute the effective health.
+///     });
+/// # }
+/// ```
+///
+/// Notifications that were dropped, such as by [`MonitorPaused`], don't count.
+///
+/// # Technical info
+///
+/// Adding this component to an entity will add a system to the [`Last`] schedule, this is only done
+/// once.
+///
+/// When all instances of this component in the world are removed the system will be removed.
+///
+/// [`MonitorPaused`]: crate::prelude::MonitorPaused
+pub struct NotifySettled {
+    pending: bool,
+}
+impl NotifySettled {
+    /// Records that the monitor received a notification this frame.
+    pub(crate) fn unsettle(&mut self) {
+        self.pending = true;
+    }
+    fn register_settle_system(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                add_systems(world, Last, trigger_settled);
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_settle_system(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_settling = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_settling == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        world.schedule_scope(Last, |world, schedule| {
+            // # Safety
+            // `NotifySettled` being registered ensures this system exists in the
+            // `Last` schedule.
+            schedule
+                .remove_systems_in_set(
+                    trigger_settled,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+    }
+}
+
+fn trigger_settled(mut commands: Commands, mut monitors: Query<(Entity, &mut NotifySettled)>) {
+    monitors.iter_mut().for_each(|(entity, mut settled)| {
+        if std::mem::take(&mut settled.pending) {
+            commands.trigger(Settled { entity });
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Component)]
+    pub struct Armor(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Recomputes(usize);
+
+    #[test]
+    fn settle_once_per_frame() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.add_schedule(Schedule::new(Last));
+        world.init_resource::<Recomputes>();
+
+        let player = world.spawn((Health(100), Armor(10))).id();
+        world
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                NotifyChanged::<Armor>::default(),
+                NotifySettled::default(),
+            ))
+            .observe(|_: On<Settled>, mut recomputes: ResMut<Recomputes>| {
+                recomputes.0 += 1;
+            });
+
+        world.run_schedule(Update);
+        world.run_schedule(Last);
+
+        assert_eq!(world.resource::<Recomputes>().0, 1);
+
+        // Nothing changed.
+        world.run_schedule(Update);
+        world.run_schedule(Last);
+
+        assert_eq!(world.resource::<Recomputes>().0, 1);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.get_mut::<Armor>(player).unwrap().0 -= 1;
+        world.run_schedule(Update);
+        world.run_schedule(Last);
+
+        assert_eq!(world.resource::<Recomputes>().0, 2);
+    }
+}
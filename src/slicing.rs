@@ -0,0 +1,173 @@
+use crate::registry::{add_systems, register_once};
+use bevy_app::{App, First};
+use bevy_ecs::prelude::*;
+use std::{collections::VecDeque, num::NonZeroUsize};
+
+/// Triggers a notification that was held back for a later frame.
+type Delivery = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+#[derive(Resource)]
+/// The number of notifications that can be delivered each frame before the rest are held back for
+/// the following frames, inserted by [`SliceNotifications::slice_notifications_over`].
+///
+/// Unlike [`NotificationBudget`], which sheds notifications, held back notifications are never
+/// lost and are delivered in the order they were triggered.
+///
+/// [`NotificationBudget`]: crate::prelude::NotificationBudget
+pub struct NotificationSlice {
+    /// The number of notifications delivered each frame, which can't be 0 as the backlog would
+    /// never be drained.
+    pub per_frame: NonZeroUsize,
+    delivered: usize,
+    backlog: VecDeque<Delivery>,
+}
+impl NotificationSlice {
+    /// The number of notifications delivered so far this frame.
+    pub fn delivered(&self) -> usize {
+        self.delivered
+    }
+    /// The number of notifications waiting to be delivered in later frames.
+    pub fn backlog(&self) -> usize {
+        self.backlog.len()
+    }
+    /// Counts a notification, returning `deliver` if it can be delivered this frame or holding it
+    /// back otherwise.
+    ///
+    /// Once anything is held back every following notification is too, so the order they were
+    /// triggered in is kept.
+    pub(crate) fn admit<D: FnOnce(&mut World) + Send + Sync + 'static>(
+        &mut self,
+        deliver: D,
+    ) -> Option<D> {
+        if self.backlog.is_empty() && self.delivered < self.per_frame.get() {
+            self.delivered += 1;
+            return Some(deliver);
+        }
+
+        self.backlog.push_back(Box::new(deliver));
+        None
+    }
+}
+
+/// Allows spreading bursts of notifications, such as those from loading thousands of entities at
+/// once, across several frames rather than stalling a single one.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::num::NonZeroUsize;
+/// # let mut app = App::new();
+/// app.slice_notifications_over(NonZeroUsize::new(1000).unwrap());
+/// ```
+///
+/// [`NotifyPriority::Critical`] notifications, and those of monitors with [`DeliverIn`], are never
+/// held back.
+///
+/// # Technical info
+///
+/// This adds a system to the [`First`] schedule that delivers held back notifications, this is
+/// only done once.
+///
+/// [`NotifyPriority::Critical`]: crate::prelude::NotifyPriority::Critical
+/// [`DeliverIn`]: crate::prelude::DeliverIn
+pub trait SliceNotifications {
+    /// Holds back notifications once more than `per_frame` have been delivered in a frame,
+    /// delivering them in the following frames.
+    fn slice_notifications_over(&mut self, per_frame: NonZeroUsize) -> &mut Self;
+}
+
+impl SliceNotifications for World {
+    fn slice_notifications_over(&mut self, per_frame: NonZeroUsize) -> &mut Self {
+        register_once::<NotificationSlice>(self, |world| {
+            add_systems(world, First, deliver_backlog);
+        });
+
+        let backlog = self
+            .remove_resource::<NotificationSlice>()
+            .map(|slice| slice.backlog)
+            .unwrap_or_default();
+
+        self.insert_resource(NotificationSlice {
+            per_frame,
+            delivered: 0,
+            backlog,
+        });
+        self
+    }
+}
+
+impl SliceNotifications for App {
+    fn slice_notifications_over(&mut self, per_frame: NonZeroUsize) -> &mut Self {
+        self.world_mut().slice_notifications_over(per_frame);
+        self
+    }
+}
+
+fn deliver_backlog(world: &mut World) {
+    let Some(mut slice) = world.get_resource_mut::<NotificationSlice>() else {
+        return;
+    };
+
+    let count = slice.per_frame.get().min(slice.backlog.len());
+    let deliveries = slice.backlog.drain(..count).collect::<Vec<_>>();
+    slice.delivered = count;
+
+    deliveries.into_iter().for_each(|delivery| delivery(world));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+    use std::num::NonZeroUsize;
+
+    #[derive(Component)]
+    pub struct Health;
+
+    #[derive(Resource, Default)]
+    pub struct Delivered(usize);
+
+    #[test]
+    fn spread_burst_over_frames() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(First));
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Delivered>();
+        world.slice_notifications_over(NonZeroUsize::new(2).unwrap());
+
+        let player = world.spawn(Health).id();
+        for _ in 0..4 {
+            world
+                .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+                .observe(
+                    |_: On<Mutation<Health>>, mut delivered: ResMut<Delivered>| {
+                        delivered.0 += 1;
+                    },
+                );
+        }
+        world
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                NotifyPriority::Critical,
+            ))
+            .observe(
+                |_: On<Mutation<Health>>, mut delivered: ResMut<Delivered>| {
+                    delivered.0 += 1;
+                },
+            );
+
+        world.run_schedule(First);
+        world.run_schedule(Update);
+
+        // The critical notification isn't counted against the slice.
+        assert_eq!(world.resource::<Delivered>().0, 3);
+        assert_eq!(world.resource::<NotificationSlice>().backlog(), 2);
+
+        world.run_schedule(First);
+
+        assert_eq!(world.resource::<Delivered>().0, 5);
+        assert_eq!(world.resource::<NotificationSlice>().backlog(), 0);
+    }
+}
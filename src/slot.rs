@@ -0,0 +1,145 @@
+use crate::prelude::*;
+use bevy_ecs::{event::SetEntityEventTarget, prelude::*, system::SystemParam};
+use bevy_reflect::Reflect;
+
+#[derive(Component, Reflect, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+/// Adding this component to a monitor will deliver its [`Addition<C>`], [`Mutation<C>`], and
+/// [`Removal<C>`] to the [`Slot`] of the entity the notification is about, rather than to the
+/// monitor itself.
+///
+/// This allows a single monitor watching a container through [`MonitorChildren`] to still drive
+/// per-item observers, such as one for every row of a list UI. The notification's `entity` is the
+/// slot, everything else about it is unchanged. Notifications about entities without a [`Slot`]
+/// are delivered to the monitor as usual.
+///
+/// Monitor wide behaviour, such as [`MonitorPaused`] or [`MonitorStats`], still applies to the
+/// monitor rather than to the slots.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Item(pub u8);
+/// # fn showcase(mut commands: Commands, inventory: Entity, item: Entity) {
+/// commands.spawn((
+///     MonitorChildren(inventory),
+///     NotifyChanged::<Item>::default(),
+///     TargetSlots,
+/// ));
+///
+/// commands
+///     .spawn(SlotOf(item))
+///     .observe(|mutation: On<Mutation<Item>>| {
+///         println!("The row showing {} needs updating", mutation.mutated);
+///     });
+/// # }
+/// ```
+///
+/// [`Addition<C>`]: crate::prelude::Addition
+/// [`Mutation<C>`]: crate::prelude::Mutation
+/// [`Removal<C>`]: crate::prelude::Removal
+pub struct TargetSlots;
+
+#[derive(Component, Reflect, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[relationship(relationship_target = Slot)]
+/// Placed on a slot entity, such as the row of a list UI, containing the watched entity it stands
+/// for.
+///
+/// See [`TargetSlots`] for how notifications are delivered to slots.
+pub struct SlotOf(pub Entity);
+
+#[derive(Component, Reflect, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[relationship_target(relationship = SlotOf)]
+/// Contains the slot standing for this entity, see [`SlotOf`].
+pub struct Slot(Entity);
+impl Slot {
+    /// The slot standing for this entity.
+    pub fn get(&self) -> Entity {
+        self.0
+    }
+}
+
+#[derive(SystemParam)]
+/// Looks up where monitors with [`TargetSlots`] deliver their notifications.
+pub(crate) struct Slots<'w, 's> {
+    slots: Query<'w, 's, &'static Slot>,
+    targeting: Query<'w, 's, (), With<TargetSlots>>,
+}
+impl Slots<'_, '_> {
+    /// The slot a notification for `monitor` about `watched` is delivered to, if it has one.
+    pub(crate) fn slot(&self, monitor: Entity, watched: Entity) -> Option<Entity> {
+        if !self.targeting.contains(monitor) {
+            return None;
+        }
+
+        self.slots.get(watched).ok().map(Slot::get)
+    }
+}
+
+impl<C: Component> SetEntityEventTarget for Addition<C> {
+    fn set_event_target(&mut self, entity: Entity) {
+        self.entity = entity;
+    }
+}
+impl<C: Component> SetEntityEventTarget for Mutation<C> {
+    fn set_event_target(&mut self, entity: Entity) {
+        self.entity = entity;
+    }
+}
+impl<C: Component> SetEntityEventTarget for Removal<C> {
+    fn set_event_target(&mut self, entity: Entity) {
+        self.entity = entity;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Item(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Seen(Vec<(Entity, Entity)>);
+
+    #[test]
+    fn deliver_to_slots() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Seen>();
+
+        let inventory = world.spawn_empty().id();
+        let sword = world.spawn((Item(1), ChildOf(inventory))).id();
+        let shield = world.spawn((Item(1), ChildOf(inventory))).id();
+
+        let record = |mutation: On<Mutation<Item>>, mut seen: ResMut<Seen>| {
+            seen.0.push((mutation.entity, mutation.mutated));
+        };
+
+        let monitor = world
+            .spawn((
+                MonitorChildren(inventory),
+                NotifyChanged::<Item>::default(),
+                TargetSlots,
+            ))
+            .observe(record)
+            .id();
+        let row = world.spawn(SlotOf(sword)).observe(record).id();
+
+        world.run_schedule(Update);
+        world.resource_mut::<Seen>().0.clear();
+
+        world.get_mut::<Item>(sword).unwrap().0 += 1;
+        world.run_schedule(Update);
+        world.get_mut::<Item>(shield).unwrap().0 += 1;
+        world.run_schedule(Update);
+
+        // The shield has no slot, so the monitor is told about it instead.
+        assert_eq!(
+            world.resource::<Seen>().0,
+            vec![(row, sword), (monitor, shield)]
+        );
+    }
+}
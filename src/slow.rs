@@ -0,0 +1,146 @@
+use crate::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+use std::time::{Duration, Instant};
+
+#[derive(EntityEvent, Clone, Copy, PartialEq, Eq, Debug)]
+/// Indicates that the observers of a monitor took longer than its [`ObserverBudget`] to handle a
+/// notification.
+pub struct SlowMonitor {
+    pub entity: Entity,
+    /// How long the observers took.
+    pub elapsed: Duration,
+    pub budget: Duration,
+}
+
+#[derive(Component, Reflect, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+/// Adding this component to a monitor will time how long its observers take to handle each
+/// notification, triggering [`SlowMonitor`] on it whenever they take longer than `budget`.
+///
+/// This makes it possible to find the observer that occasionally takes several milliseconds
+/// without attaching a profiler.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::time::Duration;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands
+///     .spawn((
+///         Monitor(player),
+///         NotifyChanged::<Health>::default(),
+///         ObserverBudget::new(Duration::from_millis(1)).pause_when_exceeded(),
+///     ))
+///     .observe(|slow: On<SlowMonitor>, names: Query<NameOrEntity>| {
+///         if let Ok(name) = names.get(slow.entity) {
+///             warn!("{name} took {:?} to handle a notification", slow.elapsed);
+///         }
+///     });
+/// # }
+/// ```
+///
+/// The time includes the commands queued by the observers.
+pub struct ObserverBudget {
+    pub budget: Duration,
+    /// Whether the monitor should be paused with [`MonitorPaused`] when the budget is exceeded.
+    pub pause: bool,
+}
+impl ObserverBudget {
+    /// Triggers [`SlowMonitor`] when the observers take longer than `budget`.
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            pause: false,
+        }
+    }
+    /// Also pause the monitor with [`MonitorPaused`] when the budget is exceeded.
+    pub fn pause_when_exceeded(self) -> Self {
+        Self {
+            pause: true,
+            ..self
+        }
+    }
+}
+
+/// Runs `trigger`, reporting `monitor` if it takes longer than `budget`.
+pub(crate) fn trigger_timed(
+    world: &mut World,
+    monitor: Entity,
+    ObserverBudget { budget, pause }: ObserverBudget,
+    trigger: impl FnOnce(&mut World),
+) {
+    let start = Instant::now();
+    trigger(world);
+    let elapsed = start.elapsed();
+
+    if elapsed <= budget {
+        return;
+    }
+
+    world.trigger(SlowMonitor {
+        entity: monitor,
+        elapsed,
+        budget,
+    });
+
+    if pause && let Ok(mut monitor) = world.get_entity_mut(monitor) {
+        monitor.insert(MonitorPaused);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+    use std::time::Duration;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct SlowMonitors(Vec<Entity>);
+
+    #[test]
+    fn report_slow_observers() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<SlowMonitors>();
+        world.add_observer(
+            |slow: On<SlowMonitor>, mut monitors: ResMut<SlowMonitors>| {
+                monitors.0.push(slow.entity);
+            },
+        );
+
+        let player = world.spawn(Health(100)).id();
+        let slow = world
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                ObserverBudget::new(Duration::from_millis(1)).pause_when_exceeded(),
+            ))
+            .observe(|_: On<Mutation<Health>>| {
+                std::thread::sleep(Duration::from_millis(5));
+            })
+            .id();
+        world
+            .spawn((
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                ObserverBudget::new(Duration::from_secs(1)),
+            ))
+            .observe(|_: On<Mutation<Health>>| {});
+
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<SlowMonitors>().0, vec![slow]);
+        assert!(world.entity(slow).contains::<MonitorPaused>());
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<SlowMonitors>().0, vec![slow]);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::prelude::*;
+use bevy_ecs::{component::Mutable, prelude::*};
+
+#[derive(Component)]
+#[require(NotifyChanged<C> = NotifyChanged::required())]
+/// Adding this component to a monitor will cause the [`Mutation<C>`] it receives to carry a
+/// snapshot of the new value of [`C`], see [`Mutation::value`].
+///
+/// This allows simple observers to be pure functions of the event, rather than querying for the
+/// value they were just notified about.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component, Clone)]
+/// # pub struct Health(pub u8);
+/// # fn showcase(mut commands: Commands, player: Entity) {
+/// commands
+///     .spawn((Monitor(player), NotifyWithValue::<Health>::default()))
+///     .observe(|mutation: On<Mutation<Health>>| {
+///         if let Some(Health(health)) = mutation.value() {
+///             println!("Health is now {health}");
+///         }
+///     });
+/// # }
+/// ```
+///
+/// The value is cloned once for every monitor notified, so this is best kept to components that
+/// are cheap to clone.
+pub struct NotifyWithValue<C: Component> {
+    pub(crate) snapshot: fn(&C) -> C,
+}
+impl<C: Component<Mutability = Mutable> + Clone> Default for NotifyWithValue<C> {
+    fn default() -> Self {
+        Self { snapshot: C::clone }
+    }
+}
+impl<C: Component> NotifyWithValue<C> {
+    /// Takes a snapshot of `value`.
+    pub(crate) fn snapshot(&self, value: &C) -> C {
+        (self.snapshot)(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Values(Vec<Option<Health>>);
+
+    #[test]
+    fn carry_new_value() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Values>();
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((Monitor(player), NotifyWithValue::<Health>::default()))
+            .observe(
+                |mutation: On<Mutation<Health>>, mut values: ResMut<Values>| {
+                    values.0.push(mutation.value().cloned());
+                },
+            );
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<Values>().0,
+            vec![Some(Health(100)), Some(Health(90))]
+        );
+    }
+}
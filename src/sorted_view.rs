@@ -0,0 +1,308 @@
+use crate::{
+    dispatch::Notifier,
+    mutation::configure_notify_sets,
+    prelude::*,
+    registry::{add_systems, register_once, register_teardown, unregister},
+};
+use bevy_app::Update;
+use bevy_ecs::{
+    entity::EntityHashMap, lifecycle::HookContext, prelude::*, schedule::ScheduleCleanupPolicy,
+    world::DeferredWorld,
+};
+use std::marker::PhantomData;
+
+#[derive(EntityEvent)]
+/// Indicates that an entity with [`C`] has entered a [`SortedView<C, K>`] at `index`.
+pub struct ItemEntered<C: Component> {
+    pub entity: Entity,
+    /// The [`Entity`] that entered the view.
+    pub item: Entity,
+    pub index: usize,
+    _phantom: PhantomData<C>,
+}
+
+#[derive(EntityEvent)]
+/// Indicates that an entity has left a [`SortedView<C, K>`], either because [`C`] was removed or
+/// the entity was despawned. `index` is the position it had before leaving.
+pub struct ItemLeft<C: Component> {
+    pub entity: Entity,
+    /// The [`Entity`] that left the view.
+    pub item: Entity,
+    pub index: usize,
+    _phantom: PhantomData<C>,
+}
+
+#[derive(EntityEvent)]
+/// Indicates that an entity in a [`SortedView<C, K>`] has moved from `from` to `to`.
+pub struct ItemMoved<C: Component> {
+    pub entity: Entity,
+    /// The [`Entity`] that moved.
+    pub item: Entity,
+    pub from: usize,
+    pub to: usize,
+    _phantom: PhantomData<C>,
+}
+
+#[derive(Component)]
+#[component(
+    on_add = SortedView::<C, K>::register_view_system,
+    on_remove = SortedView::<C, K>::remove_view_system
+)]
+/// Adding this component to a monitor will cause it to keep every entity with [`C`] sorted by a key
+/// [`K`], reporting positional changes with [`ItemEntered<C>`], [`ItemLeft<C>`], and
+/// [`ItemMoved<C>`].
+///
+/// This is useful for virtualized lists such as scoreboards and inventory grids, which need to
+/// know where rows went rather than which entities changed.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::cmp::Reverse;
+/// # #[derive(Component)]
+/// # pub struct Score(pub u32);
+/// # fn showcase(mut commands: Commands) {
+/// commands
+///     .spawn(SortedView::<Score, _>::by(|score| Reverse(score.0)))
+///     .observe(|moved: On<ItemMoved<Score>>| {
+///         println!("{} moved from {} to {}", moved.item, moved.from, moved.to);
+///     });
+/// # }
+/// ```
+///
+/// Entities with equal keys are ordered by [`Entity`] so the order is stable. Every entity whose
+/// index changes receives [`ItemMoved<C>`], including those pushed along by another entity
+/// entering or leaving.
+///
+/// [`Monitor`] and [`MonitorSelf`] have no effect on this, every entity with [`C`] is included.
+///
+/// # Technical info
+///
+/// Adding this component to an entity will add a system to the [`Update`] schedule that runs in
+/// [`NotifySet::Dispatch`], this is only done once. The view is only re-sorted on frames where
+/// [`C`] was changed or removed.
+///
+/// When all instances of this component in the world are removed the system will be removed.
+pub struct SortedView<C: Component, K: Ord + Send + Sync + 'static> {
+    key: fn(&C) -> K,
+    items: Vec<Entity>,
+    dirty: bool,
+}
+impl<C: Component, K: Ord + Send + Sync + 'static> SortedView<C, K> {
+    /// Sorts entities by the key returned by `key`, smallest first.
+    pub fn by(key: fn(&C) -> K) -> Self {
+        Self {
+            key,
+            items: Vec::new(),
+            dirty: true,
+        }
+    }
+    /// The entities currently in the view, in order.
+    pub fn items(&self) -> &[Entity] {
+        &self.items
+    }
+    fn register_view_system(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                configure_notify_sets(world, Update);
+                add_systems(
+                    world,
+                    Update,
+                    update_sorted_views::<C, K>.in_set(NotifySet::Dispatch),
+                );
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_view_system(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_views = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_views == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        world.schedule_scope(Update, |world, schedule| {
+            // # Safety
+            // `SortedView::<C, K>` being registered ensures this system exists in the
+            // `Update` schedule.
+            schedule
+                .remove_systems_in_set(
+                    update_sorted_views::<C, K>,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+    }
+}
+
+fn update_sorted_views<C: Component, K: Ord + Send + Sync + 'static>(
+    mut notifier: Notifier,
+    suppressed: Option<Res<SuppressedNotifications<C>>>,
+    mut removed: RemovedComponents<C>,
+    changed: Query<(), Changed<C>>,
+    values: Query<(Entity, &C)>,
+    mut views: Query<(Entity, &mut SortedView<C, K>)>,
+) {
+    if suppressed.is_some() {
+        return;
+    }
+
+    let outdated = removed.read().count() > 0 || !changed.is_empty();
+
+    views.iter_mut().for_each(|(entity, mut view)| {
+        if !outdated && !view.dirty {
+            return;
+        }
+        view.dirty = false;
+
+        let mut sorted = values
+            .iter()
+            .map(|(item, value)| ((view.key)(value), item))
+            .collect::<Vec<_>>();
+        sorted.sort_unstable();
+        let items = sorted.into_iter().map(|(_, item)| item).collect::<Vec<_>>();
+
+        let previous = view
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, &item)| (item, index))
+            .collect::<EntityHashMap<_>>();
+        let current = items
+            .iter()
+            .enumerate()
+            .map(|(index, &item)| (item, index))
+            .collect::<EntityHashMap<_>>();
+
+        view.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| !current.contains_key(*item))
+            .for_each(|(index, &item)| {
+                notifier.trigger(ItemLeft::<C> {
+                    entity,
+                    item,
+                    index,
+                    _phantom: PhantomData,
+                });
+            });
+
+        items
+            .iter()
+            .enumerate()
+            .for_each(|(index, &item)| match previous.get(&item) {
+                None => notifier.trigger(ItemEntered::<C> {
+                    entity,
+                    item,
+                    index,
+                    _phantom: PhantomData,
+                }),
+                Some(&from) if from != index => notifier.trigger(ItemMoved::<C> {
+                    entity,
+                    item,
+                    from,
+                    to: index,
+                    _phantom: PhantomData,
+                }),
+                Some(_) => {}
+            });
+
+        view.items = items;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+    use std::cmp::Reverse;
+
+    #[derive(Component)]
+    pub struct Score(u32);
+
+    #[derive(Resource, Default, PartialEq, Debug)]
+    pub struct Rows {
+        entered: Vec<(Entity, usize)>,
+        left: Vec<(Entity, usize)>,
+        moved: Vec<(Entity, usize, usize)>,
+    }
+
+    #[test]
+    fn scoreboard() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Rows>();
+
+        let alice = world.spawn(Score(10)).id();
+        let bob = world.spawn(Score(20)).id();
+
+        let scoreboard = world
+            .spawn(SortedView::<Score, _>::by(|score| Reverse(score.0)))
+            .observe(|entered: On<ItemEntered<Score>>, mut rows: ResMut<Rows>| {
+                rows.entered.push((entered.item, entered.index));
+            })
+            .observe(|left: On<ItemLeft<Score>>, mut rows: ResMut<Rows>| {
+                rows.left.push((left.item, left.index));
+            })
+            .observe(|moved: On<ItemMoved<Score>>, mut rows: ResMut<Rows>| {
+                rows.moved.push((moved.item, moved.from, moved.to));
+            })
+            .id();
+
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Rows>().entered, vec![(bob, 0), (alice, 1)]);
+
+        // Alice overtakes Bob, and Carol joins in between.
+
+        world.insert_resource(Rows::default());
+        world.get_mut::<Score>(alice).unwrap().0 = 30;
+        let carol = world.spawn(Score(25)).id();
+
+        world.run_schedule(Update);
+
+        assert_eq!(
+            *world.resource::<Rows>(),
+            Rows {
+                entered: vec![(carol, 1)],
+                left: vec![],
+                moved: vec![(alice, 1, 0), (bob, 0, 2)],
+            }
+        );
+
+        // Carol leaves.
+
+        world.insert_resource(Rows::default());
+        world.entity_mut(carol).remove::<Score>();
+
+        world.run_schedule(Update);
+
+        assert_eq!(
+            *world.resource::<Rows>(),
+            Rows {
+                entered: vec![],
+                left: vec![(carol, 1)],
+                moved: vec![(bob, 2, 1)],
+            }
+        );
+        assert_eq!(
+            world
+                .get::<SortedView<Score, Reverse<u32>>>(scoreboard)
+                .unwrap()
+                .items(),
+            &[alice, bob]
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use bevy_ecs::{change_detection::Tick, prelude::*};
+use bevy_reflect::Reflect;
+use std::time::Duration;
+
+#[derive(Component, Reflect, Clone, PartialEq, Eq, Default, Debug)]
+/// Adding this component to a monitor will keep track of the notifications it receives.
+///
+/// This makes it easy to find the monitor responsible for a large number of notifications.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// fn find_busiest(monitors: Query<(NameOrEntity, &MonitorStats)>) {
+///     let busiest = monitors
+///         .iter()
+///         .max_by(|(_, a), (_, b)| a.events_per_second().total_cmp(&b.events_per_second()));
+///
+///     if let Some((name, stats)) = busiest {
+///         info!("{name} receives {} notifications a second", stats.events_per_second());
+///     }
+/// }
+/// ```
+pub struct MonitorStats {
+    /// The total number of notifications delivered to this monitor.
+    pub received: u64,
+    /// The change tick of the most recently received notification.
+    pub last_tick: Option<Tick>,
+    /// The number of notifications that were dropped before reaching this monitor, these are not
+    /// counted in `received`.
+    pub dropped: u64,
+    /// The number of notifications that were merged into another before reaching this monitor.
+    pub coalesced: u64,
+    first_received: Option<Duration>,
+    last_received: Option<Duration>,
+}
+impl MonitorStats {
+    /// The average number of notifications received per second, measured between the first and
+    /// the most recent notification.
+    ///
+    /// This relies on the `Time` resource and the `time` feature, without them this will always
+    /// be `0.0`.
+    pub fn events_per_second(&self) -> f32 {
+        let (Some(first), Some(last)) = (self.first_received, self.last_received) else {
+            return 0.;
+        };
+        let elapsed = (last - first).as_secs_f32();
+
+        if elapsed == 0. {
+            return 0.;
+        }
+
+        self.received.saturating_sub(1) as f32 / elapsed
+    }
+    pub(crate) fn record(&mut self, tick: Tick, now: Option<Duration>) {
+        self.received += 1;
+        self.last_tick = Some(tick);
+
+        if let Some(now) = now {
+            self.first_received.get_or_insert(now);
+            self.last_received = Some(now);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "time"))]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+    use std::time::Duration;
+
+    #[derive(Component)]
+    pub struct Poisoned;
+
+    #[derive(Component)]
+    pub struct Ping;
+
+    #[derive(Component)]
+    pub struct Pong;
+
+    #[test]
+    fn count_notifications() {
+        let mut world = World::new();
+
+        world.init_resource::<Time>();
+
+        let player = world.spawn_empty().id();
+
+        let monitor = world
+            .spawn((
+                Monitor(player),
+                NotifyAdded::<Poisoned>::default(),
+                NotifyRemoved::<Poisoned>::default(),
+                MonitorStats::default(),
+            ))
+            .id();
+
+        for _ in 0..3 {
+            world.entity_mut(player).insert(Poisoned);
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(500));
+            world.entity_mut(player).remove::<Poisoned>();
+        }
+
+        let stats = world.get::<MonitorStats>(monitor).unwrap();
+
+        assert_eq!(stats.received, 6);
+        assert!(stats.last_tick.is_some());
+        assert_eq!(stats.events_per_second(), 5. / 1.5);
+    }
+
+    #[test]
+    fn count_depth_capped_as_dropped() {
+        let mut world = World::new();
+
+        world.insert_resource(NotifySettings {
+            max_trigger_depth: Some(3),
+            ..default()
+        });
+
+        let ball = world.spawn_empty().id();
+        // Each monitor re-adds the component the other removes, until the depth limit is hit.
+        let ping = world
+            .spawn((
+                Monitor(ball),
+                NotifyRemoved::<Ping>::default(),
+                MonitorStats::default(),
+            ))
+            .observe(move |_: On<Removal<Ping>>, mut commands: Commands| {
+                commands.entity(ball).insert(Pong).remove::<Pong>();
+            })
+            .id();
+        let pong = world
+            .spawn((
+                Monitor(ball),
+                NotifyRemoved::<Pong>::default(),
+                MonitorStats::default(),
+            ))
+            .observe(move |_: On<Removal<Pong>>, mut commands: Commands| {
+                commands.entity(ball).insert(Ping).remove::<Ping>();
+            })
+            .id();
+        world.flush();
+
+        world.entity_mut(ball).insert(Ping).remove::<Ping>();
+        world.flush();
+
+        let ping = world.get::<MonitorStats>(ping).unwrap();
+        let pong = world.get::<MonitorStats>(pong).unwrap();
+
+        assert_eq!((ping.received, ping.dropped), (2, 0));
+        assert_eq!((pong.received, pong.dropped), (1, 1));
+    }
+}
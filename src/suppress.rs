@@ -0,0 +1,180 @@
+#[cfg(feature = "state")]
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+#[cfg(feature = "state")]
+use bevy_state::prelude::*;
+use std::marker::PhantomData;
+
+#[derive(Resource, Reflect, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+/// While this resource exists no [`Mutation<C>`], [`Addition<C>`], or [`Removal<C>`] will be
+/// triggered, regardless of which monitors are watching [`C`].
+///
+/// This is inserted and removed automatically by [`SuppressNotify::suppress_in_state`], which
+/// requires the `state` feature, it holds the number of active states suppressing [`C`].
+///
+/// [`Mutation<C>`]: crate::prelude::Mutation
+/// [`Addition<C>`]: crate::prelude::Addition
+/// [`Removal<C>`]: crate::prelude::Removal
+/// [`SuppressNotify::suppress_in_state`]: crate::prelude::SuppressNotify::suppress_in_state
+pub struct SuppressedNotifications<C: Component> {
+    suppressors: usize,
+    _phantom: PhantomData<C>,
+}
+
+#[cfg(feature = "state")]
+/// Allows disabling all notifications for a component type while in a given state.
+pub trait SuppressNotify {
+    /// Disables all notifications for [`C`] while `state` is active, for example to ignore
+    /// [`Transform`] changes during a loading screen.
+    ///
+    /// [`Transform`]: https://docs.rs/bevy/latest/bevy/prelude/struct.Transform.html
+    fn suppress_in_state<S: States, C: Component>(&mut self, state: S) -> &mut Self;
+}
+
+#[cfg(feature = "state")]
+impl SuppressNotify for App {
+    fn suppress_in_state<S: States, C: Component>(&mut self, state: S) -> &mut Self {
+        self.add_systems(OnEnter(state.clone()), begin_suppression::<C>)
+            .add_systems(OnExit(state), end_suppression::<C>)
+    }
+}
+
+#[cfg(feature = "state")]
+fn begin_suppression<C: Component>(mut commands: Commands) {
+    // Queued so that states entered in the same frame each count, rather than all of them seeing
+    // the resource as missing.
+    commands.queue(|world: &mut World| {
+        world
+            .get_resource_or_insert_with(|| SuppressedNotifications::<C> {
+                suppressors: 0,
+                _phantom: PhantomData,
+            })
+            .suppressors += 1;
+    });
+}
+
+#[cfg(feature = "state")]
+fn end_suppression<C: Component>(mut commands: Commands) {
+    // Queued to stay ordered with suppression beginning in the same frame.
+    commands.queue(|world: &mut World| {
+        let Some(mut suppressed) = world.get_resource_mut::<SuppressedNotifications<C>>() else {
+            return;
+        };
+
+        suppressed.suppressors = suppressed.suppressors.saturating_sub(1);
+
+        if suppressed.suppressors == 0 {
+            world.remove_resource::<SuppressedNotifications<C>>();
+        }
+    });
+}
+
+#[cfg(all(test, feature = "state"))]
+mod tests {
+    use crate::prelude::*;
+    use bevy::{prelude::*, state::app::StatesPlugin};
+
+    #[derive(States, Hash, PartialEq, Eq, Clone, Debug, Default)]
+    pub enum GameState {
+        #[default]
+        Loading,
+        Playing,
+    }
+
+    #[derive(States, Hash, PartialEq, Eq, Clone, Debug, Default)]
+    pub enum MenuState {
+        #[default]
+        Open,
+        Closed,
+    }
+
+    #[derive(Component)]
+    pub struct Poisoned;
+
+    #[derive(Resource, Default)]
+    pub struct TimesPoisoned(usize);
+
+    #[test]
+    fn suppress_while_loading() {
+        let mut app = App::new();
+
+        app.add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<TimesPoisoned>()
+            .suppress_in_state::<GameState, Poisoned>(GameState::Loading);
+
+        let player = app
+            .world_mut()
+            .spawn((MonitorSelf, NotifyAdded::<Poisoned>::default()))
+            .observe(
+                |_: On<Addition<Poisoned>>, mut times_poisoned: ResMut<TimesPoisoned>| {
+                    times_poisoned.0 += 1;
+                },
+            )
+            .id();
+
+        app.update();
+
+        app.world_mut().entity_mut(player).insert(Poisoned);
+
+        assert_eq!(app.world().resource::<TimesPoisoned>().0, 0);
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+
+        app.world_mut()
+            .entity_mut(player)
+            .remove::<Poisoned>()
+            .insert(Poisoned);
+
+        assert_eq!(app.world().resource::<TimesPoisoned>().0, 1);
+    }
+
+    #[test]
+    fn count_states_entered_together() {
+        let mut app = App::new();
+
+        app.add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_state::<MenuState>()
+            .init_resource::<TimesPoisoned>()
+            .suppress_in_state::<GameState, Poisoned>(GameState::Loading)
+            .suppress_in_state::<MenuState, Poisoned>(MenuState::Open);
+
+        let player = app
+            .world_mut()
+            .spawn((MonitorSelf, NotifyAdded::<Poisoned>::default()))
+            .observe(
+                |_: On<Addition<Poisoned>>, mut times_poisoned: ResMut<TimesPoisoned>| {
+                    times_poisoned.0 += 1;
+                },
+            )
+            .id();
+
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<NextState<MenuState>>()
+            .set(MenuState::Closed);
+        app.update();
+
+        app.world_mut().entity_mut(player).insert(Poisoned);
+
+        assert_eq!(app.world().resource::<TimesPoisoned>().0, 0);
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+
+        app.world_mut()
+            .entity_mut(player)
+            .remove::<Poisoned>()
+            .insert(Poisoned);
+
+        assert_eq!(app.world().resource::<TimesPoisoned>().0, 1);
+    }
+}
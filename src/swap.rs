@@ -0,0 +1,256 @@
+use crate::{
+    dispatch::{Notifier, NotifyGate},
+    monitors::{Hierarchy, MonitorTargets},
+    prelude::*,
+    registry::{add_systems, register_once, register_teardown, unregister},
+};
+use bevy_app::First;
+use bevy_ecs::{
+    change_detection::Tick, entity::EntityHashSet, lifecycle::HookContext, prelude::*,
+    schedule::ScheduleCleanupPolicy, system::SystemParam, world::DeferredWorld,
+};
+use bevy_reflect::Reflect;
+use std::marker::PhantomData;
+
+#[derive(Resource)]
+/// Contains the observers detecting swaps from [`C`] to [`D`], and keeps track of the entities that
+/// are halfway through a swap this frame.
+struct DetectingSwaps<C: Component, D: Component> {
+    observers: [Entity; 2],
+    /// Entities that had [`C`] removed this frame without [`D`] being added yet.
+    removed: EntityHashSet,
+    /// Entities that had [`D`] added this frame without [`C`] being removed yet.
+    added: EntityHashSet,
+    _phantom: PhantomData<(C, D)>,
+}
+
+#[derive(EntityEvent)]
+/// Indicates that the component [`C`] was replaced by [`D`] on an entity watched by a monitor.
+///
+/// See [`NotifySwapped<C, D>`] for more information on how this is triggered.
+pub struct Swapped<C: Component, D: Component> {
+    pub entity: Entity,
+    /// The [`Entity`] that [`C`] was removed from and [`D`] was added to.
+    pub swapped: Entity,
+    /// The change tick at which this was triggered.
+    pub tick: Tick,
+    _phantom: PhantomData<(C, D)>,
+}
+
+#[derive(Component, Reflect, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[component(
+    on_add = NotifySwapped::<C, D>::register_swap_observers,
+    on_remove = NotifySwapped::<C, D>::remove_swap_observers,
+    clone_behavior = clone::<Self>()
+)]
+/// Adding this component to a entity will cause it to react to component [`C`] being removed and
+/// [`D`] being added on the same entity within a single frame with [`Swapped<C, D>`], such as
+/// `Alive` becoming `Dead`.
+///
+/// The order of the removal and the addition doesn't matter, and [`Removal<C>`] and
+/// [`Addition<D>`] are not triggered unless the monitor also has [`NotifyRemoved<C>`] and
+/// [`NotifyAdded<D>`].
+///
+/// By default this will react to swaps on **all** entities. See [`Monitor`], and [`MonitorSelf`]
+/// for restricting this.
+///
+/// # Technical info
+///
+/// Adding this component to an entity will spawn an [`Observer`] for each of [`On<Remove, C>`]
+/// and [`On<Add, D>`] and add a system to the [`First`] schedule that forgets unfinished swaps at
+/// the start of every frame, this is only done once.
+///
+/// When all instances of this component in the world are removed the observers and system will be
+/// removed.
+pub struct NotifySwapped<C: Component, D: Component>(PhantomData<(C, D)>);
+impl<C: Component, D: Component> Default for NotifySwapped<C, D> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+impl<C: Component, D: Component> Clone for NotifySwapped<C, D> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
+impl<C: Component, D: Component> NotifySwapped<C, D> {
+    fn register_swap_observers(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                let observers = [
+                    world.add_observer(swap_on_remove::<C, D>).id(),
+                    world.add_observer(swap_on_add::<C, D>).id(),
+                ];
+                world.insert_resource(DetectingSwaps::<C, D> {
+                    observers,
+                    removed: EntityHashSet::default(),
+                    added: EntityHashSet::default(),
+                    _phantom: PhantomData,
+                });
+                add_systems(world, First, forget_unfinished_swaps::<C, D>);
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_swap_observers(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_reactive = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_reactive == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        // # Safety
+        // Registering `NotifySwapped::<C, D>` adds the `DetectingSwaps` resource and
+        // the system.
+        let DetectingSwaps { observers, .. } =
+            world.remove_resource::<DetectingSwaps<C, D>>().unwrap();
+        observers.into_iter().for_each(|observer| {
+            world.entity_mut(observer).despawn();
+        });
+        world.schedule_scope(First, |world, schedule| {
+            schedule
+                .remove_systems_in_set(
+                    forget_unfinished_swaps::<C, D>,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+    }
+}
+
+fn forget_unfinished_swaps<C: Component, D: Component>(
+    mut detecting: ResMut<DetectingSwaps<C, D>>,
+) {
+    detecting.removed.clear();
+    detecting.added.clear();
+}
+
+fn swap_on_remove<C: Component, D: Component>(
+    remove: On<Remove, C>,
+    mut detecting: ResMut<DetectingSwaps<C, D>>,
+    mut swaps: SwapDispatch<C, D>,
+) {
+    if detecting.added.remove(&remove.entity) {
+        swaps.dispatch(remove.entity);
+    } else {
+        detecting.removed.insert(remove.entity);
+    }
+}
+
+fn swap_on_add<C: Component, D: Component>(
+    add: On<Add, D>,
+    mut detecting: ResMut<DetectingSwaps<C, D>>,
+    mut swaps: SwapDispatch<C, D>,
+) {
+    if detecting.removed.remove(&add.entity) {
+        swaps.dispatch(add.entity);
+    } else {
+        detecting.added.insert(add.entity);
+    }
+}
+
+#[derive(SystemParam)]
+/// Triggers [`Swapped<C, D>`] on every monitor watching an entity.
+struct SwapDispatch<'w, 's, C: Component, D: Component> {
+    notifier: Notifier<'w, 's>,
+    gate: NotifyGate<'w, 's, D>,
+    suppressed: Option<Res<'w, SuppressedNotifications<D>>>,
+    deterministic: Option<Res<'w, DeterministicNotifications>>,
+    monitors: Query<'w, 's, MonitorTargets, With<NotifySwapped<C, D>>>,
+    hierarchy: Hierarchy<'w, 's>,
+}
+impl<C: Component, D: Component> SwapDispatch<'_, '_, C, D> {
+    fn dispatch(&mut self, swapped: Entity) {
+        if self.suppressed.is_some() {
+            return;
+        }
+
+        let mut monitors = self
+            .monitors
+            .iter()
+            .filter(|targets| targets.watches(swapped, &self.hierarchy))
+            .map(|targets| targets.entity)
+            .collect::<Vec<_>>();
+
+        if self.deterministic.is_some() {
+            monitors.sort_unstable();
+        }
+
+        monitors.into_iter().for_each(|entity| {
+            if !self.gate.allows(entity, swapped) {
+                self.notifier.drop(entity);
+                return;
+            }
+
+            let tick = self.notifier.tick();
+            self.notifier.trigger(Swapped::<C, D> {
+                entity,
+                swapped,
+                tick,
+                _phantom: PhantomData,
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Alive;
+
+    #[derive(Component)]
+    pub struct Dead;
+
+    #[derive(Resource, Default)]
+    pub struct Deaths(usize);
+
+    #[test]
+    fn alive_to_dead() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(First));
+        world.init_resource::<Deaths>();
+
+        let player = world.spawn(Alive).id();
+        world
+            .spawn((Monitor(player), NotifySwapped::<Alive, Dead>::default()))
+            .observe(|_: On<Swapped<Alive, Dead>>, mut deaths: ResMut<Deaths>| {
+                deaths.0 += 1;
+            });
+
+        world.entity_mut(player).remove::<Alive>().insert(Dead);
+
+        assert_eq!(world.resource::<Deaths>().0, 1);
+
+        // The order doesn't matter.
+
+        world.entity_mut(player).remove::<Dead>().insert(Alive);
+        world.entity_mut(player).insert(Dead).remove::<Alive>();
+
+        assert_eq!(world.resource::<Deaths>().0, 2);
+
+        // Halves of a swap in different frames aren't paired.
+
+        world.entity_mut(player).remove::<Dead>().insert(Alive);
+        world.run_schedule(First);
+        world.entity_mut(player).remove::<Alive>();
+        world.run_schedule(First);
+        world.entity_mut(player).insert(Dead);
+
+        assert_eq!(world.resource::<Deaths>().0, 2);
+    }
+}
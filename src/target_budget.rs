@@ -0,0 +1,167 @@
+use crate::registry::{add_systems, register_once, register_teardown, unregister};
+use bevy_app::First;
+use bevy_ecs::{
+    lifecycle::HookContext, prelude::*, schedule::ScheduleCleanupPolicy, world::DeferredWorld,
+};
+use bevy_reflect::Reflect;
+
+#[derive(Component, Reflect, Clone, Hash, PartialEq, Eq, Debug)]
+#[component(
+    on_add = TargetBudget::register_reset_system,
+    on_remove = TargetBudget::remove_reset_system
+)]
+/// Adding this component to a **watched** entity limits the number of [`Mutation<C>`] delivered
+/// about it each frame, across all of its monitors and components.
+///
+/// This protects frame time from a single entity whose components change extremely often, such as
+/// a physics driven boss, without throttling the rest of the world like [`NotificationBudget`]
+/// does.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # fn showcase(mut commands: Commands) {
+/// commands.spawn((Transform::default(), TargetBudget::new(8)));
+/// # }
+/// ```
+///
+/// Mutations over the budget are coalesced, each monitor keeps at most one held back mutation per
+/// component which is retried the next frame before any new ones. They are counted as coalesced by
+/// [`MonitorStats`].
+///
+/// # Technical info
+///
+/// Adding this component to an entity will add a system to the [`First`] schedule that resets the
+/// budget, this is only done once.
+///
+/// When all instances of this component in the world are removed the system will be removed.
+///
+/// [`Mutation<C>`]: crate::prelude::Mutation
+/// [`NotificationBudget`]: crate::prelude::NotificationBudget
+/// [`MonitorStats`]: crate::prelude::MonitorStats
+pub struct TargetBudget {
+    pub per_frame: usize,
+    delivered: usize,
+}
+impl TargetBudget {
+    pub fn new(per_frame: usize) -> Self {
+        Self {
+            per_frame,
+            delivered: 0,
+        }
+    }
+    /// The number of mutations delivered about this entity so far this frame.
+    pub fn delivered(&self) -> usize {
+        self.delivered
+    }
+    /// Counts a mutation, returning whether it should be delivered this frame.
+    pub(crate) fn spend(&mut self) -> bool {
+        let allowed = self.delivered < self.per_frame;
+
+        if allowed {
+            self.delivered += 1;
+        }
+        allowed
+    }
+    fn register_reset_system(mut world: DeferredWorld, _: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            register_once::<Self>(world, |world| {
+                add_systems(world, First, reset_target_budgets);
+                register_teardown::<Self>(world, Self::teardown);
+            });
+        });
+    }
+    fn remove_reset_system(mut world: DeferredWorld, _: HookContext) {
+        // # Safety
+        // The only component being queried for is on that must already exist in the world for this
+        // hook to run
+        let total_budgets = world
+            .try_query_filtered::<(), With<Self>>()
+            .unwrap()
+            .iter(&world)
+            .count();
+
+        if total_budgets == 0 {
+            world.commands().queue(|world: &mut World| {
+                unregister::<Self>(world, Self::teardown);
+            });
+        }
+    }
+    fn teardown(world: &mut World) {
+        world.schedule_scope(First, |world, schedule| {
+            // # Safety
+            // `TargetBudget` being registered ensures this system exists in the
+            // `First` schedule.
+            schedule
+                .remove_systems_in_set(
+                    reset_target_budgets,
+                    world,
+                    ScheduleCleanupPolicy::RemoveSystemsOnly,
+                )
+                .unwrap();
+        });
+    }
+}
+
+fn reset_target_budgets(mut budgets: Query<&mut TargetBudget>) {
+    budgets.iter_mut().for_each(|mut budget| {
+        budget.delivered = 0;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Position;
+
+    #[derive(Resource, Default)]
+    pub struct Delivered(usize);
+
+    #[test]
+    fn limit_single_entity() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(First));
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Delivered>();
+
+        let boss = world.spawn((Position, TargetBudget::new(2))).id();
+        let minion = world.spawn(Position).id();
+        for target in [boss, boss, boss, minion, minion, minion] {
+            world
+                .spawn((
+                    Monitor(target),
+                    NotifyChanged::<Position>::default(),
+                    MonitorStats::default(),
+                ))
+                .observe(
+                    |_: On<Mutation<Position>>, mut delivered: ResMut<Delivered>| {
+                        delivered.0 += 1;
+                    },
+                );
+        }
+
+        world.run_schedule(First);
+        world.run_schedule(Update);
+
+        // Two for the boss and three for the minion.
+        assert_eq!(world.resource::<Delivered>().0, 5);
+
+        // The held back mutation is delivered next frame, even without new changes.
+        world.run_schedule(First);
+        world.run_schedule(Update);
+
+        assert_eq!(world.resource::<Delivered>().0, 6);
+        assert_eq!(
+            world
+                .query::<&MonitorStats>()
+                .iter(&world)
+                .map(|stats| stats.coalesced)
+                .sum::<u64>(),
+            1
+        );
+    }
+}
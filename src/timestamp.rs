@@ -0,0 +1,92 @@
+use bevy_ecs::prelude::*;
+use std::time::Duration;
+
+#[derive(Resource, Clone, Copy, Default, Debug)]
+/// While this resource exists every notification carries the [`Timestamp`] it was triggered at,
+/// see [`Mutation::timestamp`].
+///
+/// This spares analytics, replays, and debouncing downstream from sampling [`Time`] and
+/// [`FrameCount`] themselves, which may have moved on by the time a buffered notification is read.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(pub u8);
+/// # let mut app = App::new();
+/// app.init_resource::<TimestampedNotifications>()
+///     .add_observer(|mutation: On<Mutation<Health>>| {
+///         if let Some(timestamp) = mutation.timestamp() {
+///             println!("Health changed on frame {}", timestamp.frame);
+///         }
+///     });
+/// ```
+///
+/// [`Mutation::timestamp`]: crate::prelude::Mutation::timestamp
+/// [`Time`]: https://docs.rs/bevy/latest/bevy/time/struct.Time.html
+/// [`FrameCount`]: https://docs.rs/bevy/latest/bevy/diagnostic/struct.FrameCount.html
+pub struct TimestampedNotifications;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+/// When a notification was triggered, see [`TimestampedNotifications`].
+pub struct Timestamp {
+    /// The [`Time`] elapsed since startup, or zero if the world has no [`Time`] or the `time`
+    /// feature is disabled.
+    ///
+    /// [`Time`]: https://docs.rs/bevy/latest/bevy/time/struct.Time.html
+    pub elapsed: Duration,
+    /// The [`FrameCount`], or zero if the world has none or the `diagnostic` feature is disabled.
+    ///
+    /// [`FrameCount`]: https://docs.rs/bevy/latest/bevy/diagnostic/struct.FrameCount.html
+    pub frame: u32,
+}
+
+#[cfg(all(test, feature = "diagnostic", feature = "time"))]
+mod tests {
+    use crate::prelude::*;
+    use bevy::{diagnostic::FrameCount, prelude::*};
+    use std::time::Duration;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Timestamps(Vec<Option<Timestamp>>);
+
+    #[test]
+    fn carry_timestamp() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Timestamps>();
+        world.init_resource::<Time>();
+        world.init_resource::<FrameCount>();
+        world.init_resource::<TimestampedNotifications>();
+
+        let player = world.spawn(Health(100)).id();
+        world
+            .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+            .observe(
+                |mutation: On<Mutation<Health>>, mut timestamps: ResMut<Timestamps>| {
+                    timestamps.0.push(mutation.timestamp());
+                },
+            );
+        world.run_schedule(Update);
+        world.resource_mut::<Timestamps>().0.clear();
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(250));
+        world.resource_mut::<FrameCount>().0 = 7;
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<Timestamps>().0,
+            vec![Some(Timestamp {
+                elapsed: Duration::from_millis(250),
+                frame: 7,
+            })]
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    group::group_name,
+    prelude::*,
+    registry::{add_systems, register_once},
+};
+use bevy_app::{App, Last};
+use bevy_ecs::{component::ComponentId, prelude::*};
+use bevy_reflect::Reflect;
+use std::{any::type_name, collections::HashMap, fmt::Write};
+
+#[derive(Resource, Default)]
+/// Contains the [`WatchedComponent`] described by each `Notify*` component that has been
+/// registered, keyed by the [`ComponentId`] of the `Notify*` component.
+struct WatchedComponents(HashMap<ComponentId, WatchedComponent>);
+
+/// Registers the `Notify*` component [`N`] as watching [`C`] for notifications of `kind`, so it
+/// shows up in [`MonitorGraph`].
+pub(crate) fn register_watched<N: Component, C: Component>(
+    world: &mut World,
+    kind: NotificationKind,
+) {
+    let id = world.register_component::<N>();
+    world.get_resource_or_init::<WatchedComponents>().0.insert(
+        id,
+        WatchedComponent {
+            kind,
+            component: type_name::<C>().to_string(),
+        },
+    );
+}
+
+#[derive(Reflect, Clone, Hash, PartialEq, Eq, Debug)]
+/// A component watched by a monitor, and the kind of notification it is watched for.
+pub struct WatchedComponent {
+    pub kind: NotificationKind,
+    /// The type name of the watched component.
+    pub component: String,
+}
+
+#[derive(Reflect, Clone, PartialEq, Eq, Debug)]
+/// A single monitor in a [`MonitorGraph`].
+pub struct MonitorNode {
+    pub monitor: Entity,
+    /// The entities the monitor is watching, from [`Monitor`] and [`MonitorSelf`].
+    ///
+    /// A monitor without any targets watches every entity.
+    pub targets: Vec<Entity>,
+    pub watches: Vec<WatchedComponent>,
+    /// The name of the [`MonitorGroup`] the monitor is in, if any.
+    pub group: Option<String>,
+}
+
+#[derive(Resource, Reflect, Clone, Default, PartialEq, Eq, Debug)]
+/// A snapshot of every monitor in the world, what they are watching, and which groups they are in.
+///
+/// This is useful for debugging larger projects where monitors are spawned from many places, it can
+/// be serialized through reflection or exported to [GraphViz](https://graphviz.org) with
+/// [`MonitorGraph::to_dot`].
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// fn dump_monitors(world: &mut World) {
+///     std::fs::write("monitors.dot", MonitorGraph::capture(world).to_dot()).unwrap();
+/// }
+/// ```
+///
+/// See [`TrackMonitorGraph`] to keep a [`MonitorGraph`] resource up to date instead.
+pub struct MonitorGraph {
+    pub monitors: Vec<MonitorNode>,
+}
+impl MonitorGraph {
+    /// Captures every monitor currently in `world`.
+    ///
+    /// Only monitors with a `Notify*` component that has been registered, which happens when
+    /// commands are applied after it is first inserted, are captured.
+    pub fn capture(world: &World) -> Self {
+        let Some(watched) = world.get_resource::<WatchedComponents>() else {
+            return Self::default();
+        };
+        // # Safety
+        // `EntityRef` doesn't require any components to be registered.
+        let mut entities = world.try_query::<EntityRef>().unwrap();
+
+        let mut monitors = entities
+            .iter(world)
+            .filter_map(|entity| {
+                let watches = entity
+                    .archetype()
+                    .components()
+                    .iter()
+                    .filter_map(|id| watched.0.get(id).cloned())
+                    .collect::<Vec<_>>();
+
+                if watches.is_empty() {
+                    return None;
+                }
+
+                let targets = entity
+                    .get::<Monitor>()
+                    .map(|Monitor(target)| *target)
+                    .into_iter()
+                    .chain(entity.contains::<MonitorSelf>().then_some(entity.id()))
+                    .chain(
+                        entity
+                            .get::<MonitorMany>()
+                            .into_iter()
+                            .flat_map(|MonitorMany(targets)| targets.iter().copied()),
+                    )
+                    .chain(
+                        entity
+                            .get::<MonitorChildren>()
+                            .and_then(|&MonitorChildren(parent)| world.get::<Children>(parent))
+                            .into_iter()
+                            .flat_map(|children| children.iter()),
+                    )
+                    .collect();
+
+                Some(MonitorNode {
+                    monitor: entity.id(),
+                    targets,
+                    watches,
+                    group: entity
+                        .get::<MonitorGroup>()
+                        .map(|MonitorGroup(label)| group_name(&**label)),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        monitors.sort_by_key(|node| node.monitor.index());
+        monitors
+            .iter_mut()
+            .for_each(|node| node.watches.sort_by(|a, b| a.component.cmp(&b.component)));
+
+        Self { monitors }
+    }
+
+    /// Exports the graph in the [DOT](https://graphviz.org/doc/info/lang.html) language, with an
+    /// edge from each monitor to each of its targets for every component it watches.
+    ///
+    /// Monitors without targets have their edges point to a single `all entities` node.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph monitors {\n");
+
+        if self.monitors.iter().any(|node| node.targets.is_empty()) {
+            dot.push_str("    \"all\" [label=\"all entities\", shape=box];\n");
+        }
+
+        self.monitors.iter().for_each(|node| {
+            let label = match &node.group {
+                Some(group) => format!("{}\\ngroup: {}", node.monitor, escape(group)),
+                None => node.monitor.to_string(),
+            };
+            // Writing to a `String` can't fail.
+            let _ = writeln!(dot, "    \"{}\" [label=\"{label}\"];", node.monitor);
+
+            let targets = match node.targets.is_empty() {
+                true => vec![String::from("all")],
+                false => node.targets.iter().map(Entity::to_string).collect(),
+            };
+
+            targets.iter().for_each(|target| {
+                node.watches.iter().for_each(|watched| {
+                    let _ = writeln!(
+                        dot,
+                        "    \"{}\" -> \"{target}\" [label=\"{:?} {}\"];",
+                        node.monitor, watched.kind, watched.component
+                    );
+                });
+            });
+        });
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Allows keeping a [`MonitorGraph`] resource up to date with the monitors in the world.
+pub trait TrackMonitorGraph {
+    /// Inserts a [`MonitorGraph`] resource which is refreshed every frame.
+    ///
+    /// # Technical info
+    ///
+    /// This adds a system to the [`Last`] schedule capturing the graph, this is only done once.
+    fn track_monitor_graph(&mut self) -> &mut Self;
+}
+
+impl TrackMonitorGraph for World {
+    fn track_monitor_graph(&mut self) -> &mut Self {
+        register_once::<MonitorGraph>(self, |world| {
+            world.init_resource::<MonitorGraph>();
+            add_systems(world, Last, refresh_monitor_graph);
+        });
+        self
+    }
+}
+
+impl TrackMonitorGraph for App {
+    fn track_monitor_graph(&mut self) -> &mut Self {
+        self.world_mut().track_monitor_graph();
+        self
+    }
+}
+
+fn refresh_monitor_graph(world: &mut World) {
+    let graph = MonitorGraph::capture(world);
+
+    world.insert_resource(graph);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health;
+
+    #[test]
+    fn export_topology() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.add_schedule(Schedule::new(Last));
+        world.track_monitor_graph();
+
+        let player = world.spawn(Health).id();
+        let hud = world
+            .spawn((
+                MonitorGroup::new("hud"),
+                Monitor(player),
+                NotifyChanged::<Health>::default(),
+                NotifyRemoved::<Health>::default(),
+            ))
+            .id();
+        let logger = world.spawn(NotifyAdded::<Health>::default()).id();
+        world.spawn(Monitor(player));
+
+        world.run_schedule(Last);
+
+        let graph = world.resource::<MonitorGraph>();
+
+        assert_eq!(
+            graph
+                .monitors
+                .iter()
+                .map(|node| (node.monitor, node.targets.clone(), node.watches.len()))
+                .collect::<Vec<_>>(),
+            vec![(hud, vec![player], 2), (logger, vec![], 1)]
+        );
+        assert_eq!(graph.monitors[0].group.as_deref(), Some("hud"));
+
+        let dot = graph.to_dot();
+
+        assert!(dot.contains(&format!("\"{logger}\" -> \"all\"")));
+        assert!(dot.contains(&format!("\"{hud}\" -> \"{player}\" [label=\"Removed ")));
+    }
+}
@@ -0,0 +1,129 @@
+use bevy_ecs::prelude::*;
+use std::{fmt, marker::PhantomData};
+
+#[derive(Component)]
+/// Adding this component next to [`C`] pairs the value with a version, bumped every time a monitor
+/// is notified about it changing.
+///
+/// Consumers can remember the last version they processed rather than storing a clone of the
+/// value to compare against, both from observers through [`Mutation::version`] and from queries.
+///
+/// ```rust
+/// # use bevy_notify::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Inventory(pub Vec<String>);
+/// # fn showcase(mut commands: Commands) {
+/// let player = commands
+///     .spawn((Inventory(Vec::new()), Versioned::<Inventory>::default()))
+///     .id();
+///
+/// commands.spawn((Monitor(player), NotifyChanged::<Inventory>::default()));
+/// # }
+///
+/// fn redraw_inventory(
+///     inventories: Query<(&Inventory, &Versioned<Inventory>)>,
+///     mut drawn: Local<u64>,
+/// ) {
+///     inventories
+///         .iter()
+///         .filter(|(_, versioned)| versioned.version() > *drawn)
+///         .for_each(|(inventory, versioned)| {
+///             *drawn = versioned.version();
+///             // ...
+///         });
+/// }
+/// ```
+///
+/// The version is bumped once per change, no matter how many monitors are notified about it.
+/// Changes no monitor is notified about, such as those every monitor has filtered out, leave it
+/// unchanged.
+///
+/// [`Mutation::version`]: crate::prelude::Mutation::version
+pub struct Versioned<C: Component> {
+    version: u64,
+    _phantom: PhantomData<C>,
+}
+impl<C: Component> Default for Versioned<C> {
+    fn default() -> Self {
+        Self {
+            version: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<C: Component> Clone for Versioned<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<C: Component> Copy for Versioned<C> {}
+impl<C: Component> PartialEq for Versioned<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version
+    }
+}
+impl<C: Component> Eq for Versioned<C> {}
+impl<C: Component> fmt::Debug for Versioned<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Versioned")
+            .field("version", &self.version)
+            .finish()
+    }
+}
+impl<C: Component> Versioned<C> {
+    /// How many notified changes [`C`] has had since this was added.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+    /// Counts a notified change, returning the new version.
+    pub(crate) fn bump(&mut self) -> u64 {
+        self.version += 1;
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[derive(Component)]
+    pub struct Health(u8);
+
+    #[derive(Resource, Default)]
+    pub struct Versions(Vec<Option<u64>>);
+
+    #[test]
+    fn bump_once_per_change() {
+        let mut world = World::new();
+
+        world.add_schedule(Schedule::new(Update));
+        world.init_resource::<Versions>();
+
+        let player = world
+            .spawn((Health(100), Versioned::<Health>::default()))
+            .id();
+        (0..2).for_each(|_| {
+            world
+                .spawn((Monitor(player), NotifyChanged::<Health>::default()))
+                .observe(
+                    |mutation: On<Mutation<Health>>, mut versions: ResMut<Versions>| {
+                        versions.0.push(mutation.version());
+                    },
+                );
+        });
+        world.run_schedule(Update);
+
+        world.get_mut::<Health>(player).unwrap().0 -= 10;
+        world.run_schedule(Update);
+        // Nothing changed.
+        world.run_schedule(Update);
+
+        assert_eq!(
+            world.resource::<Versions>().0,
+            vec![Some(1), Some(1), Some(2), Some(2)]
+        );
+        assert_eq!(world.get::<Versioned<Health>>(player).unwrap().version(), 2);
+    }
+}
@@ -0,0 +1,8 @@
+#[test]
+fn compile_fail() {
+    let tests = trybuild::TestCases::new();
+
+    // Misuse is caught by trait bounds on the constructors of monitor components.
+    tests.pass("tests/ui/pass/*.rs");
+    tests.compile_fail("tests/ui/fail/*.rs");
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use bevy_monitors::prelude::*;
+
+#[derive(Component)]
+#[component(immutable)]
+pub struct Team;
+
+fn main() {
+    let mut world = World::new();
+    let player = world.spawn(Team).id();
+
+    world.spawn((Monitor(player), NotifyChanged::<Team>::default()));
+}
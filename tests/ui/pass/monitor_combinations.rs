@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+use bevy_monitors::prelude::*;
+
+#[derive(Component)]
+pub struct Health;
+
+#[derive(Component)]
+#[component(immutable)]
+pub struct Team;
+
+fn main() {
+    let mut world = World::new();
+    let player = world.spawn((Health, Team)).id();
+
+    world.spawn((
+        Monitor(player),
+        MonitorSelf,
+        NotifyAdded::<Health>::default(),
+        NotifyChanged::<Health>::default(),
+        NotifyRemoved::<Health>::default(),
+    ));
+    world.spawn((
+        Monitor(player),
+        NotifyAdded::<Team>::default(),
+        NotifyRemoved::<Team>::default(),
+    ));
+}